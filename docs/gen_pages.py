@@ -517,6 +517,13 @@ title: {item.name} | API
 default_matcher: Callable[[RootItem], bool] = lambda x: x.type == "type"
 
 create_transformer("Align", default_matcher, "alias", "api/Align")
+create_transformer("Orientation", default_matcher, "alias", "api/Orientation")
+create_transformer("EllipsizeMode", default_matcher, "alias", "api/EllipsizeMode")
+create_transformer("WorkspaceProviderKind", default_matcher, "alias", "api/WorkspaceProviderKind")
+create_transformer("GraphSource", default_matcher, "alias", "api/GraphSource")
+create_transformer("LevelValue", default_matcher, "alias", "api/LevelValue")
+create_transformer("RevealedValue", default_matcher, "alias", "api/RevealedValue")
+create_transformer("ScaleValue", default_matcher, "alias", "api/ScaleValue")
 create_transformer("Component", default_matcher, "alias", "api/Component")
 create_transformer("ComponentProps", default_matcher, "alias", "api/ComponentProps")
 # create_transformer("BarPosition", default_matcher, "type", "api/BarProps")
@@ -525,43 +532,102 @@ create_transformer("MemoryInfo", default_matcher, "type", "api/MemoryInfo")
 create_transformer("CpuLoadInfo", default_matcher, "type", "api/CpuLoadInfo")
 create_transformer("BatteryInfo", default_matcher, "type", "api/BatteryInfo")
 create_transformer("WeatherForecast", default_matcher, "type", "api/WeatherForecast")
+create_transformer("TaskbarItem", default_matcher, "type", "api/TaskbarItem")
+create_transformer("KomorebiLayoutInfo", default_matcher, "type", "api/KomorebiLayoutInfo")
+create_transformer("WindowTitleInfo", default_matcher, "type", "api/WindowTitleInfo")
+create_transformer("WorkspaceInfo", default_matcher, "type", "api/WorkspaceInfo")
+create_transformer("ScaleChangeEvent", default_matcher, "type", "api/ScaleChangeEvent")
+create_transformer("EventContext", default_matcher, "type", "api/EventContext")
+create_transformer("MediaInfo", default_matcher, "type", "api/MediaInfo")
+create_transformer("MediaIcons", default_matcher, "type", "api/MediaIcons")
+create_transformer("SystemInfoData", default_matcher, "type", "api/SystemInfoData")
+create_transformer("TemperatureInfo", default_matcher, "type", "api/TemperatureInfo")
+create_transformer("SensorInfo", default_matcher, "type", "api/SensorInfo")
+create_transformer("DiskInfo", default_matcher, "type", "api/DiskInfo")
+create_transformer("DiskMountInfo", default_matcher, "type", "api/DiskMountInfo")
+create_transformer("NetworkInfo", default_matcher, "type", "api/NetworkInfo")
 
 create_transformer("hitokage", default_matcher, "mod", "api/hitokage")
 create_transformer("bar", default_matcher, "mod", "api/hitokage/bar")
 create_transformer("monitor", default_matcher, "mod", "api/hitokage/monitor")
+create_transformer("sandbox", default_matcher, "mod", "api/hitokage/sandbox")
+create_transformer("timer", default_matcher, "mod", "api/hitokage/timer")
 create_transformer("unstable", default_matcher, "mod", "api/hitokage/unstable")
 create_transformer("reactive", default_matcher, "mod", "api/hitokage/unstable/reactive")
 create_transformer("Monitor", default_matcher, "userdata", "api/Monitor")
 create_transformer("ReactiveString", default_matcher, "userdata", "api/ReactiveString")
+create_transformer("TimerHandle", default_matcher, "userdata", "api/TimerHandle")
 create_transformer("MonitorGeometry", default_matcher, "type", "api/MonitorGeometry")
 create_transformer("BarProps", default_matcher, "type", "api/BarProps")
 create_transformer("BarOffset", default_matcher, "type", "api/BarOffset")
 create_transformer("WrapBatteryProps", default_matcher, "type", "api/WrapBatteryProps")
 create_transformer("WrapBoxProps", default_matcher, "type", "api/WrapBoxProps")
+create_transformer("WrapButtonProps", default_matcher, "type", "api/WrapButtonProps")
 create_transformer("WrapClockProps", default_matcher, "type", "api/WrapClockProps")
 create_transformer("WrapCpuProps", default_matcher, "type", "api/WrapCpuProps")
+create_transformer("WrapDiskProps", default_matcher, "type", "api/WrapDiskProps")
+create_transformer("WrapGraphProps", default_matcher, "type", "api/WrapGraphProps")
 create_transformer("WrapIconProps", default_matcher, "type", "api/WrapIconProps")
+create_transformer("WrapKomorebiLayoutProps", default_matcher, "type", "api/WrapKomorebiLayoutProps")
 create_transformer("WrapLabelProps", default_matcher, "type", "api/WrapLabelProps")
+create_transformer("WrapLevelProps", default_matcher, "type", "api/WrapLevelProps")
+create_transformer("WrapMediaProps", default_matcher, "type", "api/WrapMediaProps")
 create_transformer("WrapMemoryProps", default_matcher, "type", "api/WrapMemoryProps")
+create_transformer("WrapNetworkProps", default_matcher, "type", "api/WrapNetworkProps")
+create_transformer("WrapRevealerProps", default_matcher, "type", "api/WrapRevealerProps")
+create_transformer("WrapScaleProps", default_matcher, "type", "api/WrapScaleProps")
+create_transformer("WrapStackProps", default_matcher, "type", "api/WrapStackProps")
+create_transformer("WrapSystemInfoProps", default_matcher, "type", "api/WrapSystemInfoProps")
+create_transformer("WrapTaskbarProps", default_matcher, "type", "api/WrapTaskbarProps")
+create_transformer("WrapTemperatureProps", default_matcher, "type", "api/WrapTemperatureProps")
 create_transformer("WrapWeatherProps", default_matcher, "type", "api/WrapWeatherProps")
+create_transformer("WrapWindowTitleProps", default_matcher, "type", "api/WrapWindowTitleProps")
 create_transformer("WrapWorkspaceProps", default_matcher, "type", "api/WrapWorkspaceProps")
 create_transformer("BatteryProps", default_matcher, "type", "api/WrapBatteryProps/BatteryProps")
 create_transformer("BoxProps", default_matcher, "type", "api/WrapBoxProps/BoxProps")
+create_transformer("ButtonProps", default_matcher, "type", "api/WrapButtonProps/ButtonProps")
 create_transformer("ClockProps", default_matcher, "type", "api/WrapClockProps/ClockProps")
 create_transformer("CpuProps", default_matcher, "type", "api/WrapCpuProps/CpuProps")
+create_transformer("DiskProps", default_matcher, "type", "api/WrapDiskProps/DiskProps")
+create_transformer("GraphProps", default_matcher, "type", "api/WrapGraphProps/GraphProps")
 create_transformer("IconProps", default_matcher, "type", "api/WrapIconProps/IconProps")
+create_transformer("KomorebiLayoutProps", default_matcher, "type", "api/WrapKomorebiLayoutProps/KomorebiLayoutProps")
 create_transformer("LabelProps", default_matcher, "type", "api/WrapLabelProps/LabelProps")
+create_transformer("LevelProps", default_matcher, "type", "api/WrapLevelProps/LevelProps")
+create_transformer("MediaProps", default_matcher, "type", "api/WrapMediaProps/MediaProps")
 create_transformer("MemoryProps", default_matcher, "type", "api/WrapMemoryProps/MemoryProps")
+create_transformer("NetworkProps", default_matcher, "type", "api/WrapNetworkProps/NetworkProps")
+create_transformer("RevealerProps", default_matcher, "type", "api/WrapRevealerProps/RevealerProps")
+create_transformer("ScaleProps", default_matcher, "type", "api/WrapScaleProps/ScaleProps")
+create_transformer("StackProps", default_matcher, "type", "api/WrapStackProps/StackProps")
+create_transformer("SystemInfoProps", default_matcher, "type", "api/WrapSystemInfoProps/SystemInfoProps")
+create_transformer("TaskbarProps", default_matcher, "type", "api/WrapTaskbarProps/TaskbarProps")
+create_transformer("TemperatureProps", default_matcher, "type", "api/WrapTemperatureProps/TemperatureProps")
 create_transformer("WeatherProps", default_matcher, "type", "api/WrapWeatherProps/WeatherProps")
+create_transformer("WindowTitleProps", default_matcher, "type", "api/WrapWindowTitleProps/WindowTitleProps")
 create_transformer("WorkspaceProps", default_matcher, "type", "api/WrapWorkspaceProps/WorkspaceProps")
 create_transformer("Battery", default_matcher, "userdata", "api/Battery")
 create_transformer("Box", default_matcher, "userdata", "api/Box")
+create_transformer("Button", default_matcher, "userdata", "api/Button")
 create_transformer("Clock", default_matcher, "userdata", "api/Clock")
 create_transformer("Cpu", default_matcher, "userdata", "api/Cpu")
+create_transformer("Disk", default_matcher, "userdata", "api/Disk")
+create_transformer("Graph", default_matcher, "userdata", "api/Graph")
 create_transformer("Icon", default_matcher, "userdata", "api/Icon")
+create_transformer("KomorebiLayout", default_matcher, "userdata", "api/KomorebiLayout")
 create_transformer("Label", default_matcher, "userdata", "api/Label")
+create_transformer("Level", default_matcher, "userdata", "api/Level")
+create_transformer("Media", default_matcher, "userdata", "api/Media")
 create_transformer("Memory", default_matcher, "userdata", "api/Memory")
+create_transformer("Network", default_matcher, "userdata", "api/Network")
+create_transformer("Revealer", default_matcher, "userdata", "api/Revealer")
+create_transformer("Scale", default_matcher, "userdata", "api/Scale")
+create_transformer("Stack", default_matcher, "userdata", "api/Stack")
+create_transformer("SystemInfo", default_matcher, "userdata", "api/SystemInfo")
+create_transformer("Taskbar", default_matcher, "userdata", "api/Taskbar")
+create_transformer("Temperature", default_matcher, "userdata", "api/Temperature")
 create_transformer("Weather", default_matcher, "userdata", "api/Weather")
+create_transformer("WindowTitle", default_matcher, "userdata", "api/WindowTitle")
 create_transformer("Workspace", default_matcher, "userdata", "api/Workspace")
 create_transformer("Bar", default_matcher, "userdata", "api/Bar")
 create_transformer("MonitorScaleFactor", default_matcher, "type", "api/MonitorScaleFactor")
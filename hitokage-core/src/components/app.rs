@@ -41,7 +41,7 @@ pub enum LuaHookType {
   WriteState,     //
   ReadEvent,      // This should probably exclusively be used for initializing configurations, it does not subscribe!
   CreateBar(Box<Monitor>, BarProps, Box<dyn Fn(relm4::Sender<BarMsg>) + Send>),
-  CheckConfigUpdate,
+  WatchFile(std::path::PathBuf), // a file the config depends on, ie. a required module
   NoAction, // These hooks are used for Relm4 hooking into, so it is very possible we don't need to handle anything
 }
 
@@ -57,7 +57,7 @@ impl std::fmt::Debug for LuaHookType {
         .field("props", props)
         .field("callback", &"<function>")
         .finish(),
-      &LuaHookType::CheckConfigUpdate => write!(f, "CheckConfigUpdate"),
+      LuaHookType::WatchFile(path) => f.debug_tuple("WatchFile").field(path).finish(),
       LuaHookType::NoAction => write!(f, "NoAction"),
     }
   }
@@ -2,7 +2,8 @@ use super::app::AppMsg;
 use super::base::BaseMsgHook;
 use super::r#box::{BoxInner, BoxMsgHook, BoxProps};
 use super::ChildUserData;
-use crate::structs::{Monitor, MonitorGeometry, MonitorScaleFactor};
+use super::{deserialize_empty_or_seq, Child};
+use crate::structs::{Monitor, MonitorGeometry, MonitorScaleFactor, Orientation};
 use crate::win_utils::get_windows_version;
 use crate::{
   generate_base_match_arms, generate_box_children, generate_box_match_arms, prepend_css_class,
@@ -24,7 +25,7 @@ use std::sync::mpsc::Sender;
 use std::sync::{Arc, Mutex};
 use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, WPARAM};
 use windows::Win32::UI::Shell::{
-  SHAppBarMessage, ABE_BOTTOM, ABE_TOP, ABM_NEW, ABM_QUERYPOS, ABM_REMOVE, ABM_SETPOS, APPBARDATA,
+  SHAppBarMessage, ABE_BOTTOM, ABE_LEFT, ABE_RIGHT, ABE_TOP, ABM_NEW, ABM_QUERYPOS, ABM_REMOVE, ABM_SETPOS, APPBARDATA,
 };
 use windows::Win32::UI::WindowsAndMessaging::{
   CallWindowProcW, DefWindowProcW, SetWindowLongPtrW, SetWindowPos, ShowWindow, GWL_EXSTYLE, HWND_NOTOPMOST,
@@ -118,11 +119,18 @@ fn setup_window_surface(
   offset_y: i32,
   position: &BarPosition,
 ) -> anyhow::Result<()> {
-  let height = ((geometry.height + offset_y) as f32) as i32;
-  let width = ((geometry.width + offset_x) as f32) as i32;
+  // the offset away from the monitor edge is reserved along with the bar, which is its height for top and bottom
+  // bars, but its width for side bars
+  let (width, height) = if position.is_vertical() {
+    (geometry.width + offset_x, geometry.height)
+  } else {
+    (geometry.width + offset_x, geometry.height + offset_y)
+  };
   let u_edge = match position {
     BarPosition::Top => ABE_TOP,
     BarPosition::Bottom => ABE_BOTTOM,
+    BarPosition::Left => ABE_LEFT,
+    BarPosition::Right => ABE_RIGHT,
   };
 
   let rc = windows::Win32::Foundation::RECT {
@@ -193,6 +201,14 @@ pub enum BarMsg {
 pub enum BarPosition {
   Top,
   Bottom,
+  Left,
+  Right,
+}
+
+impl BarPosition {
+  fn is_vertical(&self) -> bool {
+    matches!(self, BarPosition::Left | BarPosition::Right)
+  }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -207,10 +223,25 @@ pub struct BarProps {
   pub width: Option<i32>,
   pub height: Option<i32>,
   pub offset: Option<BarOffset>,
+  /// Children placed at the start of the bar
+  #[serde(default, deserialize_with = "deserialize_empty_or_seq")]
+  pub start: Option<Vec<Child>>,
+  /// Children kept centered on the bar, regardless of the size of `start` and `end`
+  #[serde(default, deserialize_with = "deserialize_empty_or_seq")]
+  pub center: Option<Vec<Child>>,
+  /// Children placed at the end of the bar
+  #[serde(default, deserialize_with = "deserialize_empty_or_seq")]
+  pub end: Option<Vec<Child>>,
   #[serde(flatten)]
   pub r#box: BoxProps,
 }
 
+fn section_box(orientation: Orientation, class: &str) -> GtkBox {
+  let section = GtkBox::new(orientation.into(), 0);
+  section.add_css_class(class);
+  section
+}
+
 pub struct Bar {
   position: BarPosition,
   geometry: MonitorGeometry,
@@ -238,7 +269,7 @@ impl Component for Bar {
 
   view! {
     Window {
-      set_default_size: (500, crate::common::HITOKAGE_STATUSBAR_HEIGHT),
+      set_default_size: default_size,
       set_resizable: false,
       set_display: &gdk4::Display::default().expect("Failed to get default display"),
       set_decorated: false,
@@ -246,7 +277,7 @@ impl Component for Bar {
 
       #[name = "main_box"]
       gtk::Box {
-        set_orientation: gtk::Orientation::Horizontal,
+        set_orientation: orientation.into(),
       },
 
       connect_realize => move |window| {
@@ -315,12 +346,31 @@ impl Component for Bar {
     let mut offset_x = 0;
     let mut offset_y = 0;
 
-    geometry.width = props.width.unwrap_or(geometry.width);
-    geometry.height = props.height.unwrap_or(crate::common::HITOKAGE_STATUSBAR_HEIGHT);
+    // side bars span the height of the monitor, and use the statusbar height as their width
+    if position.is_vertical() {
+      geometry.width = props.width.unwrap_or(crate::common::HITOKAGE_STATUSBAR_HEIGHT);
+      geometry.height = props.height.unwrap_or(geometry.height);
+    } else {
+      geometry.width = props.width.unwrap_or(geometry.width);
+      geometry.height = props.height.unwrap_or(crate::common::HITOKAGE_STATUSBAR_HEIGHT);
+    }
+
+    let orientation = props.r#box.orientation.unwrap_or(if position.is_vertical() {
+      Orientation::Vertical
+    } else {
+      Orientation::Horizontal
+    });
+
+    let default_size = if position.is_vertical() {
+      (crate::common::HITOKAGE_STATUSBAR_HEIGHT, 500)
+    } else {
+      (500, crate::common::HITOKAGE_STATUSBAR_HEIGHT)
+    };
 
     match position {
-      BarPosition::Top => (),
+      BarPosition::Top | BarPosition::Left => (),
       BarPosition::Bottom => geometry.y += monitor.geometry.height - geometry.height,
+      BarPosition::Right => geometry.x += monitor.geometry.width - geometry.width,
     };
 
     if let Some(offset) = props.offset {
@@ -361,6 +411,27 @@ impl Component for Bar {
       sender.output_sender()
     );
 
+    if props.start.is_some() || props.center.is_some() || props.end.is_some() {
+      let (start, center, end) = (
+        section_box(orientation, "start"),
+        section_box(orientation, "center"),
+        section_box(orientation, "end"),
+      );
+      generate_box_children!(props.start, model.r#box, monitor, start, sender.output_sender());
+      generate_box_children!(props.center, model.r#box, monitor, center, sender.output_sender());
+      generate_box_children!(props.end, model.r#box, monitor, end, sender.output_sender());
+
+      let center_box = gtk::CenterBox::builder()
+        .orientation(orientation.into())
+        .hexpand(orientation == Orientation::Horizontal)
+        .vexpand(orientation == Orientation::Vertical)
+        .start_widget(&start)
+        .center_widget(&center)
+        .end_widget(&end)
+        .build();
+      widgets.main_box.append(&center_box);
+    }
+
     // manually realize/show
     root.show();
 
@@ -1,6 +1,15 @@
+use crate::event::{LUA_ACTION_REQUESTS, LUA_WAKER, MAX_LUA_ACTION_REQUESTS};
+use crate::handlebar::register_hitokage_helpers;
+use crate::structs::lua_action::LuaActionRequest;
+use crate::structs::lua_fn::LuaFn;
+use crate::structs::reactive::Reactive;
+use crate::structs::reactive_string_fn::ReactiveStringFn;
 use crate::structs::{Align, CssClass};
+use gtk4::prelude::*;
+use handlebars::Handlebars;
 use serde::{Deserialize, Serialize};
-use std::sync::mpsc::Sender;
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug, Clone)]
 pub enum BaseMsgHook {
@@ -16,6 +25,8 @@ pub enum BaseMsgHook {
   GetId(Sender<Option<String>>),
   GetSizeRequest(Sender<(i32, i32)>),
   SetSizeRequest((Option<i32>, Option<i32>)),
+  GetTooltip(Sender<Option<String>>),
+  SetTooltip(Option<String>),
   GetValign(Sender<Align>),
   SetValign(Align),
   GetVexpand(Sender<bool>),
@@ -35,6 +46,16 @@ pub struct BaseProps {
   pub valign: Option<Align>,
   pub vexpand: Option<bool>,
   pub width_request: Option<i32>,
+  #[serde(skip_serializing)]
+  pub tooltip: Option<ReactiveStringFn>,
+  #[serde(skip_serializing)]
+  pub on_click: Option<LuaFn>,
+  #[serde(skip_serializing)]
+  pub on_scroll: Option<LuaFn>,
+  #[serde(skip_serializing)]
+  pub on_hover_enter: Option<LuaFn>,
+  #[serde(skip_serializing)]
+  pub on_hover_leave: Option<LuaFn>,
 }
 
 impl From<BaseProps> for Base {
@@ -47,6 +68,7 @@ impl From<BaseProps> for Base {
       hexpand: props.hexpand.unwrap_or(false),
       valign: props.valign,
       vexpand: props.vexpand.unwrap_or(false),
+      tooltip: Arc::new(Mutex::new(props.tooltip.map(Tooltip::from).unwrap_or_default())),
     }
   }
 }
@@ -60,6 +82,272 @@ pub struct Base {
   pub hexpand: bool,
   pub valign: Option<Align>,
   pub vexpand: bool,
+  pub tooltip: Arc<Mutex<Tooltip>>,
+}
+
+impl Base {
+  /// Sets the args the tooltip template is rendered against, ie. the same args used for `format`
+  pub fn set_tooltip_args<W: IsA<gtk4::Widget>>(&self, args: impl Serialize, root: &W) {
+    let mut tooltip = self.tooltip.lock().unwrap();
+    if tooltip.text.is_none() {
+      return;
+    }
+    tooltip.args = serde_json::to_value(args).ok();
+    // rendered once gtk queries the tooltip, only a visible tooltip needs refreshing
+    root.trigger_tooltip_query();
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct Tooltip {
+  pub text: Option<Reactive<String>>,
+  pub callback: Option<LuaFn>,
+  pub args: Option<serde_json::Value>,
+}
+
+impl From<ReactiveStringFn> for Tooltip {
+  fn from(value: ReactiveStringFn) -> Self {
+    // a shared reactive is only read from, its sender belongs to whichever component displays it
+    let text = match &value {
+      ReactiveStringFn::Str(str) => Reactive::new(str.clone()),
+      ReactiveStringFn::Reactive(reactive) => reactive.clone(),
+      ReactiveStringFn::Function(_) => Reactive::new(String::new()),
+    };
+    Tooltip {
+      text: Some(text),
+      callback: value.as_fn(),
+      args: None,
+    }
+  }
+}
+
+impl Tooltip {
+  /// Templates are only rendered if the component provided args, and never for the result of a lua function
+  fn render(&self) -> Option<String> {
+    let text = self.text.as_ref()?.get();
+    let rendered = match (&self.callback, &self.args) {
+      (None, Some(args)) => {
+        let reg = register_hitokage_helpers(Handlebars::new());
+        match reg.render_template(&text, args) {
+          Ok(rendered) => rendered,
+          Err(err) => {
+            log::error!("{:?}", err);
+            return None;
+          }
+        }
+      }
+      _ => text,
+    };
+
+    match rendered.is_empty() {
+      true => None,
+      false => Some(rendered),
+    }
+  }
+}
+
+/// Renders the tooltip whenever gtk is about to show it, so reactive changes are picked up without watching them.
+/// Lua function tooltips are evaluated on hover.
+pub fn connect_tooltip<W: IsA<gtk4::Widget>>(root: &W, id: Option<String>, tooltip: &Arc<Mutex<Tooltip>>) {
+  let guard = tooltip.lock().unwrap();
+  root.set_has_tooltip(guard.text.is_some());
+
+  {
+    let tooltip = Arc::clone(tooltip);
+    root.connect_query_tooltip(move |_, _, _, _, gtk_tooltip| match tooltip.lock().unwrap().render() {
+      Some(text) => {
+        gtk_tooltip.set_text(Some(&text));
+        true
+      }
+      None => false,
+    });
+  }
+
+  if guard.callback.is_some() {
+    // results of the lua function are forwarded back onto the main loop
+    let (result_sender, result_receiver) = relm4::channel::<String>();
+    {
+      let widget = root.upcast_ref::<gtk4::Widget>().downgrade();
+      let tooltip = Arc::clone(tooltip);
+      relm4::spawn_local(async move {
+        while let Some(result) = result_receiver.recv().await {
+          let Some(widget) = widget.upgrade() else {
+            break;
+          };
+          if let Some(text) = &tooltip.lock().unwrap().text {
+            text.set(result);
+          }
+          widget.trigger_tooltip_query();
+        }
+      });
+    }
+
+    let tooltip = Arc::clone(tooltip);
+    let motion = gtk4::EventControllerMotion::new();
+    motion.connect_enter(move |_, _, _| {
+      let (callback, args) = {
+        let guard = tooltip.lock().unwrap();
+        let Some(callback) = guard.callback.clone() else {
+          return;
+        };
+        let args = guard.args.clone().unwrap_or_else(|| serde_json::json!({ "id": id }));
+        (callback, args)
+      };
+
+      let rx = request_lua_action(&callback, args);
+      let result_sender = result_sender.clone();
+      // the receiver only resolves once the lua scheduler ran the function, or disconnects if it never will
+      relm4::spawn_blocking(move || match rx.recv() {
+        Ok(mlua::Value::String(s)) => {
+          let _ = result_sender.send(s.to_string_lossy());
+        }
+        Ok(v) => {
+          log::error!("Expected string for tooltip callback, received: {:?}", v);
+        }
+        Err(_) => {}
+      });
+    });
+    root.add_controller(motion);
+  }
+}
+
+/// Replaces the tooltip text. Setting `None` removes the tooltip.
+pub fn set_tooltip<W: IsA<gtk4::Widget>>(root: &W, tooltip: &Arc<Mutex<Tooltip>>, value: Option<String>) {
+  let mut guard = tooltip.lock().unwrap();
+  match (value, guard.text.clone()) {
+    (None, _) => {
+      guard.text = None;
+      guard.callback = None;
+    }
+    (Some(value), Some(text)) => {
+      *text.value.lock().unwrap() = value;
+      guard.callback = None;
+    }
+    (Some(value), None) => {
+      guard.callback = None;
+      guard.text = Some(Reactive::new(value));
+    }
+  }
+  root.set_has_tooltip(guard.text.is_some());
+  root.trigger_tooltip_query();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ScrollDirection {
+  Up,
+  Down,
+}
+
+/// The table passed to lua event handlers
+#[derive(Debug, Clone, Serialize)]
+pub struct EventContext {
+  pub id: Option<String>,
+  pub button: Option<u32>,
+  pub direction: Option<ScrollDirection>,
+  pub modifiers: Vec<String>,
+}
+
+impl EventContext {
+  pub fn new(id: Option<String>, modifiers: gdk4::ModifierType) -> Self {
+    EventContext {
+      id,
+      button: None,
+      direction: None,
+      modifiers: modifier_names(modifiers),
+    }
+  }
+}
+
+fn modifier_names(modifiers: gdk4::ModifierType) -> Vec<String> {
+  [
+    (gdk4::ModifierType::SHIFT_MASK, "shift"),
+    (gdk4::ModifierType::CONTROL_MASK, "ctrl"),
+    (gdk4::ModifierType::ALT_MASK, "alt"),
+    (gdk4::ModifierType::SUPER_MASK, "super"),
+  ]
+  .into_iter()
+  .filter(|(mask, _)| modifiers.contains(*mask))
+  .map(|(_, name)| name.to_string())
+  .collect()
+}
+
+/// Queues a lua function outside of the component message loop, the result is sent on the returned receiver
+pub fn request_lua_action(callback: &LuaFn, args: serde_json::Value) -> Receiver<mlua::Value> {
+  let (tx, rx) = std::sync::mpsc::channel::<_>();
+  let mut deque = LUA_ACTION_REQUESTS.write();
+  if deque.len() >= MAX_LUA_ACTION_REQUESTS {
+    log::error!("Lua action requests are full, dropping request");
+    return rx;
+  }
+  deque.push_back(LuaActionRequest {
+    id: callback.r.clone(),
+    args,
+    f: Some(tx),
+  });
+  drop(deque);
+  // the scheduler sleeps until it is woken, so it would otherwise only see this on the next komorebi event
+  LUA_WAKER.wake();
+  rx
+}
+
+/// Queues a lua event handler without waiting on its result
+pub fn request_lua_event(callback: &LuaFn, ctx: EventContext) {
+  request_lua_action(callback, serde_json::to_value(ctx).unwrap());
+}
+
+/// Attaches the gesture controllers for any event handlers in `props` to `root`
+pub fn connect_base_events<W: IsA<gtk4::Widget>>(root: &W, props: &BaseProps) {
+  if let Some(on_click) = props.on_click.clone() {
+    let id = props.id.clone();
+    let click = gtk4::GestureClick::builder().button(0).build();
+    click.connect_released(move |gesture, n_press, _, _| {
+      if n_press == 1 {
+        let mut ctx = EventContext::new(id.clone(), gesture.current_event_state());
+        ctx.button = Some(gesture.current_button());
+        request_lua_event(&on_click, ctx);
+      }
+    });
+    root.add_controller(click);
+  }
+
+  if let Some(on_scroll) = props.on_scroll.clone() {
+    let id = props.id.clone();
+    let scroll = gtk4::EventControllerScroll::new(gtk4::EventControllerScrollFlags::VERTICAL);
+    scroll.connect_scroll(move |controller, _, dy| {
+      let mut ctx = EventContext::new(id.clone(), controller.current_event_state());
+      ctx.direction = Some(if dy < 0.0 {
+        ScrollDirection::Up
+      } else {
+        ScrollDirection::Down
+      });
+      request_lua_event(&on_scroll, ctx);
+      glib::Propagation::Stop
+    });
+    root.add_controller(scroll);
+  }
+
+  if props.on_hover_enter.is_some() || props.on_hover_leave.is_some() {
+    let motion = gtk4::EventControllerMotion::new();
+    if let Some(on_hover_enter) = props.on_hover_enter.clone() {
+      let id = props.id.clone();
+      motion.connect_enter(move |controller, _, _| {
+        request_lua_event(
+          &on_hover_enter,
+          EventContext::new(id.clone(), controller.current_event_state()),
+        );
+      });
+    }
+    if let Some(on_hover_leave) = props.on_hover_leave.clone() {
+      let id = props.id.clone();
+      motion.connect_leave(move |controller| {
+        request_lua_event(
+          &on_hover_leave,
+          EventContext::new(id.clone(), controller.current_event_state()),
+        );
+      });
+    }
+    root.add_controller(motion);
+  }
 }
 
 #[macro_export]
@@ -117,6 +405,13 @@ macro_rules! generate_base_match_arms {
         tx.send($root.size_request()).unwrap();
       }
       BaseMsgHook::SetSizeRequest((width, height)) => $root.set_size_request(width.unwrap_or(-1), height.unwrap_or(-1)),
+      BaseMsgHook::GetTooltip(tx) => {
+        let tooltip = $self.base.tooltip.lock().unwrap();
+        tx.send(tooltip.text.as_ref().map(|text| text.get())).unwrap();
+      }
+      BaseMsgHook::SetTooltip(tooltip) => {
+        $crate::components::base::set_tooltip(&$root, &$self.base.tooltip, tooltip);
+      }
       BaseMsgHook::GetValign(tx) => {
         if let Some(valign) = $self.base.valign {
           tx.send(valign).unwrap();
@@ -166,5 +461,7 @@ macro_rules! set_initial_base_props {
     }
     $root.set_vexpand($self.base.vexpand);
     $root.set_vexpand_set(true);
+    $crate::components::base::connect_base_events(&$root, &$base_props);
+    $crate::components::base::connect_tooltip(&$root, $self.base.id.clone(), &$self.base.tooltip);
   };
 }
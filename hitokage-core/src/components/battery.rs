@@ -71,7 +71,7 @@ impl From<BatteryMsgOut> for BoxMsg {
 #[derive(Debug, Deserialize)]
 pub struct BatteryProps {
   #[serde(flatten)]
-  base: BaseProps,
+  pub(crate) base: BaseProps,
   format: ReactiveStringFn,
   #[serde(default)]
   icons: BatteryIcons,
@@ -218,6 +218,7 @@ impl AsyncComponent for Battery {
       }
       BatteryMsg::RequestBatteryLife => {
         self.set_battery(self.system.battery_life().await.into());
+        self.base.set_tooltip_args(self.battery.format_args(&self.icons), root);
       }
       BatteryMsg::Callback(tx) => {
         if let Some(callback) = &self.callback {
@@ -12,6 +12,7 @@ use crate::prepend_css_class_to_model;
 use crate::set_initial_base_props;
 use crate::set_initial_box_props;
 use crate::structs::Monitor;
+use crate::structs::Orientation;
 use gtk4::prelude::*;
 use gtk4::Widget;
 use relm4::prelude::*;
@@ -64,6 +65,8 @@ pub struct BoxProps {
   #[serde(flatten)]
   pub base: BaseProps,
   pub homogeneous: Option<bool>,
+  /// Defaults to `Horizontal`
+  pub orientation: Option<Orientation>,
 
   #[serde(default, deserialize_with = "deserialize_empty_or_seq", alias = "widgets")]
   pub children: Option<Vec<super::Child>>,
@@ -74,6 +77,7 @@ impl Default for BoxProps {
     Self {
       base: BaseProps::default(),
       homogeneous: None,
+      orientation: None,
       children: Some(Vec::new()),
     }
   }
@@ -99,7 +103,7 @@ impl Component for HitokageBox {
 
   view! {
     gtk::Box {
-      set_orientation: gtk::Orientation::Horizontal,
+      set_orientation: props.orientation.unwrap_or_default().into(),
     }
   }
 
@@ -164,6 +168,13 @@ macro_rules! generate_box_children {
           $root.append(controller.widget());
           $model.children.push(ChildController::Box(controller));
         }
+        Child::Button(inner_props) => {
+          let controller = $crate::components::button::Button::builder()
+            .launch(inner_props)
+            .forward($input_sender, |m| m.into());
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Button(controller));
+        }
         Child::Clock(inner_props) => {
           let controller = $crate::components::clock::Clock::builder().launch(inner_props).detach();
           $root.append(controller.widget());
@@ -174,16 +185,49 @@ macro_rules! generate_box_children {
           $root.append(controller.widget());
           $model.children.push(ChildController::Cpu(controller));
         }
+        Child::Disk(inner_props) => {
+          let controller = $crate::components::disk::Disk::builder()
+            .launch(inner_props)
+            .forward($input_sender, |m| m.into());
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Disk(controller));
+        }
+        Child::Graph(inner_props) => {
+          let controller = $crate::components::graph::Graph::builder().launch(inner_props).detach();
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Graph(controller));
+        }
         Child::Icon(inner_props) => {
           let controller = $crate::components::icon::Icon::builder().launch(inner_props).detach();
           $root.append(controller.widget());
           $model.children.push(ChildController::Icon(controller));
         }
+        Child::KomorebiLayout(inner_props) => {
+          let controller = $crate::components::komorebi_layout::KomorebiLayout::builder()
+            .launch((inner_props, monitor.id as u32))
+            .detach();
+          $root.append(controller.widget());
+          $model.children.push(ChildController::KomorebiLayout(controller));
+        }
         Child::Label(inner_props) => {
           let controller = $crate::components::label::Label::builder().launch(inner_props).detach();
           $root.append(controller.widget());
           $model.children.push(ChildController::Label(controller));
         }
+        Child::Level(inner_props) => {
+          let controller = $crate::components::level::Level::builder()
+            .launch(inner_props)
+            .forward($input_sender, |m| m.into());
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Level(controller));
+        }
+        Child::Media(inner_props) => {
+          let controller = $crate::components::media::Media::builder()
+            .launch(inner_props)
+            .forward($input_sender, |m| m.into());
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Media(controller));
+        }
         Child::Memory(inner_props) => {
           let controller = $crate::components::memory::Memory::builder()
             .launch(inner_props)
@@ -191,6 +235,55 @@ macro_rules! generate_box_children {
           $root.append(controller.widget());
           $model.children.push(ChildController::Memory(controller));
         }
+        Child::Network(inner_props) => {
+          let controller = $crate::components::network::Network::builder()
+            .launch(inner_props)
+            .forward($input_sender, |m| m.into());
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Network(controller));
+        }
+        Child::Revealer(inner_props) => {
+          let controller = $crate::components::revealer::Revealer::builder()
+            .launch((monitor, inner_props))
+            .forward($input_sender, |m| m.into());
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Revealer(controller));
+        }
+        Child::Scale(inner_props) => {
+          let controller = $crate::components::scale::Scale::builder()
+            .launch(inner_props)
+            .forward($input_sender, |m| m.into());
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Scale(controller));
+        }
+        Child::Stack(inner_props) => {
+          let controller = $crate::components::stack::Stack::builder()
+            .launch((monitor, inner_props))
+            .forward($input_sender, |m| m.into());
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Stack(controller));
+        }
+        Child::SystemInfo(inner_props) => {
+          let controller = $crate::components::system_info::SystemInfo::builder()
+            .launch(inner_props)
+            .forward($input_sender, |m| m.into());
+          $root.append(controller.widget());
+          $model.children.push(ChildController::SystemInfo(controller));
+        }
+        Child::Taskbar(inner_props) => {
+          let controller = $crate::components::taskbar::Taskbar::builder()
+            .launch((inner_props, monitor.id as u32))
+            .detach();
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Taskbar(controller));
+        }
+        Child::Temperature(inner_props) => {
+          let controller = $crate::components::temperature::Temperature::builder()
+            .launch(inner_props)
+            .forward($input_sender, |m| m.into());
+          $root.append(controller.widget());
+          $model.children.push(ChildController::Temperature(controller));
+        }
         Child::Weather(inner_props) => {
           let controller = $crate::components::weather::Weather::builder()
             .launch(inner_props)
@@ -198,9 +291,16 @@ macro_rules! generate_box_children {
           $root.append(controller.widget());
           $model.children.push(ChildController::Weather(controller));
         }
+        Child::WindowTitle(inner_props) => {
+          let controller = $crate::components::window_title::WindowTitle::builder()
+            .launch((inner_props, monitor.id as u32))
+            .detach();
+          $root.append(controller.widget());
+          $model.children.push(ChildController::WindowTitle(controller));
+        }
         Child::Workspace(inner_props) => {
           use $crate::components::workspace::Workspace;
-          let controller = Workspace::builder().launch((inner_props, monitor.id as u32)).detach();
+          let controller = Workspace::builder().launch((inner_props, monitor)).detach();
           $root.append(controller.widget());
           $model.children.push(ChildController::Workspace(controller));
         }
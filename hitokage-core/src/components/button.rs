@@ -0,0 +1,243 @@
+use super::app::AppMsg;
+use super::base::request_lua_event;
+use super::base::Base;
+use super::base::BaseProps;
+use super::base::EventContext;
+use super::base::ScrollDirection;
+use super::r#box::BoxMsg;
+use crate::components::base::BaseMsgHook;
+use crate::generate_base_match_arms;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::lua_fn::LuaFn;
+use crate::structs::reactive::create_react_sender;
+use crate::structs::reactive::AsReactive;
+use crate::structs::reactive::Reactive;
+use crate::structs::reactive_string::ReactiveString;
+use gtk4::prelude::*;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::Deserialize;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub enum ButtonMsgHook {
+  BaseHook(BaseMsgHook),
+  GetLabel(Sender<String>),
+  GetLabelReactive(Sender<Reactive<String>>),
+  SetLabel(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum ButtonMsg {
+  LuaHook(ButtonMsgHook),
+  React,
+  Click(u32, gdk4::ModifierType),
+  Scroll(ScrollDirection, gdk4::ModifierType),
+  Hover,
+}
+
+#[derive(Debug)]
+pub enum ButtonMsgOut {
+  RequestLuaAction(
+    Arc<mlua::RegistryKey>,
+    serde_json::Value,
+    std::sync::mpsc::Sender<mlua::Value>,
+  ),
+}
+
+impl From<ButtonMsgOut> for AppMsg {
+  fn from(value: ButtonMsgOut) -> Self {
+    match value {
+      ButtonMsgOut::RequestLuaAction(a, b, c) => AppMsg::RequestLuaAction(a, b, c),
+      #[allow(unreachable_patterns)]
+      _ => AppMsg::NoOp,
+    }
+  }
+}
+
+impl From<ButtonMsgOut> for BoxMsg {
+  fn from(value: ButtonMsgOut) -> Self {
+    match value {
+      ButtonMsgOut::RequestLuaAction(a, b, c) => BoxMsg::AppMsg(AppMsg::RequestLuaAction(a, b, c)),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ButtonProps {
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+  label: ReactiveString,
+  on_middle_click: Option<LuaFn>,
+  on_right_click: Option<LuaFn>,
+  on_scroll_up: Option<LuaFn>,
+  on_scroll_down: Option<LuaFn>,
+  on_hover: Option<LuaFn>,
+}
+
+#[tracker::track]
+pub struct Button {
+  #[tracker::do_not_track]
+  base: Base,
+  #[tracker::do_not_track]
+  label: Reactive<String>,
+  #[tracker::do_not_track]
+  on_click: Option<LuaFn>,
+  #[tracker::do_not_track]
+  on_middle_click: Option<LuaFn>,
+  #[tracker::do_not_track]
+  on_right_click: Option<LuaFn>,
+  #[tracker::do_not_track]
+  on_scroll_up: Option<LuaFn>,
+  #[tracker::do_not_track]
+  on_scroll_down: Option<LuaFn>,
+  #[tracker::do_not_track]
+  on_hover: Option<LuaFn>,
+  react: bool,
+}
+
+#[relm4::component(pub)]
+impl Component for Button {
+  type Input = ButtonMsg;
+  type Output = ButtonMsgOut;
+  type Init = ButtonProps;
+  type Widgets = ButtonWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::Button {
+      #[track = "model.changed(Button::react())"]
+      set_label: &model.label.get(),
+    }
+  }
+
+  fn init(mut props: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    // the button claims the primary click in the bubble phase, so the base `on_click` is handled by the capture phase
+    // gesture below instead of being connected with the other base events
+    let on_click = props.base.on_click.take();
+    let click = gtk::GestureClick::builder()
+      .button(0)
+      .propagation_phase(gtk::PropagationPhase::Capture)
+      .build();
+    {
+      let sender = sender.clone();
+      click.connect_released(move |gesture, n_press, _, _| {
+        if n_press == 1 {
+          sender.input(ButtonMsg::Click(
+            gesture.current_button(),
+            gesture.current_event_state(),
+          ));
+        }
+      });
+    }
+    root.add_controller(click);
+
+    let scroll = gtk::EventControllerScroll::new(gtk::EventControllerScrollFlags::VERTICAL);
+    {
+      let sender = sender.clone();
+      scroll.connect_scroll(move |controller, _, dy| {
+        let direction = if dy < 0.0 {
+          ScrollDirection::Up
+        } else {
+          ScrollDirection::Down
+        };
+        sender.input(ButtonMsg::Scroll(direction, controller.current_event_state()));
+        glib::Propagation::Stop
+      });
+    }
+    root.add_controller(scroll);
+
+    let motion = gtk::EventControllerMotion::new();
+    {
+      let sender = sender.clone();
+      motion.connect_enter(move |_, _, _| {
+        sender.input(ButtonMsg::Hover);
+      });
+    }
+    root.add_controller(motion);
+
+    let mut model = Button {
+      base: props.base.clone().into(),
+      label: props
+        .label
+        .as_reactive(create_react_sender(sender.input_sender(), ButtonMsg::React)),
+      on_click,
+      on_middle_click: props.on_middle_click,
+      on_right_click: props.on_right_click,
+      on_scroll_up: props.on_scroll_up,
+      on_scroll_down: props.on_scroll_down,
+      on_hover: props.on_hover,
+      react: false,
+      tracker: 0,
+    };
+
+    prepend_css_class_to_model!("button", model, root);
+    set_initial_base_props!(model, root, props.base);
+
+    let widgets = view_output!();
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      ButtonMsg::LuaHook(hook) => match hook {
+        ButtonMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "button", root, base)
+        }
+        ButtonMsgHook::GetLabel(tx) => {
+          tx.send(self.label.get()).unwrap();
+        }
+        ButtonMsgHook::GetLabelReactive(tx) => {
+          tx.send(self.label.clone()).unwrap();
+        }
+        ButtonMsgHook::SetLabel(label) => {
+          let arc = self.label.value.clone();
+          let mut str = arc.lock().unwrap();
+          *str = label;
+          self.set_react(!self.react);
+        }
+      },
+      ButtonMsg::React => {
+        self.set_react(!self.react);
+      }
+      ButtonMsg::Click(button, modifiers) => {
+        // the dedicated handlers take precedence, `on_click` is called for any other button like on every component
+        let callback = match button {
+          gdk4::BUTTON_MIDDLE if self.on_middle_click.is_some() => &self.on_middle_click,
+          gdk4::BUTTON_SECONDARY if self.on_right_click.is_some() => &self.on_right_click,
+          _ => &self.on_click,
+        };
+        if let Some(callback) = callback {
+          let mut event = EventContext::new(self.base.id.clone(), modifiers);
+          event.button = Some(button);
+          request_lua_event(callback, event);
+        }
+      }
+      ButtonMsg::Scroll(direction, modifiers) => {
+        let callback = match direction {
+          ScrollDirection::Up => &self.on_scroll_up,
+          ScrollDirection::Down => &self.on_scroll_down,
+        };
+        if let Some(callback) = callback {
+          let mut event = EventContext::new(self.base.id.clone(), modifiers);
+          event.direction = Some(direction);
+          request_lua_event(callback, event);
+        }
+      }
+      ButtonMsg::Hover => {
+        if let Some(callback) = &self.on_hover {
+          request_lua_event(
+            callback,
+            EventContext::new(self.base.id.clone(), gdk4::ModifierType::empty()),
+          );
+        }
+      }
+    }
+  }
+}
@@ -34,7 +34,7 @@ pub enum ClockMsg {
 pub struct ClockProps {
   format: ReactiveString,
   #[serde(flatten)]
-  base: BaseProps,
+  pub(crate) base: BaseProps,
 }
 
 pub struct Clock {
@@ -74,7 +74,7 @@ impl From<CpuMsgOut> for BoxMsg {
 #[derive(Debug, Deserialize)]
 pub struct CpuProps {
   #[serde(flatten)]
-  base: BaseProps,
+  pub(crate) base: BaseProps,
   format: ReactiveStringFn,
 }
 
@@ -207,6 +207,7 @@ impl Component for Cpu {
             let classes_ref: Vec<&str> = joined.iter().map(AsRef::as_ref).collect();
             root.set_css_classes(&classes_ref);
             self.set_cpu(res.into());
+            self.base.set_tooltip_args(cpu_format_args(self.cpu.as_slice()), root);
           }
           Err(err) => {
             log::error!("Failed to obtain CPU usage information: {}", err);
@@ -233,12 +234,12 @@ impl Component for Cpu {
 }
 
 #[derive(Debug, Clone)]
-struct CPULoadWrapper {
+pub(crate) struct CPULoadWrapper {
   cpu_loads: Vec<CPULoad>,
 }
 
 impl CPULoadWrapper {
-  fn new(value: impl Into<Vec<CPULoad>>) -> Self {
+  pub(crate) fn new(value: impl Into<Vec<CPULoad>>) -> Self {
     CPULoadWrapper {
       cpu_loads: value.into(),
     }
@@ -248,6 +249,16 @@ impl CPULoadWrapper {
     self.cpu_loads.as_slice()
   }
 
+  /// Average usage across all cores, between `0.0` and `1.0`
+  pub(crate) fn usage(&self) -> f32 {
+    let usage = self.as_lua_args().usage;
+    if usage.is_nan() {
+      0.0
+    } else {
+      usage
+    }
+  }
+
   fn as_lua_args(&self) -> CpuLoadInfo {
     let mut total_user = 0.0;
     let mut total_nice = 0.0;
@@ -339,6 +350,17 @@ struct CpuLoadInfo {
 fn format_cpu(format: &str, cpu_loads: &[CPULoad]) -> String {
   let reg = register_hitokage_helpers(Handlebars::new());
 
+  match reg.render_template(format, &cpu_format_args(cpu_loads)) {
+    Ok(name) => return name,
+    Err(err) => {
+      log::error!("{:?}", err);
+    }
+  };
+
+  "".to_owned()
+}
+
+fn cpu_format_args(cpu_loads: &[CPULoad]) -> HashMap<String, f32> {
   let mut args = HashMap::new();
   let mut total_user = 0.0;
   let mut total_nice = 0.0;
@@ -394,14 +416,7 @@ fn format_cpu(format: &str, cpu_loads: &[CPULoad]) -> String {
     },
   );
 
-  match reg.render_template(format, &args) {
-    Ok(name) => return name,
-    Err(err) => {
-      log::error!("{:?}", err);
-    }
-  };
-
-  "".to_owned()
+  args
 }
 
 // prepend numbers
@@ -0,0 +1,446 @@
+use super::app::AppMsg;
+use super::base::request_lua_action;
+use super::base::Base;
+use super::base::BaseProps;
+use super::r#box::BoxMsg;
+use crate::components::base::BaseMsgHook;
+use crate::generate_base_match_arms;
+use crate::handlebar::register_hitokage_helpers;
+use crate::prepend_css_class;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::lua_fn::LuaFn;
+use crate::structs::reactive::create_react_sender;
+use crate::structs::reactive::AsReactive;
+use crate::structs::reactive::Reactive;
+use crate::structs::reactive_string_fn::ReactiveStringFn;
+use gtk4::prelude::*;
+use handlebars::Handlebars;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use systemstat::Platform;
+use systemstat::System;
+
+const BYTES_TO_GB: f64 = 1_073_741_824.0;
+
+#[cfg(target_os = "windows")]
+const DEFAULT_MOUNT: &str = "C:\\";
+#[cfg(not(target_os = "windows"))]
+const DEFAULT_MOUNT: &str = "/";
+
+#[derive(Debug, Clone)]
+pub enum DiskMsgHook {
+  BaseHook(BaseMsgHook),
+  GetFormat(Sender<String>),
+  GetFormatReactive(Sender<Reactive<String>>),
+  SetFormat(String),
+  GetMounts(Sender<Vec<String>>),
+  SetMounts(Vec<String>),
+}
+
+#[derive(Debug, Clone)]
+pub enum DiskMsg {
+  LuaHook(DiskMsgHook),
+  React,
+  Tick,
+  Callback,
+  /// The result of the `format` lua function
+  Formatted(String),
+}
+
+#[derive(Debug)]
+pub enum DiskMsgOut {
+  RequestLuaAction(
+    Arc<mlua::RegistryKey>,
+    serde_json::Value,
+    std::sync::mpsc::Sender<mlua::Value>,
+  ),
+}
+
+impl From<DiskMsgOut> for AppMsg {
+  fn from(value: DiskMsgOut) -> Self {
+    match value {
+      DiskMsgOut::RequestLuaAction(a, b, c) => AppMsg::RequestLuaAction(a, b, c),
+      #[allow(unreachable_patterns)]
+      _ => AppMsg::NoOp,
+    }
+  }
+}
+
+impl From<DiskMsgOut> for BoxMsg {
+  fn from(value: DiskMsgOut) -> Self {
+    match value {
+      DiskMsgOut::RequestLuaAction(a, b, c) => BoxMsg::AppMsg(AppMsg::RequestLuaAction(a, b, c)),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum DiskMount {
+  Str(String),
+  Vec(Vec<String>),
+}
+
+impl From<DiskMount> for Vec<String> {
+  fn from(value: DiskMount) -> Self {
+    match value {
+      DiskMount::Str(s) => vec![s],
+      DiskMount::Vec(v) => v,
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DiskProps {
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+  format: ReactiveStringFn,
+  mount: Option<DiskMount>,
+}
+
+#[tracker::track]
+pub struct Disk {
+  #[tracker::do_not_track]
+  base: Base,
+  disk: DiskWrapper,
+  #[tracker::do_not_track]
+  mounts: Vec<String>,
+  /// Mounts that were already warned about, so a missing drive is not logged on every tick
+  #[tracker::do_not_track]
+  missing_mounts: HashSet<String>,
+  #[tracker::do_not_track]
+  source_id: Option<glib::SourceId>,
+  #[tracker::do_not_track]
+  format: Reactive<String>,
+  #[tracker::do_not_track]
+  sys: System,
+  react: bool,
+  #[tracker::do_not_track]
+  callback: Option<LuaFn>,
+}
+
+#[relm4::component(pub)]
+impl Component for Disk {
+  type Input = DiskMsg;
+  type Output = DiskMsgOut;
+  type Init = DiskProps;
+  type Widgets = DiskWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::Label {
+      #[track = "model.changed(Disk::react() | Disk::disk())"]
+      set_label: &format_disk(&model.format.get(), &model.disk),
+    }
+  }
+
+  fn init(props: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    let callback = props.format.as_fn();
+    let reactive = props
+      .format
+      .as_reactive(create_react_sender(sender.input_sender(), DiskMsg::React));
+
+    let source_id = {
+      let sender = sender.clone();
+
+      match callback {
+        Some(_) => glib::timeout_add_local(std::time::Duration::from_secs(1), move || {
+          sender.input(DiskMsg::Tick);
+          sender.input(DiskMsg::Callback);
+          glib::ControlFlow::Continue
+        }),
+        None => glib::timeout_add_local(std::time::Duration::from_secs(1), move || {
+          sender.input(DiskMsg::Tick);
+          glib::ControlFlow::Continue
+        }),
+      }
+    };
+
+    let sys = System::new();
+    let mounts: Vec<String> = props
+      .mount
+      .map_or_else(|| vec![DEFAULT_MOUNT.to_string()], |mount| mount.into());
+
+    let mut missing_mounts = HashSet::new();
+
+    let mut model = Disk {
+      base: props.base.clone().into(),
+      disk: DiskWrapper::new(&sys, &mounts, &mut missing_mounts),
+      mounts,
+      missing_mounts,
+      source_id: Some(source_id),
+      format: reactive,
+      callback,
+      react: false,
+      tracker: 0,
+      sys,
+    };
+
+    prepend_css_class_to_model!("disk", model, root);
+    set_initial_base_props!(model, root, props.base);
+
+    let widgets = view_output!();
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      DiskMsg::LuaHook(hook) => match hook {
+        DiskMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "disk", root, base)
+        }
+        DiskMsgHook::GetFormat(tx) => {
+          tx.send(self.format.get()).unwrap();
+        }
+        DiskMsgHook::GetFormatReactive(tx) => {
+          tx.send(self.format.clone()).unwrap();
+        }
+        DiskMsgHook::SetFormat(format) => {
+          let arc = self.format.value.clone();
+          let mut str = arc.lock().unwrap();
+          *str = format;
+          self.set_react(!self.react);
+        }
+        DiskMsgHook::GetMounts(tx) => {
+          tx.send(self.mounts.clone()).unwrap();
+        }
+        DiskMsgHook::SetMounts(mounts) => {
+          self.mounts = mounts;
+          sender.input(DiskMsg::Tick);
+        }
+      },
+      DiskMsg::React => {
+        self.set_react(!self.react);
+      }
+      DiskMsg::Tick => {
+        let disk = DiskWrapper::new(&self.sys, &self.mounts, &mut self.missing_mounts);
+        self.base.classes_temp = generate_disk_classes(&disk);
+        let joined = prepend_css_class!(self.base.classes.clone(), self.base.classes_temp.clone());
+        let classes_ref: Vec<&str> = joined.iter().map(AsRef::as_ref).collect();
+        root.set_css_classes(&classes_ref);
+        self.set_disk(disk);
+      }
+      DiskMsg::Callback => {
+        if let Some(callback) = &self.callback {
+          let rx = request_lua_action(callback, serde_json::to_value(self.disk.as_lua_args()).unwrap());
+          let input = sender.input_sender().clone();
+          // the receiver only resolves once the lua scheduler ran the function, or disconnects if it never will
+          relm4::spawn_blocking(move || match rx.recv() {
+            Ok(mlua::Value::String(s)) => {
+              let _ = input.send(DiskMsg::Formatted(s.to_string_lossy()));
+            }
+            Ok(v) => {
+              log::error!("Expected string for disk callback, received: {:?}", v);
+            }
+            Err(_) => {}
+          });
+        }
+      }
+      DiskMsg::Formatted(format) => {
+        self.format.set(format);
+      }
+    }
+  }
+
+  fn shutdown(&mut self, _widgets: &mut Self::Widgets, _output: relm4::Sender<Self::Output>) {
+    if let Some(a) = self.source_id.take() {
+      glib::SourceId::remove(a)
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+struct DiskWrapper {
+  filesystems: Vec<(String, Option<systemstat::Filesystem>)>,
+}
+
+impl DiskWrapper {
+  fn new(sys: &System, mounts: &[String], missing_mounts: &mut HashSet<String>) -> Self {
+    let available = match sys.mounts() {
+      Ok(available) => available,
+      Err(err) => {
+        log::error!("Failed to fetch mounted filesystems: {}", err);
+        Vec::new()
+      }
+    };
+
+    let filesystems = mounts
+      .iter()
+      .map(|mount| {
+        let fs = available
+          .iter()
+          .find(|fs| same_mount(&fs.fs_mounted_on, mount))
+          .cloned();
+        match fs {
+          // warn again should it go missing after being mounted
+          Some(_) => {
+            missing_mounts.remove(mount);
+          }
+          None if !available.is_empty() && missing_mounts.insert(mount.clone()) => {
+            log::warn!("No filesystem is mounted at {}", mount);
+          }
+          None => {}
+        }
+        (mount.clone(), fs)
+      })
+      .collect();
+
+    DiskWrapper { filesystems }
+  }
+
+  fn as_lua_args(&self) -> DiskInfo {
+    let mounts: Vec<DiskMountInfo> = self
+      .filesystems
+      .iter()
+      .map(|(mount, fs)| match fs {
+        Some(fs) => {
+          let total = fs.total.as_u64() as f64 / BYTES_TO_GB;
+          // blocks reserved for root are free but not available, they don't count as used like in `df`
+          let free = fs.free.as_u64() as f64 / BYTES_TO_GB;
+          let avail = fs.avail.as_u64() as f64 / BYTES_TO_GB;
+          DiskMountInfo {
+            mount: mount.clone(),
+            name: fs.fs_mounted_from.clone(),
+            fs_type: fs.fs_type.clone(),
+            used: total - free,
+            free,
+            avail,
+            total,
+            percent: percent(total - free, total),
+          }
+        }
+        None => DiskMountInfo {
+          mount: mount.clone(),
+          name: String::new(),
+          fs_type: String::new(),
+          used: 0.0,
+          free: 0.0,
+          avail: 0.0,
+          total: 0.0,
+          percent: 0.0,
+        },
+      })
+      .collect();
+
+    let used = mounts.iter().map(|m| m.used).sum();
+    let free = mounts.iter().map(|m| m.free).sum();
+    let avail = mounts.iter().map(|m| m.avail).sum();
+    let total = mounts.iter().map(|m| m.total).sum();
+
+    DiskInfo {
+      used,
+      free,
+      avail,
+      total,
+      percent: percent(used, total),
+      mounts,
+    }
+  }
+}
+
+impl PartialEq for DiskWrapper {
+  fn eq(&self, other: &Self) -> bool {
+    self.filesystems.len() == other.filesystems.len()
+      && self
+        .filesystems
+        .iter()
+        .zip(other.filesystems.iter())
+        .all(|((a_mount, a), (b_mount, b))| {
+          a_mount == b_mount
+            && match (a, b) {
+              (Some(a), Some(b)) => a.free == b.free && a.avail == b.avail && a.total == b.total,
+              (None, None) => true,
+              _ => false,
+            }
+        })
+  }
+}
+
+fn same_mount(mounted_on: &str, mount: &str) -> bool {
+  let trim = |s: &str| {
+    let trimmed = s.trim_end_matches(['/', '\\']);
+    if trimmed.is_empty() {
+      s.to_string()
+    } else {
+      trimmed.to_string()
+    }
+  };
+
+  if cfg!(target_os = "windows") {
+    trim(mounted_on).eq_ignore_ascii_case(&trim(mount))
+  } else {
+    trim(mounted_on) == trim(mount)
+  }
+}
+
+fn percent(used: f64, total: f64) -> f64 {
+  if total > 0.0 {
+    used / total * 100.0
+  } else {
+    0.0
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DiskMountInfo {
+  mount: String,
+  name: String,
+  fs_type: String,
+  used: f64,
+  free: f64,
+  /// Free space usable without root, ie. less than `free` on filesystems with reserved blocks
+  avail: f64,
+  total: f64,
+  percent: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DiskInfo {
+  used: f64,
+  free: f64,
+  avail: f64,
+  total: f64,
+  percent: f64,
+  mounts: Vec<DiskMountInfo>,
+}
+
+fn format_disk(format: &str, disk: &DiskWrapper) -> String {
+  let reg = register_hitokage_helpers(Handlebars::new());
+
+  match reg.render_template(format, &disk.as_lua_args()) {
+    Ok(name) => return name,
+    Err(err) => {
+      log::error!("{:?}", err);
+    }
+  };
+
+  "".to_owned()
+}
+
+// prepend numbers
+fn generate_disk_classes(disk: &DiskWrapper) -> Vec<String> {
+  let mut class_names = vec!["disk".to_string()];
+  let info = disk.as_lua_args();
+
+  for (index, mount) in info.mounts.iter().enumerate() {
+    for percent in (10..=mount.percent.trunc() as i32).step_by(10) {
+      class_names.push(format!("mount{}-usage-{}", index, percent));
+    }
+  }
+
+  for percent in (10..=info.percent.trunc() as i32).step_by(10) {
+    class_names.push(format!("usage-{}", percent));
+  }
+
+  class_names
+}
@@ -0,0 +1,333 @@
+use super::base::Base;
+use super::base::BaseMsgHook;
+use super::base::BaseProps;
+use super::cpu::CPULoadWrapper;
+use super::memory::MemoryAndSwapWrapper;
+use super::network::NetworkWrapper;
+use crate::generate_base_match_arms;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::reactive::Reactive;
+use gtk4::prelude::*;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::{Deserialize, Deserializer};
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use systemstat::{CPULoad, DelayedMeasurement, Platform, System};
+
+const DEFAULT_LENGTH: usize = 60;
+const DEFAULT_INTERVAL: u64 = 1000;
+
+#[derive(Debug, Clone)]
+pub enum GraphMsgHook {
+  BaseHook(BaseMsgHook),
+  GetSamples(Sender<Vec<f64>>),
+  GetMin(Sender<f64>),
+  SetMin(Option<f64>),
+  GetMax(Sender<Option<f64>>),
+  SetMax(Option<f64>),
+}
+
+#[derive(Debug, Clone)]
+pub enum GraphMsg {
+  LuaHook(GraphMsgHook),
+  Tick,
+}
+
+/// Where the graph reads its samples from
+#[derive(Debug, Clone)]
+pub enum GraphSource {
+  /// Overall cpu usage as a percentage
+  Cpu,
+  /// Memory used as a percentage
+  Memory,
+  /// Received KiB/s on the default interface
+  NetworkRx,
+  /// Transmitted KiB/s on the default interface
+  NetworkTx,
+  /// A reactive holding a number, ie. `"42.5"`
+  Reactive(Reactive<String>),
+}
+
+impl GraphSource {
+  fn default_max(&self) -> Option<f64> {
+    match self {
+      GraphSource::Cpu | GraphSource::Memory => Some(100.0),
+      _ => None,
+    }
+  }
+}
+
+struct GraphSourceVisitor;
+
+impl serde::de::Visitor<'_> for GraphSourceVisitor {
+  type Value = GraphSource;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("one of 'Cpu', 'Memory', 'NetworkRx', 'NetworkTx' or a reactive")
+  }
+
+  fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    match v {
+      "Cpu" => Ok(GraphSource::Cpu),
+      "Memory" => Ok(GraphSource::Memory),
+      "NetworkRx" => Ok(GraphSource::NetworkRx),
+      "NetworkTx" => Ok(GraphSource::NetworkTx),
+      _ => Err(E::unknown_variant(v, &["Cpu", "Memory", "NetworkRx", "NetworkTx"])),
+    }
+  }
+
+  fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(GraphSource::Reactive(crate::structs::reactive_string::parse_bytes(
+      value,
+    )))
+  }
+}
+
+impl<'de> Deserialize<'de> for GraphSource {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_any(GraphSourceVisitor)
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GraphProps {
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+  source: GraphSource,
+  /// Number of samples to keep
+  length: Option<usize>,
+  /// Sample interval in milliseconds
+  interval: Option<u64>,
+  min: Option<f64>,
+  /// Defaults to `100` for percentages, otherwise scales to the largest sample
+  max: Option<f64>,
+}
+
+#[derive(Debug)]
+struct GraphHistory {
+  samples: VecDeque<f64>,
+  length: usize,
+  min: f64,
+  max: Option<f64>,
+}
+
+impl GraphHistory {
+  fn push(&mut self, sample: f64) {
+    while self.samples.len() >= self.length {
+      self.samples.pop_front();
+    }
+    self.samples.push_back(sample);
+  }
+}
+
+pub struct Graph {
+  base: Base,
+  source: GraphSource,
+  sys: System,
+  cpu_inflight: Option<DelayedMeasurement<Vec<CPULoad>>>,
+  network: Option<NetworkWrapper>,
+  history: Arc<Mutex<GraphHistory>>,
+  source_id: Option<glib::SourceId>,
+}
+
+impl Graph {
+  fn sample(&mut self) -> Option<f64> {
+    match &self.source {
+      GraphSource::Cpu => {
+        let sample = self.cpu_inflight.take().and_then(|inflight| match inflight.done() {
+          Ok(loads) => Some(CPULoadWrapper::new(loads).usage() as f64 * 100.0),
+          Err(err) => {
+            log::error!("Failed to obtain CPU usage information: {}", err);
+            None
+          }
+        });
+        self.cpu_inflight = self.sys.cpu_load().ok();
+        sample
+      }
+      GraphSource::Memory => MemoryAndSwapWrapper::from(self.sys.memory_and_swap()).used_percent(),
+      GraphSource::NetworkRx | GraphSource::NetworkTx => {
+        let network = NetworkWrapper::sample(&self.sys, None, self.network.as_ref());
+        // the first sample has nothing to diff against
+        let sample = self.network.as_ref().map(|_| match self.source {
+          GraphSource::NetworkRx => network.rx_rate(),
+          _ => network.tx_rate(),
+        });
+        self.network = Some(network);
+        sample
+      }
+      GraphSource::Reactive(reactive) => {
+        let value = reactive.get();
+        match value.trim().parse::<f64>() {
+          Ok(sample) => Some(sample),
+          Err(_) => {
+            log::error!("Expected a number for graph reactive, received: {:?}", value);
+            None
+          }
+        }
+      }
+    }
+  }
+}
+
+#[relm4::component(pub)]
+impl Component for Graph {
+  type Input = GraphMsg;
+  type Output = ();
+  type Init = GraphProps;
+  type Widgets = GraphWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::DrawingArea {
+      set_content_width: 60,
+      set_content_height: 16,
+    }
+  }
+
+  fn init(props: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    let interval = std::time::Duration::from_millis(props.interval.unwrap_or(DEFAULT_INTERVAL).max(1));
+    let source_id = {
+      let sender = sender.clone();
+      glib::timeout_add_local(interval, move || {
+        sender.input(GraphMsg::Tick);
+        glib::ControlFlow::Continue
+      })
+    };
+
+    let history = Arc::new(Mutex::new(GraphHistory {
+      samples: VecDeque::new(),
+      length: props.length.unwrap_or(DEFAULT_LENGTH).max(2),
+      min: props.min.unwrap_or(0.0),
+      max: props.max.or(props.source.default_max()),
+    }));
+
+    {
+      let history = Arc::clone(&history);
+      root.set_draw_func(move |area, cr, width, height| {
+        draw_graph(area, cr, width as f64, height as f64, &history.lock().unwrap());
+      });
+    }
+
+    let sys = System::new();
+
+    let mut model = Graph {
+      base: props.base.clone().into(),
+      cpu_inflight: sys.cpu_load().ok(),
+      sys,
+      source: props.source,
+      network: None,
+      history,
+      source_id: Some(source_id),
+    };
+
+    prepend_css_class_to_model!("graph", model, root);
+    set_initial_base_props!(model, root, props.base);
+
+    let widgets = view_output!();
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      GraphMsg::LuaHook(hook) => match hook {
+        GraphMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "graph", root, base)
+        }
+        GraphMsgHook::GetSamples(tx) => {
+          tx.send(self.history.lock().unwrap().samples.iter().copied().collect())
+            .unwrap();
+        }
+        GraphMsgHook::GetMin(tx) => {
+          tx.send(self.history.lock().unwrap().min).unwrap();
+        }
+        GraphMsgHook::SetMin(min) => {
+          self.history.lock().unwrap().min = min.unwrap_or(0.0);
+          root.queue_draw();
+        }
+        GraphMsgHook::GetMax(tx) => {
+          tx.send(self.history.lock().unwrap().max).unwrap();
+        }
+        GraphMsgHook::SetMax(max) => {
+          self.history.lock().unwrap().max = max;
+          root.queue_draw();
+        }
+      },
+      GraphMsg::Tick => {
+        if let Some(sample) = self.sample() {
+          self.history.lock().unwrap().push(sample);
+          root.queue_draw();
+        }
+      }
+    }
+  }
+
+  fn shutdown(&mut self, _widgets: &mut Self::Widgets, _output: relm4::Sender<Self::Output>) {
+    if let Some(a) = self.source_id.take() {
+      glib::SourceId::remove(a)
+    }
+  }
+}
+
+/// Draws the history right aligned, so new samples enter from the right. The line uses the css `color`, and the area
+/// beneath it uses the same color at a quarter of the opacity.
+fn draw_graph(area: &gtk4::DrawingArea, cr: &gtk4::cairo::Context, width: f64, height: f64, history: &GraphHistory) {
+  let samples = &history.samples;
+  if samples.len() < 2 {
+    return;
+  }
+
+  let max = history
+    .max
+    .unwrap_or_else(|| samples.iter().copied().fold(history.min, f64::max));
+  let range = (max - history.min).max(f64::EPSILON);
+  let step = width / (history.length - 1) as f64;
+  let offset = (history.length - samples.len()) as f64 * step;
+  let y = |sample: f64| height - ((sample - history.min) / range).clamp(0.0, 1.0) * height;
+  let last_x = offset + (samples.len() - 1) as f64 * step;
+
+  let color = area.style_context().color();
+  let (red, green, blue, alpha) = (
+    color.red() as f64,
+    color.green() as f64,
+    color.blue() as f64,
+    color.alpha() as f64,
+  );
+
+  cr.move_to(offset, height);
+  for (i, sample) in samples.iter().enumerate() {
+    cr.line_to(offset + i as f64 * step, y(*sample));
+  }
+  cr.line_to(last_x, height);
+  cr.close_path();
+  cr.set_source_rgba(red, green, blue, alpha * 0.25);
+  if let Err(err) = cr.fill() {
+    log::error!("Failed to fill graph: {}", err);
+  }
+
+  cr.move_to(offset, y(samples[0]));
+  for (i, sample) in samples.iter().enumerate().skip(1) {
+    cr.line_to(offset + i as f64 * step, y(*sample));
+  }
+  cr.set_source_rgba(red, green, blue, alpha);
+  cr.set_line_width(1.0);
+  if let Err(err) = cr.stroke() {
+    log::error!("Failed to stroke graph: {}", err);
+  }
+}
@@ -33,7 +33,7 @@ pub enum IconMsg {
 #[derive(Debug, Deserialize)]
 pub struct IconProps {
   #[serde(flatten)]
-  base: BaseProps,
+  pub(crate) base: BaseProps,
   file: ReactiveString,
 }
 
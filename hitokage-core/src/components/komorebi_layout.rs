@@ -0,0 +1,283 @@
+use super::base::{Base, BaseMsgHook, BaseProps};
+use crate::event::STATE;
+use crate::handlebar::register_hitokage_helpers;
+use crate::komorebi::{get_focused_workspace, get_focused_workspace_index, get_layout_name};
+use crate::{generate_base_match_arms, prepend_css_class_to_model, set_initial_base_props};
+use gtk4::prelude::*;
+use handlebars::Handlebars;
+use komorebi_client::DefaultLayout;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::Sender;
+
+#[derive(Debug, Clone)]
+pub enum KomorebiLayoutMsgHook {
+  BaseHook(BaseMsgHook),
+  GetFormat(Sender<String>),
+  SetFormat(String),
+  GetLayout(Sender<Option<String>>),
+  Next,
+  Previous,
+}
+
+#[derive(Debug, Clone)]
+pub enum KomorebiLayoutMsg {
+  Layout(Option<LayoutState>),
+  LuaHook(KomorebiLayoutMsgHook),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KomorebiLayoutProps {
+  /// Handlebars template, defaults to `{{mode}}`
+  format: Option<String>,
+  /// Cycle layouts when clicked, defaults to `true`
+  click: Option<bool>,
+  /// Cycle layouts when scrolled over, defaults to `true`
+  scroll: Option<bool>,
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LayoutState {
+  /// `BSP`, `Columns`, ..., or `Custom`
+  layout: String,
+  /// The layout unless a mode overrides it, ie. `Paused`, `Monocle`, `Maximized` or `Float`
+  mode: String,
+  monocle: bool,
+  maximized: bool,
+  paused: bool,
+  floating: bool,
+}
+
+impl LayoutState {
+  fn modes(&self) -> [(&'static str, bool); 4] {
+    [
+      ("paused", self.paused),
+      ("monocle", self.monocle),
+      ("maximized", self.maximized),
+      ("floating", self.floating),
+    ]
+  }
+}
+
+#[tracker::track]
+pub struct KomorebiLayout {
+  #[tracker::do_not_track]
+  base: Base,
+  #[tracker::do_not_track]
+  id: u32, // win id
+  layout: Option<LayoutState>,
+  format: String,
+}
+
+impl KomorebiLayout {
+  /// Changes the layout of the focused workspace on this monitor, custom layouts restart from `BSP`
+  fn cycle(&self, next: bool) {
+    let state = STATE.read();
+    let (monitor_index, workspace_index) = match get_focused_workspace_index(&state, self.id) {
+      Ok(indices) => indices,
+      Err(err) => {
+        log::error!("Failed to read focused workspace: {:?}", err);
+        return;
+      }
+    };
+
+    let layout = get_focused_workspace(&state, self.id)
+      .ok()
+      .and_then(|workspace| workspace.get("layout"))
+      .and_then(|layout| layout.get("Default"))
+      .and_then(|layout| serde_json::from_value::<DefaultLayout>(layout.clone()).ok());
+
+    let layout = match layout {
+      Some(layout) if next => layout.cycle_next(),
+      Some(layout) => layout.cycle_previous(),
+      None => DefaultLayout::BSP,
+    };
+
+    log::info!("hitokage is changing layout to {:?}", layout);
+    let _ = komorebi_client::send_message(&komorebi_client::SocketMessage::WorkspaceLayout(
+      monitor_index,
+      workspace_index,
+      layout,
+    ));
+  }
+}
+
+#[relm4::component(pub)]
+impl Component for KomorebiLayout {
+  type Input = KomorebiLayoutMsg;
+  type Output = ();
+  type Init = (KomorebiLayoutProps, u32); // win id
+  type CommandOutput = ();
+
+  view! {
+    gtk::Label {
+      #[track = "model.changed(KomorebiLayout::layout() | KomorebiLayout::format())"]
+      set_label: &format_layout(&model.format, &model.layout),
+    }
+  }
+
+  fn init(propst: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    let (props, id) = propst;
+
+    if props.click.unwrap_or(true) {
+      let click = gtk::GestureClick::builder().button(0).build();
+      {
+        let sender = sender.clone();
+        click.connect_released(move |gesture, n_press, _, _| {
+          if n_press == 1 {
+            let hook = match gesture.current_button() {
+              3 => KomorebiLayoutMsgHook::Previous,
+              _ => KomorebiLayoutMsgHook::Next,
+            };
+            sender.input(KomorebiLayoutMsg::LuaHook(hook));
+          }
+        });
+      }
+      root.add_controller(click);
+    }
+
+    if props.scroll.unwrap_or(true) {
+      let scroll = gtk::EventControllerScroll::new(
+        gtk::EventControllerScrollFlags::VERTICAL | gtk::EventControllerScrollFlags::DISCRETE,
+      );
+      {
+        let sender = sender.clone();
+        scroll.connect_scroll(move |_, _, dy| {
+          let hook = if dy < 0.0 {
+            KomorebiLayoutMsgHook::Previous
+          } else {
+            KomorebiLayoutMsgHook::Next
+          };
+          sender.input(KomorebiLayoutMsg::LuaHook(hook));
+          glib::Propagation::Stop
+        });
+      }
+      root.add_controller(scroll);
+    }
+
+    let mut model = KomorebiLayout {
+      base: props.base.clone().into(),
+      id,
+      layout: None,
+      format: props.format.unwrap_or("{{mode}}".to_string()),
+      tracker: 0,
+    };
+
+    prepend_css_class_to_model!("komorebi_layout", model, root);
+    set_initial_base_props!(model, root, props.base);
+
+    STATE.subscribe(sender.input_sender(), move |state| {
+      KomorebiLayoutMsg::Layout(get_layout(state, id))
+    });
+
+    let widgets = view_output!();
+
+    sender.input(KomorebiLayoutMsg::Layout(get_layout(&STATE.read(), id)));
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      KomorebiLayoutMsg::Layout(layout) => {
+        if layout != self.layout {
+          // mirror the modes as css classes so they can be styled, ie. `.komorebi_layout.monocle`
+          for (class, active) in layout.clone().unwrap_or_default().modes() {
+            if active {
+              root.add_css_class(class);
+            } else {
+              root.remove_css_class(class);
+            }
+          }
+          self.base.set_tooltip_args(&layout, root);
+          self.set_layout(layout);
+        }
+      }
+      KomorebiLayoutMsg::LuaHook(hook) => match hook {
+        KomorebiLayoutMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "komorebi_layout", root, base)
+        }
+        KomorebiLayoutMsgHook::GetFormat(tx) => {
+          tx.send(self.format.clone()).unwrap();
+        }
+        KomorebiLayoutMsgHook::SetFormat(format) => {
+          self.set_format(format);
+        }
+        KomorebiLayoutMsgHook::GetLayout(tx) => {
+          tx.send(self.layout.as_ref().map(|layout| layout.layout.clone()))
+            .unwrap();
+        }
+        KomorebiLayoutMsgHook::Next => self.cycle(true),
+        KomorebiLayoutMsgHook::Previous => self.cycle(false),
+      },
+    }
+  }
+}
+
+// get the layout and modes of the focused workspace on this monitor from komorebi
+fn get_layout(state: &serde_json::Value, monitor_id: u32) -> Option<LayoutState> {
+  // komorebi has not sent us a state yet
+  if state.is_null() {
+    return None;
+  }
+
+  let workspace = match get_focused_workspace(state, monitor_id) {
+    Ok(workspace) => workspace,
+    Err(err) => {
+      log::error!("Failed to read focused workspace: {:?}", err);
+      return None;
+    }
+  };
+
+  let layout = get_layout_name(workspace);
+  let is_set = |key: &str| workspace.get(key).map_or(false, |v| !v.is_null());
+
+  let monocle = is_set("monocle_container");
+  let maximized = is_set("maximized_window");
+  let paused = state.get("is_paused").and_then(|v| v.as_bool()).unwrap_or(false);
+  let floating = !workspace.get("tile").and_then(|v| v.as_bool()).unwrap_or(true)
+    || workspace.get("layer").and_then(|v| v.as_str()) == Some("Floating");
+
+  let mode = if paused {
+    "Paused".to_string()
+  } else if monocle {
+    "Monocle".to_string()
+  } else if maximized {
+    "Maximized".to_string()
+  } else if floating {
+    "Float".to_string()
+  } else {
+    layout.clone()
+  };
+
+  Some(LayoutState {
+    layout,
+    mode,
+    monocle,
+    maximized,
+    paused,
+    floating,
+  })
+}
+
+fn format_layout(format: &str, layout: &Option<LayoutState>) -> String {
+  let Some(layout) = layout else {
+    return "".to_owned();
+  };
+
+  let reg = register_hitokage_helpers(Handlebars::new());
+
+  match reg.render_template(format, layout) {
+    Ok(label) => label,
+    Err(err) => {
+      log::error!("{:?}", err);
+      layout.mode.clone()
+    }
+  }
+}
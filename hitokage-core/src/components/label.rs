@@ -30,7 +30,7 @@ pub enum LabelMsg {
 #[derive(Debug, Deserialize)]
 pub struct LabelProps {
   #[serde(flatten)]
-  base: BaseProps,
+  pub(crate) base: BaseProps,
   label: ReactiveString,
 }
 
@@ -0,0 +1,310 @@
+use super::app::AppMsg;
+use super::base::Base;
+use super::base::BaseProps;
+use super::cpu::CPULoadWrapper;
+use super::memory::MemoryAndSwapWrapper;
+use super::r#box::BoxMsg;
+use crate::components::base::BaseMsgHook;
+use crate::generate_base_match_arms;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::reactive::create_react_sender;
+use crate::structs::reactive::Reactive;
+use crate::structs::system::SystemWrapper;
+use gtk4::prelude::*;
+use indexmap::IndexMap;
+use relm4::prelude::*;
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+use std::sync::mpsc::Sender;
+use systemstat::{CPULoad, DelayedMeasurement, Platform, System};
+
+const DEFAULT_INTERVAL: u64 = 1000;
+
+#[derive(Debug, Clone)]
+pub enum LevelMsgHook {
+  BaseHook(BaseMsgHook),
+  GetValue(Sender<f64>),
+  GetMin(Sender<f64>),
+  SetMin(f64),
+  GetMax(Sender<f64>),
+  SetMax(f64),
+}
+
+#[derive(Debug, Clone)]
+pub enum LevelMsg {
+  LuaHook(LevelMsgHook),
+  React,
+  Tick,
+}
+
+#[derive(Debug)]
+pub enum LevelMsgOut {
+  RequestSystem(relm4::tokio::sync::oneshot::Sender<SystemWrapper>),
+}
+
+impl From<LevelMsgOut> for AppMsg {
+  fn from(value: LevelMsgOut) -> Self {
+    match value {
+      LevelMsgOut::RequestSystem(tx) => AppMsg::RequestSystem(tx),
+    }
+  }
+}
+
+impl From<LevelMsgOut> for BoxMsg {
+  fn from(value: LevelMsgOut) -> Self {
+    match value {
+      LevelMsgOut::RequestSystem(tx) => BoxMsg::AppMsg(AppMsg::RequestSystem(tx)),
+    }
+  }
+}
+
+/// Where the level reads its value from. Built-in metrics are percentages.
+#[derive(Debug, Clone)]
+pub enum LevelValue {
+  Battery,
+  Cpu,
+  Memory,
+  /// A reactive holding a number, ie. `"0.5"`
+  Reactive(Reactive<String>),
+}
+
+struct LevelValueVisitor;
+
+impl serde::de::Visitor<'_> for LevelValueVisitor {
+  type Value = LevelValue;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("one of 'Battery', 'Cpu', 'Memory' or a reactive")
+  }
+
+  fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    match v {
+      "Battery" => Ok(LevelValue::Battery),
+      "Cpu" => Ok(LevelValue::Cpu),
+      "Memory" => Ok(LevelValue::Memory),
+      _ => Err(E::unknown_variant(v, &["Battery", "Cpu", "Memory"])),
+    }
+  }
+
+  fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(LevelValue::Reactive(crate::structs::reactive_string::parse_bytes(
+      value,
+    )))
+  }
+}
+
+impl<'de> Deserialize<'de> for LevelValue {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_any(LevelValueVisitor)
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LevelProps {
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+  value: LevelValue,
+  /// Defaults to `0`
+  min: Option<f64>,
+  /// Defaults to `100` for built-in metrics, otherwise `1`
+  max: Option<f64>,
+  /// Named offsets used for css styling, ie. `{ low = 20, high = 80, full = 100 }`. Defaults to `low`, `high` and
+  /// `full` at 25%, 75% and 100% of the range.
+  offsets: Option<IndexMap<String, f64>>,
+  /// Refresh interval in milliseconds
+  interval: Option<u64>,
+}
+
+#[tracker::track]
+pub struct Level {
+  #[tracker::do_not_track]
+  base: Base,
+  #[tracker::do_not_track]
+  source: LevelValue,
+  #[tracker::do_not_track]
+  source_id: Option<glib::SourceId>,
+  #[tracker::do_not_track]
+  system: SystemWrapper,
+  #[tracker::do_not_track]
+  sys: System,
+  #[tracker::do_not_track]
+  cpu_inflight: Option<DelayedMeasurement<Vec<CPULoad>>>,
+  value: f64,
+  min: f64,
+  max: f64,
+}
+
+impl Level {
+  async fn sample(&mut self) -> Option<f64> {
+    match &self.source {
+      LevelValue::Battery => match self.system.battery_life().await {
+        Ok(battery) => Some(battery.remaining_capacity as f64 * 100.0),
+        Err(err) => {
+          log::error!("Failed to obtain battery information: {}", err);
+          None
+        }
+      },
+      LevelValue::Cpu => {
+        let sample = self.cpu_inflight.take().and_then(|inflight| match inflight.done() {
+          Ok(loads) => Some(CPULoadWrapper::new(loads).usage() as f64 * 100.0),
+          Err(err) => {
+            log::error!("Failed to obtain CPU usage information: {}", err);
+            None
+          }
+        });
+        self.cpu_inflight = self.sys.cpu_load().ok();
+        sample
+      }
+      LevelValue::Memory => MemoryAndSwapWrapper::from(self.sys.memory_and_swap()).used_percent(),
+      LevelValue::Reactive(reactive) => {
+        let value = reactive.get();
+        match value.trim().parse::<f64>() {
+          Ok(sample) => Some(sample),
+          Err(_) => {
+            log::error!("Expected a number for level reactive, received: {:?}", value);
+            None
+          }
+        }
+      }
+    }
+  }
+}
+
+#[relm4::component(async, pub)]
+impl AsyncComponent for Level {
+  type Input = LevelMsg;
+  type Output = LevelMsgOut;
+  type Init = LevelProps;
+  type Widgets = LevelWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::LevelBar {
+      #[track = "model.changed(Level::min())"]
+      set_min_value: model.min,
+      #[track = "model.changed(Level::max())"]
+      set_max_value: model.max,
+      #[track = "model.changed(Level::value() | Level::min() | Level::max())"]
+      set_value: model.value.clamp(model.min, model.max.max(model.min)),
+    }
+  }
+
+  async fn init(props: Self::Init, root: Self::Root, sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+    let (tx, rx) = relm4::tokio::sync::oneshot::channel::<_>();
+    let _ = sender.output(LevelMsgOut::RequestSystem(tx));
+    let system = match rx.await {
+      Ok(v) => v,
+      Err(err) => {
+        log::error!("Failed to receive system: {}", err);
+        SystemWrapper::new()
+      }
+    };
+
+    if let LevelValue::Reactive(reactive) = &props.value {
+      *reactive.sender.lock().unwrap() = Some(create_react_sender(sender.input_sender(), LevelMsg::React));
+    }
+
+    let interval = std::time::Duration::from_millis(props.interval.unwrap_or(DEFAULT_INTERVAL).max(1));
+    let source_id = {
+      let sender = sender.clone();
+      glib::timeout_add_local(interval, move || {
+        sender.input(LevelMsg::Tick);
+        glib::ControlFlow::Continue
+      })
+    };
+
+    let min = props.min.unwrap_or(0.0);
+    let max = props.max.unwrap_or(match props.value {
+      LevelValue::Reactive(_) => 1.0,
+      _ => 100.0,
+    });
+
+    for name in ["low", "high", "full"] {
+      root.remove_offset_value(Some(name));
+    }
+    let offsets = props.offsets.unwrap_or_else(|| {
+      IndexMap::from([
+        ("low".to_string(), min + (max - min) * 0.25),
+        ("high".to_string(), min + (max - min) * 0.75),
+        ("full".to_string(), max),
+      ])
+    });
+    for (name, value) in &offsets {
+      root.add_offset_value(name, *value);
+    }
+
+    let sys = System::new();
+
+    let mut model = Level {
+      base: props.base.clone().into(),
+      source: props.value,
+      source_id: Some(source_id),
+      system,
+      cpu_inflight: sys.cpu_load().ok(),
+      sys,
+      value: min,
+      min,
+      max,
+      tracker: 0,
+    };
+
+    if let Some(value) = model.sample().await {
+      model.value = value;
+    }
+
+    prepend_css_class_to_model!("level", model, root);
+    set_initial_base_props!(model, root, props.base);
+
+    let widgets = view_output!();
+
+    root.show();
+
+    AsyncComponentParts { model, widgets }
+  }
+
+  async fn update(&mut self, msg: Self::Input, _sender: AsyncComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      LevelMsg::LuaHook(hook) => match hook {
+        LevelMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "level", root, base)
+        }
+        LevelMsgHook::GetValue(tx) => {
+          tx.send(self.value).unwrap();
+        }
+        LevelMsgHook::GetMin(tx) => {
+          tx.send(self.min).unwrap();
+        }
+        LevelMsgHook::SetMin(min) => {
+          self.set_min(min);
+        }
+        LevelMsgHook::GetMax(tx) => {
+          tx.send(self.max).unwrap();
+        }
+        LevelMsgHook::SetMax(max) => {
+          self.set_max(max);
+        }
+      },
+      LevelMsg::React | LevelMsg::Tick => {
+        if let Some(value) = self.sample().await {
+          self.set_value(value);
+        }
+      }
+    }
+  }
+
+  fn shutdown(&mut self, _widgets: &mut Self::Widgets, _sender: relm4::Sender<Self::Output>) {
+    if let Some(a) = self.source_id.take() {
+      glib::SourceId::remove(a)
+    }
+  }
+}
@@ -1,75 +1,342 @@
-// use super::app::AppMsg;
-// use super::base::{Base, BaseMsgHook, BaseProps};
-// use super::r#box::{BoxInner, BoxMsgHook, BoxProps};
-// use super::ChildUserData;
-// use crate::structs::{Monitor, MonitorGeometry, MonitorScaleFactor};
-// use crate::win_utils::get_windows_version;
-// use crate::{
-//   generate_base_match_arms, prepend_css_class,
-//   prepend_css_class_to_model, set_initial_base_props,
-// };
-// use gtk4::prelude::*;
-// use relm4::prelude::*;
-// use relm4::ComponentParts;
-// use relm4::{Component, ComponentSender};
-// use serde::{Deserialize, Serialize};
-
-// #[derive(Debug)]
-// pub(crate) enum MediaMsgHook {
-//   BaseHook(BaseMsgHook),
-// }
-
-// #[derive(Debug)]
-// pub(crate) enum MediaMsg {
-//   LuaHook(MediaMsgHook),
-// }
-
-// #[derive(Debug, Deserialize, Serialize)]
-// pub(crate) struct MediaProps {
-//   #[serde(flatten)]
-//   pub base: BaseProps,
-// }
-
-// pub(crate) struct Media {
-//   base: Base,
-// }
-
-// #[relm4::component(pub(crate))]
-// impl Component for Media {
-//   type Input = MediaMsg;
-//   type Output = AppMsg;
-//   type Init = MediaProps;
-//   type Widgets = AppWidgets;
-//   type CommandOutput = ();
-
-//   view! {
-//     gtk::Box {
-      
-//     }
-//   }
-
-//   fn init(props: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
-//     let mut model = Media {
-//       base: props.base.clone().into(),
-//     };
-
-//     prepend_css_class_to_model!("media", model, root);
-//     set_initial_base_props!(model, root, props.base);
-
-//     let widgets = view_output!();
-
-//     root.show();
-
-//     ComponentParts { model, widgets }
-//   }
-
-//   fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>, root: &Self::Root) {
-//     match msg {
-//       MediaMsg::LuaHook(hook) => match hook {
-//         MediaMsgHook::BaseHook(base) => {
-//           generate_base_match_arms!(self, "format", root, base)
-//         }
-//       },
-//     }
-//   }
-// }
\ No newline at end of file
+use super::app::AppMsg;
+use super::base::request_lua_action;
+use super::base::Base;
+use super::base::BaseProps;
+use super::r#box::BoxMsg;
+use crate::components::base::BaseMsgHook;
+use crate::generate_base_match_arms;
+use crate::handlebar::register_hitokage_helpers;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::lua_fn::LuaFn;
+use crate::structs::media::default_player;
+use crate::structs::media::MediaCommand;
+use crate::structs::media::MediaState;
+use crate::structs::media::MediaWorker;
+use crate::structs::media::PlaybackStatus;
+use crate::structs::reactive::create_react_sender;
+use crate::structs::reactive::AsReactive;
+use crate::structs::reactive::Reactive;
+use crate::structs::reactive_string_fn::ReactiveStringFn;
+use gtk4::prelude::*;
+use handlebars::Handlebars;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone)]
+pub enum MediaMsgHook {
+  BaseHook(BaseMsgHook),
+  GetFormat(Sender<String>),
+  GetFormatReactive(Sender<Reactive<String>>),
+  SetFormat(String),
+  GetControls(Sender<bool>),
+  SetControls(bool),
+  PlayPause,
+  Next,
+  Previous,
+}
+
+#[derive(Debug, Clone)]
+pub enum MediaMsg {
+  LuaHook(MediaMsgHook),
+  React,
+  State(Option<MediaState>),
+  Callback,
+  /// The result of the `format` lua function
+  Formatted(String),
+  PlayPause,
+  Next,
+  Previous,
+}
+
+#[derive(Debug)]
+pub enum MediaMsgOut {
+  RequestLuaAction(
+    Arc<mlua::RegistryKey>,
+    serde_json::Value,
+    std::sync::mpsc::Sender<mlua::Value>,
+  ),
+}
+
+impl From<MediaMsgOut> for AppMsg {
+  fn from(value: MediaMsgOut) -> Self {
+    match value {
+      MediaMsgOut::RequestLuaAction(a, b, c) => AppMsg::RequestLuaAction(a, b, c),
+      #[allow(unreachable_patterns)]
+      _ => AppMsg::NoOp,
+    }
+  }
+}
+
+impl From<MediaMsgOut> for BoxMsg {
+  fn from(value: MediaMsgOut) -> Self {
+    match value {
+      MediaMsgOut::RequestLuaAction(a, b, c) => BoxMsg::AppMsg(AppMsg::RequestLuaAction(a, b, c)),
+    }
+  }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MediaIcons {
+  #[serde(default)]
+  play: String,
+  #[serde(default)]
+  pause: String,
+  #[serde(default)]
+  next: String,
+  #[serde(default)]
+  previous: String,
+}
+
+impl Default for MediaIcons {
+  fn default() -> Self {
+    Self {
+      play: "\u{F040A}".to_string(),
+      pause: "\u{F03E4}".to_string(),
+      next: "\u{F04AD}".to_string(),
+      previous: "\u{F04AE}".to_string(),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MediaProps {
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+  format: ReactiveStringFn,
+  /// The player to display, ie. `"Spotify"`. On Windows this matches part of the app id. Defaults to the most active
+  /// player.
+  player: Option<String>,
+  controls: Option<bool>,
+  #[serde(default)]
+  icons: MediaIcons,
+}
+
+#[tracker::track]
+pub struct Media {
+  #[tracker::do_not_track]
+  base: Base,
+  #[tracker::do_not_track]
+  worker: MediaWorker,
+  state: Option<MediaState>,
+  controls: bool,
+  #[tracker::do_not_track]
+  icons: MediaIcons,
+  #[tracker::do_not_track]
+  source_id: Option<glib::SourceId>,
+  #[tracker::do_not_track]
+  format: Reactive<String>,
+  react: bool,
+  #[tracker::do_not_track]
+  callback: Option<LuaFn>,
+}
+
+#[relm4::component(pub)]
+impl Component for Media {
+  type Input = MediaMsg;
+  type Output = MediaMsgOut;
+  type Init = MediaProps;
+  type Widgets = MediaWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::Box {
+      #[name="label"]
+      gtk::Label {
+        #[track = "model.changed(Media::react() | Media::state())"]
+        set_label: &format_media(&model.format.get(), &model.state),
+      },
+      #[name="previous"]
+      gtk::Button {
+        add_css_class: "media-previous",
+        set_label: &model.icons.previous,
+        #[track = "model.changed(Media::controls())"]
+        set_visible: model.controls,
+        connect_clicked => MediaMsg::Previous,
+      },
+      #[name="play_pause"]
+      gtk::Button {
+        add_css_class: "media-play-pause",
+        #[track = "model.changed(Media::state())"]
+        set_label: if is_playing(&model.state) { &model.icons.pause } else { &model.icons.play },
+        #[track = "model.changed(Media::controls())"]
+        set_visible: model.controls,
+        connect_clicked => MediaMsg::PlayPause,
+      },
+      #[name="next"]
+      gtk::Button {
+        add_css_class: "media-next",
+        set_label: &model.icons.next,
+        #[track = "model.changed(Media::controls())"]
+        set_visible: model.controls,
+        connect_clicked => MediaMsg::Next,
+      },
+    }
+  }
+
+  fn init(props: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    let callback = props.format.as_fn();
+    let reactive = props
+      .format
+      .as_reactive(create_react_sender(sender.input_sender(), MediaMsg::React));
+
+    let source_id = callback.as_ref().map(|_| {
+      let sender = sender.clone();
+      glib::timeout_add_local(POLL_INTERVAL, move || {
+        sender.input(MediaMsg::Callback);
+        glib::ControlFlow::Continue
+      })
+    });
+
+    // the player is polled off the main thread, some backends block on every call
+    let worker = {
+      let input = sender.input_sender().clone();
+      let name = props.player;
+      MediaWorker::spawn(
+        POLL_INTERVAL,
+        move || default_player(name),
+        move |state| input.send(MediaMsg::State(state)).is_ok(),
+      )
+    };
+
+    let mut model = Media {
+      base: props.base.clone().into(),
+      worker,
+      state: None,
+      controls: props.controls.unwrap_or(true),
+      icons: props.icons,
+      source_id,
+      format: reactive,
+      callback,
+      react: false,
+      tracker: 0,
+    };
+
+    prepend_css_class_to_model!("media", model, root);
+    set_initial_base_props!(model, root, props.base);
+
+    let widgets = view_output!();
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      MediaMsg::LuaHook(hook) => match hook {
+        MediaMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "media", root, base)
+        }
+        MediaMsgHook::GetFormat(tx) => {
+          tx.send(self.format.get()).unwrap();
+        }
+        MediaMsgHook::GetFormatReactive(tx) => {
+          tx.send(self.format.clone()).unwrap();
+        }
+        MediaMsgHook::SetFormat(format) => {
+          let arc = self.format.value.clone();
+          let mut str = arc.lock().unwrap();
+          *str = format;
+          self.set_react(!self.react);
+        }
+        MediaMsgHook::GetControls(tx) => {
+          tx.send(self.controls).unwrap();
+        }
+        MediaMsgHook::SetControls(controls) => {
+          self.set_controls(controls);
+        }
+        MediaMsgHook::PlayPause => sender.input(MediaMsg::PlayPause),
+        MediaMsgHook::Next => sender.input(MediaMsg::Next),
+        MediaMsgHook::Previous => sender.input(MediaMsg::Previous),
+      },
+      MediaMsg::React => {
+        self.set_react(!self.react);
+      }
+      MediaMsg::State(state) => {
+        self.set_state(state);
+      }
+      MediaMsg::Callback => {
+        if let Some(callback) = &self.callback {
+          let rx = request_lua_action(callback, serde_json::to_value(MediaInfo::from(&self.state)).unwrap());
+          let input = sender.input_sender().clone();
+          // the receiver only resolves once the lua scheduler ran the function, or disconnects if it never will
+          relm4::spawn_blocking(move || match rx.recv() {
+            Ok(mlua::Value::String(s)) => {
+              let _ = input.send(MediaMsg::Formatted(s.to_string_lossy()));
+            }
+            Ok(v) => {
+              log::error!("Expected string for media callback, received: {:?}", v);
+            }
+            Err(_) => {}
+          });
+        }
+      }
+      MediaMsg::Formatted(format) => {
+        self.format.set(format);
+      }
+      MediaMsg::PlayPause => self.worker.send(MediaCommand::PlayPause),
+      MediaMsg::Next => self.worker.send(MediaCommand::Next),
+      MediaMsg::Previous => self.worker.send(MediaCommand::Previous),
+    }
+  }
+
+  fn shutdown(&mut self, _widgets: &mut Self::Widgets, _output: relm4::Sender<Self::Output>) {
+    if let Some(a) = self.source_id.take() {
+      glib::SourceId::remove(a)
+    }
+  }
+}
+
+fn is_playing(state: &Option<MediaState>) -> bool {
+  state.as_ref().is_some_and(|s| s.status == PlaybackStatus::Playing)
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+struct MediaInfo {
+  player: String,
+  title: String,
+  artist: String,
+  album: String,
+  status: PlaybackStatus,
+  position: u64,
+  length: u64,
+}
+
+impl From<&Option<MediaState>> for MediaInfo {
+  fn from(value: &Option<MediaState>) -> Self {
+    match value {
+      Some(state) => MediaInfo {
+        player: state.player.clone(),
+        title: state.title.clone(),
+        artist: state.artists.join(", "),
+        album: state.album.clone(),
+        status: state.status,
+        position: state.position.as_secs(),
+        length: state.length.map_or(0, |length| length.as_secs()),
+      },
+      None => MediaInfo::default(),
+    }
+  }
+}
+
+fn format_media(format: &str, state: &Option<MediaState>) -> String {
+  let reg = register_hitokage_helpers(Handlebars::new());
+
+  match reg.render_template(format, &MediaInfo::from(state)) {
+    Ok(name) => return name,
+    Err(err) => {
+      log::error!("{:?}", err);
+    }
+  };
+
+  "".to_owned()
+}
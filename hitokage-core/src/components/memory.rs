@@ -73,7 +73,7 @@ impl From<MemoryMsgOut> for BoxMsg {
 #[derive(Debug, Deserialize)]
 pub struct MemoryProps {
   #[serde(flatten)]
-  base: BaseProps,
+  pub(crate) base: BaseProps,
   format: ReactiveStringFn,
 }
 
@@ -195,6 +195,9 @@ impl Component for Memory {
       }
       MemoryMsg::Tick => {
         self.mem_and_swap = self.sys.memory_and_swap().into();
+        if let (Some(memory), Some(swap)) = (&self.mem_and_swap.memory, &self.mem_and_swap.swap) {
+          self.base.set_tooltip_args(memory_format_args(memory, swap), root);
+        }
         self.set_react(!self.react);
       }
       MemoryMsg::Callback(tx) => {
@@ -217,12 +220,22 @@ impl Component for Memory {
 }
 
 #[derive(Debug, Clone)]
-struct MemoryAndSwapWrapper {
+pub(crate) struct MemoryAndSwapWrapper {
   memory: Option<systemstat::Memory>,
   swap: Option<systemstat::Swap>,
 }
 
 impl MemoryAndSwapWrapper {
+  /// Percentage of memory used, between `0.0` and `100.0`
+  pub(crate) fn used_percent(&self) -> Option<f64> {
+    let memory = self.memory.as_ref()?;
+    let total = memory.total.as_u64() as f64;
+    if total <= 0.0 {
+      return None;
+    }
+    Some((total - memory.free.as_u64() as f64) / total * 100.0)
+  }
+
   fn as_lua_args(&self) -> MemoryInfo {
     let free_mb = self.memory.clone().unwrap().free.as_u64() as f64 / BYTES_TO_MB;
     let total_mb = self.memory.clone().unwrap().total.as_u64() as f64 / BYTES_TO_MB;
@@ -297,6 +310,17 @@ fn handle_optional_sys_and_mem(format: &str, mem_and_swap: &MemoryAndSwapWrapper
 fn format_memory(format: &str, memory: &systemstat::Memory, swap: &systemstat::Swap) -> String {
   let reg = register_hitokage_helpers(Handlebars::new());
 
+  match reg.render_template(format, &memory_format_args(memory, swap)) {
+    Ok(name) => return name,
+    Err(err) => {
+      log::error!("{:?}", err);
+    }
+  };
+
+  "".to_owned()
+}
+
+fn memory_format_args(memory: &systemstat::Memory, swap: &systemstat::Swap) -> HashMap<String, f64> {
   let mut args = HashMap::new();
 
   const BYTES_TO_MB: f64 = 1_048_576.0;
@@ -316,12 +340,5 @@ fn format_memory(format: &str, memory: &systemstat::Memory, swap: &systemstat::S
   args.insert("swap_total".to_string(), swap_total_mb);
   args.insert("swap_used".to_string(), swap_used_mb);
 
-  match reg.render_template(format, &args) {
-    Ok(name) => return name,
-    Err(err) => {
-      log::error!("{:?}", err);
-    }
-  };
-
-  "".to_owned()
+  args
 }
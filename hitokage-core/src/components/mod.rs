@@ -3,62 +3,162 @@ pub mod bar;
 pub mod base;
 pub mod battery;
 pub mod r#box;
+pub mod button;
 pub mod clock;
 pub mod cpu;
+pub mod disk;
+pub mod graph;
 pub mod icon;
+pub mod komorebi_layout;
 pub mod label;
-// pub mod media;
+pub mod level;
+pub mod media;
 pub mod memory;
+pub mod network;
+pub mod revealer;
+pub mod scale;
+pub mod stack;
+pub mod system_info;
+pub mod taskbar;
+pub mod temperature;
 pub mod weather;
+pub mod window_title;
 pub mod workspace;
 
 use battery::Battery;
 use battery::BatteryMsg;
+use button::Button;
+use button::ButtonMsg;
 use clock::Clock;
 use clock::ClockMsg;
 use cpu::Cpu;
 use cpu::CpuMsg;
+use disk::Disk;
+use disk::DiskMsg;
+use graph::Graph;
+use graph::GraphMsg;
 use icon::Icon;
 use icon::IconMsg;
+use komorebi_layout::KomorebiLayout;
+use komorebi_layout::KomorebiLayoutMsg;
 use label::Label;
 use label::LabelMsg;
+use level::Level;
+use level::LevelMsg;
+use media::Media;
+use media::MediaMsg;
 use memory::Memory;
 use memory::MemoryMsg;
+use network::Network;
+use network::NetworkMsg;
 use r#box::BoxMsgPortable;
 use r#box::HitokageBox;
 use relm4::component::AsyncComponentController;
 use relm4::prelude::AsyncController;
 use relm4::ComponentController;
 use relm4::Controller;
+use revealer::Revealer;
+use revealer::RevealerMsgPortable;
+use scale::Scale;
+use scale::ScaleMsg;
 use serde::de;
 use serde::Deserialize;
+use stack::Stack;
+use stack::StackMsgPortable;
 use std::fmt;
+use system_info::SystemInfo;
+use system_info::SystemInfoMsg;
+use taskbar::Taskbar;
+use taskbar::TaskbarMsg;
+use temperature::Temperature;
+use temperature::TemperatureMsg;
 use weather::Weather;
 use weather::WeatherMsg;
+use window_title::WindowTitle;
+use window_title::WindowTitleMsg;
 use workspace::{Workspace, WorkspaceMsg};
 
 #[derive(Debug, Deserialize)]
 pub enum Child {
   Battery(battery::BatteryProps),
   Box(r#box::BoxProps),
+  Button(button::ButtonProps),
   Clock(clock::ClockProps),
   Cpu(cpu::CpuProps),
+  Disk(disk::DiskProps),
+  Graph(graph::GraphProps),
   Icon(icon::IconProps),
+  KomorebiLayout(komorebi_layout::KomorebiLayoutProps),
   Label(label::LabelProps),
+  Level(level::LevelProps),
+  Media(media::MediaProps),
   Memory(memory::MemoryProps),
+  Network(network::NetworkProps),
+  Revealer(revealer::RevealerProps),
+  Scale(scale::ScaleProps),
+  Stack(stack::StackProps),
+  SystemInfo(system_info::SystemInfoProps),
+  Taskbar(taskbar::TaskbarProps),
+  Temperature(temperature::TemperatureProps),
   Weather(weather::WeatherProps),
+  WindowTitle(window_title::WindowTitleProps),
   Workspace(workspace::WorkspaceProps),
 }
 
+impl Child {
+  /// The props shared by every child, ie. its `id`
+  pub(crate) fn base(&self) -> &base::BaseProps {
+    match self {
+      Child::Battery(props) => &props.base,
+      Child::Box(props) => &props.base,
+      Child::Button(props) => &props.base,
+      Child::Clock(props) => &props.base,
+      Child::Cpu(props) => &props.base,
+      Child::Disk(props) => &props.base,
+      Child::Graph(props) => &props.base,
+      Child::Icon(props) => &props.base,
+      Child::KomorebiLayout(props) => &props.base,
+      Child::Label(props) => &props.base,
+      Child::Level(props) => &props.base,
+      Child::Media(props) => &props.base,
+      Child::Memory(props) => &props.base,
+      Child::Network(props) => &props.base,
+      Child::Revealer(props) => &props.base,
+      Child::Scale(props) => &props.base,
+      Child::Stack(props) => &props.base,
+      Child::SystemInfo(props) => &props.base,
+      Child::Taskbar(props) => &props.base,
+      Child::Temperature(props) => &props.base,
+      Child::Weather(props) => &props.base,
+      Child::WindowTitle(props) => &props.base,
+      Child::Workspace(props) => &props.base,
+    }
+  }
+}
+
 pub enum ChildController {
   Battery(AsyncController<Battery>),
   Box(Controller<HitokageBox>),
+  Button(Controller<Button>),
   Clock(Controller<Clock>),
   Cpu(Controller<Cpu>),
+  Disk(Controller<Disk>),
+  Graph(Controller<Graph>),
   Icon(Controller<Icon>),
+  KomorebiLayout(Controller<KomorebiLayout>),
   Label(AsyncController<Label>),
+  Level(AsyncController<Level>),
+  Media(Controller<Media>),
   Memory(Controller<Memory>),
+  Network(Controller<Network>),
+  Revealer(Controller<Revealer>),
+  Scale(Controller<Scale>),
+  Stack(Controller<Stack>),
+  SystemInfo(AsyncController<SystemInfo>),
+  Taskbar(Controller<Taskbar>),
+  Temperature(Controller<Temperature>),
   Weather(AsyncController<Weather>),
+  WindowTitle(Controller<WindowTitle>),
   Workspace(Controller<Workspace>),
 }
 
@@ -67,12 +167,26 @@ impl ChildController {
     match self {
       ChildController::Battery(c) => c.widget().clone().into(),
       ChildController::Box(c) => c.widget().clone().into(),
+      ChildController::Button(c) => c.widget().clone().into(),
       ChildController::Clock(c) => c.widget().clone().into(),
       ChildController::Cpu(c) => c.widget().clone().into(),
+      ChildController::Disk(c) => c.widget().clone().into(),
+      ChildController::Graph(c) => c.widget().clone().into(),
       ChildController::Icon(c) => c.widget().clone().into(),
+      ChildController::KomorebiLayout(c) => c.widget().clone().into(),
       ChildController::Label(c) => c.widget().clone().into(),
+      ChildController::Level(c) => c.widget().clone().into(),
+      ChildController::Media(c) => c.widget().clone().into(),
       ChildController::Memory(c) => c.widget().clone().into(),
+      ChildController::Network(c) => c.widget().clone().into(),
+      ChildController::Revealer(c) => c.widget().clone().into(),
+      ChildController::Scale(c) => c.widget().clone().into(),
+      ChildController::Stack(c) => c.widget().clone().into(),
+      ChildController::SystemInfo(c) => c.widget().clone().into(),
+      ChildController::Taskbar(c) => c.widget().clone().into(),
+      ChildController::Temperature(c) => c.widget().clone().into(),
       ChildController::Weather(c) => c.widget().clone().into(),
+      ChildController::WindowTitle(c) => c.widget().clone().into(),
       ChildController::Workspace(c) => c.widget().clone().into(),
     }
   }
@@ -82,12 +196,26 @@ impl ChildController {
 pub enum ChildUserData {
   Battery(relm4::Sender<BatteryMsg>),
   Box(relm4::Sender<BoxMsgPortable>),
+  Button(relm4::Sender<ButtonMsg>),
   Clock(relm4::Sender<ClockMsg>),
   Cpu(relm4::Sender<CpuMsg>),
+  Disk(relm4::Sender<DiskMsg>),
+  Graph(relm4::Sender<GraphMsg>),
   Icon(relm4::Sender<IconMsg>),
+  KomorebiLayout(relm4::Sender<KomorebiLayoutMsg>),
   Label(relm4::Sender<LabelMsg>),
+  Level(relm4::Sender<LevelMsg>),
+  Media(relm4::Sender<MediaMsg>),
   Memory(relm4::Sender<MemoryMsg>),
+  Network(relm4::Sender<NetworkMsg>),
+  Revealer(relm4::Sender<RevealerMsgPortable>),
+  Scale(relm4::Sender<ScaleMsg>),
+  Stack(relm4::Sender<StackMsgPortable>),
+  SystemInfo(relm4::Sender<SystemInfoMsg>),
+  Taskbar(relm4::Sender<TaskbarMsg>),
+  Temperature(relm4::Sender<TemperatureMsg>),
   Weather(relm4::Sender<WeatherMsg>),
+  WindowTitle(relm4::Sender<WindowTitleMsg>),
   Workspace(relm4::Sender<WorkspaceMsg>),
 }
 
@@ -100,12 +228,34 @@ impl<'a> From<&'a ChildController> for ChildUserData {
         relm4::spawn_local(receiver.forward(item.sender().clone(), |m| m.into()));
         ChildUserData::Box(sender)
       }
+      ChildController::Button(item) => ChildUserData::Button(item.sender().clone()),
       ChildController::Clock(item) => ChildUserData::Clock(item.sender().clone()),
       ChildController::Cpu(item) => ChildUserData::Cpu(item.sender().clone()),
+      ChildController::Disk(item) => ChildUserData::Disk(item.sender().clone()),
+      ChildController::Graph(item) => ChildUserData::Graph(item.sender().clone()),
       ChildController::Icon(item) => ChildUserData::Icon(item.sender().clone()),
+      ChildController::KomorebiLayout(item) => ChildUserData::KomorebiLayout(item.sender().clone()),
       ChildController::Label(item) => ChildUserData::Label(item.sender().clone()),
+      ChildController::Level(item) => ChildUserData::Level(item.sender().clone()),
+      ChildController::Media(item) => ChildUserData::Media(item.sender().clone()),
       ChildController::Memory(item) => ChildUserData::Memory(item.sender().clone()),
+      ChildController::Network(item) => ChildUserData::Network(item.sender().clone()),
+      ChildController::Revealer(item) => {
+        let (sender, receiver) = relm4::channel::<RevealerMsgPortable>();
+        relm4::spawn_local(receiver.forward(item.sender().clone(), |m| m.into()));
+        ChildUserData::Revealer(sender)
+      }
+      ChildController::Scale(item) => ChildUserData::Scale(item.sender().clone()),
+      ChildController::Stack(item) => {
+        let (sender, receiver) = relm4::channel::<StackMsgPortable>();
+        relm4::spawn_local(receiver.forward(item.sender().clone(), |m| m.into()));
+        ChildUserData::Stack(sender)
+      }
+      ChildController::SystemInfo(item) => ChildUserData::SystemInfo(item.sender().clone()),
+      ChildController::Taskbar(item) => ChildUserData::Taskbar(item.sender().clone()),
+      ChildController::Temperature(item) => ChildUserData::Temperature(item.sender().clone()),
       ChildController::Weather(item) => ChildUserData::Weather(item.sender().clone()),
+      ChildController::WindowTitle(item) => ChildUserData::WindowTitle(item.sender().clone()),
       ChildController::Workspace(item) => ChildUserData::Workspace(item.sender().clone()),
     }
   }
@@ -0,0 +1,374 @@
+use super::app::AppMsg;
+use super::base::request_lua_action;
+use super::base::Base;
+use super::base::BaseProps;
+use super::r#box::BoxMsg;
+use crate::components::base::BaseMsgHook;
+use crate::generate_base_match_arms;
+use crate::handlebar::register_hitokage_helpers;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::lua_fn::LuaFn;
+use crate::structs::reactive::create_react_sender;
+use crate::structs::reactive::AsReactive;
+use crate::structs::reactive::Reactive;
+use crate::structs::reactive_string_fn::ReactiveStringFn;
+use gtk4::prelude::*;
+use handlebars::Handlebars;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::Instant;
+use systemstat::Platform;
+use systemstat::System;
+
+const BYTES_TO_KB: f64 = 1_024.0;
+const BYTES_TO_MB: f64 = 1_048_576.0;
+
+#[derive(Debug, Clone)]
+pub enum NetworkMsgHook {
+  BaseHook(BaseMsgHook),
+  GetFormat(Sender<String>),
+  GetFormatReactive(Sender<Reactive<String>>),
+  SetFormat(String),
+  GetInterface(Sender<Option<String>>),
+  SetInterface(Option<String>),
+}
+
+#[derive(Debug, Clone)]
+pub enum NetworkMsg {
+  LuaHook(NetworkMsgHook),
+  React,
+  Tick,
+  Callback,
+  /// The result of the `format` lua function
+  Formatted(String),
+}
+
+#[derive(Debug)]
+pub enum NetworkMsgOut {
+  RequestLuaAction(
+    Arc<mlua::RegistryKey>,
+    serde_json::Value,
+    std::sync::mpsc::Sender<mlua::Value>,
+  ),
+}
+
+impl From<NetworkMsgOut> for AppMsg {
+  fn from(value: NetworkMsgOut) -> Self {
+    match value {
+      NetworkMsgOut::RequestLuaAction(a, b, c) => AppMsg::RequestLuaAction(a, b, c),
+      #[allow(unreachable_patterns)]
+      _ => AppMsg::NoOp,
+    }
+  }
+}
+
+impl From<NetworkMsgOut> for BoxMsg {
+  fn from(value: NetworkMsgOut) -> Self {
+    match value {
+      NetworkMsgOut::RequestLuaAction(a, b, c) => BoxMsg::AppMsg(AppMsg::RequestLuaAction(a, b, c)),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NetworkProps {
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+  format: ReactiveStringFn,
+  interface: Option<String>,
+}
+
+#[tracker::track]
+pub struct Network {
+  #[tracker::do_not_track]
+  base: Base,
+  network: NetworkWrapper,
+  #[tracker::do_not_track]
+  source_id: Option<glib::SourceId>,
+  #[tracker::do_not_track]
+  format: Reactive<String>,
+  #[tracker::do_not_track]
+  interface: Option<String>,
+  #[tracker::do_not_track]
+  sys: System,
+  react: bool,
+  #[tracker::do_not_track]
+  callback: Option<LuaFn>,
+}
+
+#[relm4::component(pub)]
+impl Component for Network {
+  type Input = NetworkMsg;
+  type Output = NetworkMsgOut;
+  type Init = NetworkProps;
+  type Widgets = NetworkWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::Label {
+      #[track = "model.changed(Network::react() | Network::network())"]
+      set_label: &format_network(&model.format.get(), &model.network),
+    }
+  }
+
+  fn init(props: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    let callback = props.format.as_fn();
+    let reactive = props
+      .format
+      .as_reactive(create_react_sender(sender.input_sender(), NetworkMsg::React));
+
+    let source_id = {
+      let sender = sender.clone();
+
+      match callback {
+        Some(_) => glib::timeout_add_local(std::time::Duration::from_secs(1), move || {
+          sender.input(NetworkMsg::Tick);
+          sender.input(NetworkMsg::Callback);
+          glib::ControlFlow::Continue
+        }),
+        None => glib::timeout_add_local(std::time::Duration::from_secs(1), move || {
+          sender.input(NetworkMsg::Tick);
+          glib::ControlFlow::Continue
+        }),
+      }
+    };
+
+    let sys = System::new();
+
+    let network = NetworkWrapper::sample(&sys, props.interface.as_deref(), None);
+
+    let mut model = Network {
+      base: props.base.clone().into(),
+      network,
+      source_id: Some(source_id),
+      format: reactive,
+      interface: props.interface,
+      callback,
+      react: false,
+      tracker: 0,
+      sys,
+    };
+
+    prepend_css_class_to_model!("network", model, root);
+    set_initial_base_props!(model, root, props.base);
+
+    let widgets = view_output!();
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      NetworkMsg::LuaHook(hook) => match hook {
+        NetworkMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "network", root, base)
+        }
+        NetworkMsgHook::GetFormat(tx) => {
+          tx.send(self.format.get()).unwrap();
+        }
+        NetworkMsgHook::GetFormatReactive(tx) => {
+          tx.send(self.format.clone()).unwrap();
+        }
+        NetworkMsgHook::SetFormat(format) => {
+          let arc = self.format.value.clone();
+          let mut str = arc.lock().unwrap();
+          *str = format;
+          self.set_react(!self.react);
+        }
+        NetworkMsgHook::GetInterface(tx) => {
+          tx.send(self.interface.clone()).unwrap();
+        }
+        NetworkMsgHook::SetInterface(interface) => {
+          self.interface = interface;
+          // the previous counters belong to a different interface, so don't diff against them
+          self.set_network(NetworkWrapper::sample(&self.sys, self.interface.as_deref(), None));
+        }
+      },
+      NetworkMsg::React => {
+        self.set_react(!self.react);
+      }
+      NetworkMsg::Tick => {
+        let network = NetworkWrapper::sample(&self.sys, self.interface.as_deref(), Some(&self.network));
+        self.set_network(network);
+      }
+      NetworkMsg::Callback => {
+        if let Some(callback) = &self.callback {
+          let rx = request_lua_action(callback, serde_json::to_value(self.network.as_lua_args()).unwrap());
+          let input = sender.input_sender().clone();
+          // the receiver only resolves once the lua scheduler ran the function, or disconnects if it never will
+          relm4::spawn_blocking(move || match rx.recv() {
+            Ok(mlua::Value::String(s)) => {
+              let _ = input.send(NetworkMsg::Formatted(s.to_string_lossy()));
+            }
+            Ok(v) => {
+              log::error!("Expected string for network callback, received: {:?}", v);
+            }
+            Err(_) => {}
+          });
+        }
+      }
+      NetworkMsg::Formatted(format) => {
+        self.format.set(format);
+      }
+    }
+  }
+
+  fn shutdown(&mut self, _widgets: &mut Self::Widgets, _output: relm4::Sender<Self::Output>) {
+    if let Some(a) = self.source_id.take() {
+      glib::SourceId::remove(a)
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NetworkWrapper {
+  interface: Option<String>,
+  ip: Option<String>,
+  stats: Option<systemstat::NetworkStats>,
+  sampled_at: Instant,
+  // bytes per second
+  rx_rate: f64,
+  tx_rate: f64,
+}
+
+impl NetworkWrapper {
+  /// Samples the interface counters, diffing against `previous` to get the current rates
+  pub(crate) fn sample(sys: &System, interface: Option<&str>, previous: Option<&NetworkWrapper>) -> Self {
+    let (interface, ip) = match find_interface(sys, interface) {
+      Some((name, ip)) => (Some(name), ip),
+      None => (interface.map(String::from), None),
+    };
+
+    let stats = interface.as_ref().and_then(|name| match sys.network_stats(name) {
+      Ok(stats) => Some(stats),
+      Err(err) => {
+        log::error!("Failed to fetch network statistics for {}: {}", name, err);
+        None
+      }
+    });
+
+    let sampled_at = Instant::now();
+
+    let (rx_rate, tx_rate) = previous
+      .filter(|previous| previous.interface == interface)
+      .and_then(|previous| {
+        let (prev_stats, stats) = previous.stats.as_ref().zip(stats.as_ref())?;
+        let elapsed = sampled_at.duration_since(previous.sampled_at).as_secs_f64();
+        if elapsed <= 0.0 {
+          return None;
+        }
+        let rx = stats.rx_bytes.as_u64().saturating_sub(prev_stats.rx_bytes.as_u64()) as f64;
+        let tx = stats.tx_bytes.as_u64().saturating_sub(prev_stats.tx_bytes.as_u64()) as f64;
+        Some((rx / elapsed, tx / elapsed))
+      })
+      .unwrap_or((0.0, 0.0));
+
+    NetworkWrapper {
+      interface,
+      ip,
+      stats,
+      sampled_at,
+      rx_rate,
+      tx_rate,
+    }
+  }
+
+  /// Received KiB/s
+  pub(crate) fn rx_rate(&self) -> f64 {
+    self.rx_rate / BYTES_TO_KB
+  }
+
+  /// Transmitted KiB/s
+  pub(crate) fn tx_rate(&self) -> f64 {
+    self.tx_rate / BYTES_TO_KB
+  }
+
+  fn as_lua_args(&self) -> NetworkInfo {
+    let (rx_total, tx_total) = self.stats.as_ref().map_or((0.0, 0.0), |stats| {
+      (
+        stats.rx_bytes.as_u64() as f64 / BYTES_TO_MB,
+        stats.tx_bytes.as_u64() as f64 / BYTES_TO_MB,
+      )
+    });
+
+    NetworkInfo {
+      interface: self.interface.clone().unwrap_or_default(),
+      ip: self.ip.clone().unwrap_or_default(),
+      rx_rate: self.rx_rate / BYTES_TO_KB,
+      tx_rate: self.tx_rate / BYTES_TO_KB,
+      rx_total,
+      tx_total,
+    }
+  }
+}
+
+impl PartialEq for NetworkWrapper {
+  fn eq(&self, other: &Self) -> bool {
+    self.interface == other.interface
+      && self.ip == other.ip
+      && self.rx_rate == other.rx_rate
+      && self.tx_rate == other.tx_rate
+      && self
+        .stats
+        .as_ref()
+        .zip(other.stats.as_ref())
+        .map_or(true, |(a, b)| a.rx_bytes == b.rx_bytes && a.tx_bytes == b.tx_bytes)
+  }
+}
+
+/// Resolves the interface to sample and its ip address. If no interface is specified the first
+/// interface with a non-loopback ipv4 address is used.
+fn find_interface(sys: &System, interface: Option<&str>) -> Option<(String, Option<String>)> {
+  let networks = match sys.networks() {
+    Ok(networks) => networks,
+    Err(err) => {
+      log::error!("Failed to fetch network interfaces: {}", err);
+      return None;
+    }
+  };
+
+  let ip_of = |network: &systemstat::Network| {
+    network.addrs.iter().find_map(|addr| match addr.addr {
+      systemstat::IpAddr::V4(ip) if !ip.is_loopback() => Some(ip.to_string()),
+      _ => None,
+    })
+  };
+
+  match interface {
+    Some(name) => networks.get(name).map(|network| (network.name.clone(), ip_of(network))),
+    None => networks
+      .values()
+      .find_map(|network| ip_of(network).map(|ip| (network.name.clone(), Some(ip)))),
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct NetworkInfo {
+  interface: String,
+  ip: String,
+  rx_rate: f64,
+  tx_rate: f64,
+  rx_total: f64,
+  tx_total: f64,
+}
+
+fn format_network(format: &str, network: &NetworkWrapper) -> String {
+  let reg = register_hitokage_helpers(Handlebars::new());
+
+  match reg.render_template(format, &network.as_lua_args()) {
+    Ok(name) => return name,
+    Err(err) => {
+      log::error!("{:?}", err);
+    }
+  };
+
+  "".to_owned()
+}
@@ -0,0 +1,378 @@
+use super::app::AppMsg;
+use super::base::BaseMsgHook;
+use super::base::BaseProps;
+use super::r#box::{BoxInner, BoxMsg, BoxMsgHook, ChildMsg};
+use super::ChildUserData;
+use crate::components::deserialize_empty_or_seq;
+use crate::generate_base_match_arms;
+use crate::generate_box_children;
+use crate::generate_box_match_arms;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::reactive::create_react_sender;
+use crate::structs::reactive::Reactive;
+use crate::structs::Monitor;
+use crate::structs::Orientation;
+use gtk4::prelude::*;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::sync::mpsc::Sender;
+
+const DEFAULT_TRANSITION_DURATION: u32 = 250;
+const MIN_HIT_AREA: i32 = 8;
+
+#[derive(Debug, Clone)]
+pub enum RevealerMsgHook {
+  BaseHook(BaseMsgHook),
+  GetHomogeneous(Sender<bool>),
+  SetHomogeneous(bool),
+  GetChildren(Sender<Vec<ChildUserData>>),
+  GetRevealed(Sender<bool>),
+  SetRevealed(bool),
+  GetTransitionDuration(Sender<u32>),
+  SetTransitionDuration(u32),
+}
+
+#[derive(Debug)]
+pub enum RevealerMsg {
+  LuaHook(RevealerMsgHook),
+  ChildMsg(ChildMsg),
+  AppMsg(AppMsg),
+  Reveal(bool),
+  Toggle,
+  React,
+}
+
+#[derive(Debug)]
+pub enum RevealerMsgPortable {
+  LuaHook(RevealerMsgHook),
+}
+
+impl From<RevealerMsgPortable> for RevealerMsg {
+  fn from(value: RevealerMsgPortable) -> Self {
+    match value {
+      RevealerMsgPortable::LuaHook(luahook) => RevealerMsg::LuaHook(luahook),
+    }
+  }
+}
+
+impl From<BoxMsg> for RevealerMsg {
+  fn from(value: BoxMsg) -> Self {
+    match value {
+      BoxMsg::LuaHook(hook) => match hook {
+        BoxMsgHook::BaseHook(base) => RevealerMsg::LuaHook(RevealerMsgHook::BaseHook(base)),
+        BoxMsgHook::GetHomogeneous(tx) => RevealerMsg::LuaHook(RevealerMsgHook::GetHomogeneous(tx)),
+        BoxMsgHook::SetHomogeneous(homogeneous) => RevealerMsg::LuaHook(RevealerMsgHook::SetHomogeneous(homogeneous)),
+        BoxMsgHook::GetChildren(tx) => RevealerMsg::LuaHook(RevealerMsgHook::GetChildren(tx)),
+      },
+      BoxMsg::ChildMsg(msg) => RevealerMsg::ChildMsg(msg),
+      BoxMsg::AppMsg(msg) => RevealerMsg::AppMsg(msg),
+    }
+  }
+}
+
+/// What opens the revealer. It can always be opened from lua or with `revealed`.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+pub enum RevealOn {
+  /// Open while the pointer is over the revealer
+  #[default]
+  Hover,
+  /// Toggle when the revealer is clicked
+  Click,
+  /// Only open from lua or with `revealed`
+  Manual,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+pub enum RevealerTransition {
+  None,
+  Crossfade,
+  #[default]
+  SlideRight,
+  SlideLeft,
+  SlideUp,
+  SlideDown,
+}
+
+impl From<RevealerTransition> for gtk::RevealerTransitionType {
+  fn from(value: RevealerTransition) -> Self {
+    match value {
+      RevealerTransition::None => gtk::RevealerTransitionType::None,
+      RevealerTransition::Crossfade => gtk::RevealerTransitionType::Crossfade,
+      RevealerTransition::SlideRight => gtk::RevealerTransitionType::SlideRight,
+      RevealerTransition::SlideLeft => gtk::RevealerTransitionType::SlideLeft,
+      RevealerTransition::SlideUp => gtk::RevealerTransitionType::SlideUp,
+      RevealerTransition::SlideDown => gtk::RevealerTransitionType::SlideDown,
+    }
+  }
+}
+
+/// Whether the revealer starts open, or a reactive holding `"true"` or `"false"` which is kept in sync with it.
+#[derive(Debug, Clone)]
+pub enum RevealedValue {
+  Bool(bool),
+  Reactive(Reactive<String>),
+}
+
+struct RevealedValueVisitor;
+
+impl serde::de::Visitor<'_> for RevealedValueVisitor {
+  type Value = RevealedValue;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a boolean or a reactive")
+  }
+
+  fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(RevealedValue::Bool(v))
+  }
+
+  fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(RevealedValue::Reactive(crate::structs::reactive_string::parse_bytes(
+      value,
+    )))
+  }
+}
+
+impl<'de> Deserialize<'de> for RevealedValue {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_any(RevealedValueVisitor)
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RevealerProps {
+  #[serde(flatten)]
+  pub base: BaseProps,
+  pub homogeneous: Option<bool>,
+  /// Defaults to `Horizontal`. Applies to both the trigger and the revealed children
+  pub orientation: Option<Orientation>,
+  /// Defaults to `false`
+  pub revealed: Option<RevealedValue>,
+  /// Defaults to `Hover`. Without `trigger` children, `Hover` and `Click` keep a [`MIN_HIT_AREA`] wide area to
+  /// open from unless `width_request` is set, or a tall one unless `height_request` is set when `Vertical`
+  pub reveal_on: Option<RevealOn>,
+  /// Defaults to `SlideRight`
+  pub transition_type: Option<RevealerTransition>,
+  /// Transition duration in milliseconds
+  pub transition_duration: Option<u32>,
+
+  /// Children which are always shown, placed before the revealed children
+  #[serde(default, deserialize_with = "deserialize_empty_or_seq")]
+  pub trigger: Option<Vec<super::Child>>,
+  /// Children which are revealed
+  #[serde(default, deserialize_with = "deserialize_empty_or_seq", alias = "widgets")]
+  pub children: Option<Vec<super::Child>>,
+}
+
+pub struct Revealer {
+  r#box: BoxInner,
+  revealer: gtk::Revealer,
+  content: gtk::Box,
+  reactive: Option<Reactive<String>>,
+}
+
+impl Revealer {
+  fn read_reactive(&self) -> Option<bool> {
+    let value = self.reactive.as_ref()?.get();
+    match value.trim().parse::<bool>() {
+      Ok(value) => Some(value),
+      Err(_) => {
+        log::error!(
+          "Expected 'true' or 'false' for revealer reactive, received: {:?}",
+          value
+        );
+        None
+      }
+    }
+  }
+
+  fn reveal(&self, revealed: bool) {
+    self.revealer.set_reveal_child(revealed);
+    if let Some(reactive) = &self.reactive {
+      reactive.set(revealed.to_string());
+    }
+  }
+}
+
+#[relm4::component(pub)]
+impl Component for Revealer {
+  type Input = RevealerMsg;
+  type Output = AppMsg;
+  type Init = (Monitor, RevealerProps);
+  type Widgets = RevealerWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::Box {
+      set_orientation: props.orientation.unwrap_or_default().into(),
+    }
+  }
+
+  fn init(input: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    let (monitor, props) = input;
+
+    let reveal_on = props.reveal_on.unwrap_or_default();
+    match reveal_on {
+      RevealOn::Hover => {
+        let motion = gtk::EventControllerMotion::new();
+        {
+          let sender = sender.clone();
+          motion.connect_enter(move |_, _, _| {
+            sender.input(RevealerMsg::Reveal(true));
+          });
+        }
+        {
+          let sender = sender.clone();
+          motion.connect_leave(move |_| {
+            sender.input(RevealerMsg::Reveal(false));
+          });
+        }
+        root.add_controller(motion);
+      }
+      RevealOn::Click => {
+        let click = gtk::GestureClick::new();
+        {
+          let sender = sender.clone();
+          click.connect_pressed(move |_, n_press, _, _| {
+            if n_press == 1 {
+              sender.input(RevealerMsg::Toggle);
+            }
+          });
+        }
+        root.add_controller(click);
+      }
+      RevealOn::Manual => {}
+    }
+
+    let (revealed, reactive) = match props.revealed {
+      Some(RevealedValue::Bool(revealed)) => (revealed, None),
+      Some(RevealedValue::Reactive(reactive)) => {
+        // our messages carry app messages and cannot be cloned, so react through a unit channel instead
+        let (react_sender, react_receiver) = relm4::channel::<()>();
+        relm4::spawn_local(react_receiver.forward(sender.input_sender().clone(), |_| RevealerMsg::React));
+        *reactive.sender.lock().unwrap() = Some(create_react_sender(&react_sender, ()));
+        (false, Some(reactive))
+      }
+      None => (false, None),
+    };
+
+    let content = gtk::Box::new(props.orientation.unwrap_or_default().into(), 0);
+    if let Some(homogeneous) = props.homogeneous {
+      content.set_homogeneous(homogeneous);
+    }
+    let revealer = gtk::Revealer::builder()
+      .transition_type(props.transition_type.unwrap_or_default().into())
+      .transition_duration(props.transition_duration.unwrap_or(DEFAULT_TRANSITION_DURATION))
+      .reveal_child(revealed)
+      .child(&content)
+      .build();
+
+    let mut model = Revealer {
+      r#box: BoxInner {
+        children: Vec::new(),
+        base: props.base.clone().into(),
+        homogeneous: props.homogeneous,
+      },
+      revealer,
+      content,
+      reactive,
+    };
+
+    if let Some(revealed) = model.read_reactive() {
+      model.revealer.set_reveal_child(revealed);
+    }
+
+    prepend_css_class_to_model!("revealer", model.r#box, root);
+    set_initial_base_props!(model.r#box, root, props.base);
+    let widgets = view_output!();
+
+    // children forward into a box channel, which is then mapped into our own messages
+    let (child_sender, child_receiver) = relm4::channel::<BoxMsg>();
+    relm4::spawn_local(child_receiver.forward(sender.input_sender().clone(), RevealerMsg::from));
+
+    // a closed revealer without triggers is otherwise 0 wide, and can never be hovered or clicked
+    let has_trigger = props.trigger.as_ref().is_some_and(|trigger| !trigger.is_empty());
+    if !has_trigger && reveal_on != RevealOn::Manual {
+      match props.orientation.unwrap_or_default() {
+        Orientation::Horizontal if props.base.width_request.is_none() => root.set_width_request(MIN_HIT_AREA),
+        Orientation::Vertical if props.base.height_request.is_none() => root.set_height_request(MIN_HIT_AREA),
+        _ => {}
+      }
+    }
+
+    generate_box_children!(props.trigger, model.r#box, monitor, root, &child_sender);
+    root.append(&model.revealer);
+    generate_box_children!(props.children, model.r#box, monitor, model.content, &child_sender);
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      RevealerMsg::LuaHook(hook) => match hook {
+        RevealerMsgHook::BaseHook(base) => {
+          generate_box_match_arms!(self, "revealer", root, BoxMsgHook, BoxMsgHook::BaseHook(base))
+        }
+        RevealerMsgHook::GetHomogeneous(tx) => {
+          tx.send(self.content.is_homogeneous()).unwrap();
+        }
+        RevealerMsgHook::SetHomogeneous(homogeneous) => {
+          self.r#box.homogeneous = Some(homogeneous);
+          self.content.set_homogeneous(homogeneous);
+        }
+        RevealerMsgHook::GetChildren(tx) => {
+          generate_box_match_arms!(self, "revealer", root, BoxMsgHook, BoxMsgHook::GetChildren(tx))
+        }
+        RevealerMsgHook::GetRevealed(tx) => {
+          tx.send(self.revealer.reveals_child()).unwrap();
+        }
+        RevealerMsgHook::SetRevealed(revealed) => {
+          self.reveal(revealed);
+        }
+        RevealerMsgHook::GetTransitionDuration(tx) => {
+          tx.send(self.revealer.transition_duration()).unwrap();
+        }
+        RevealerMsgHook::SetTransitionDuration(duration) => {
+          self.revealer.set_transition_duration(duration);
+        }
+      },
+      RevealerMsg::ChildMsg(msg) => match msg {
+        ChildMsg::Remove(child) => {
+          if let Some(parent) = child.parent().and_downcast::<gtk::Box>() {
+            parent.remove(&child);
+          }
+          self.r#box.children.retain(|w| w.widget() != child);
+        }
+      },
+      RevealerMsg::AppMsg(msg) => {
+        sender.output(msg).unwrap();
+      }
+      RevealerMsg::Reveal(revealed) => {
+        self.reveal(revealed);
+      }
+      RevealerMsg::Toggle => {
+        self.reveal(!self.revealer.reveals_child());
+      }
+      RevealerMsg::React => {
+        if let Some(revealed) = self.read_reactive() {
+          self.revealer.set_reveal_child(revealed);
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,333 @@
+use super::app::AppMsg;
+use super::base::request_lua_action;
+use super::base::Base;
+use super::base::BaseProps;
+use super::r#box::BoxMsg;
+use crate::components::base::BaseMsgHook;
+use crate::generate_base_match_arms;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::lua_fn::LuaFn;
+use crate::structs::reactive::create_react_sender;
+use crate::structs::reactive::Reactive;
+use gtk4::prelude::*;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub enum ScaleMsgHook {
+  BaseHook(BaseMsgHook),
+  GetValue(Sender<f64>),
+  SetValue(f64),
+  GetMin(Sender<f64>),
+  SetMin(f64),
+  GetMax(Sender<f64>),
+  SetMax(f64),
+  GetStep(Sender<f64>),
+  SetStep(f64),
+}
+
+#[derive(Debug, Clone)]
+pub enum ScaleMsg {
+  LuaHook(ScaleMsgHook),
+  React,
+  Change(f64),
+  /// The queued `on_change` call ran, or was dropped
+  ChangeHandled,
+}
+
+#[derive(Debug)]
+pub enum ScaleMsgOut {
+  RequestLuaAction(
+    Arc<mlua::RegistryKey>,
+    serde_json::Value,
+    std::sync::mpsc::Sender<mlua::Value>,
+  ),
+}
+
+impl From<ScaleMsgOut> for AppMsg {
+  fn from(value: ScaleMsgOut) -> Self {
+    match value {
+      ScaleMsgOut::RequestLuaAction(a, b, c) => AppMsg::RequestLuaAction(a, b, c),
+    }
+  }
+}
+
+impl From<ScaleMsgOut> for BoxMsg {
+  fn from(value: ScaleMsgOut) -> Self {
+    match value {
+      ScaleMsgOut::RequestLuaAction(a, b, c) => BoxMsg::AppMsg(AppMsg::RequestLuaAction(a, b, c)),
+    }
+  }
+}
+
+/// The initial value of the scale, or a reactive holding a number which is kept in sync with the scale.
+#[derive(Debug, Clone)]
+pub enum ScaleValue {
+  Number(f64),
+  /// A reactive holding a number, ie. `"0.5"`
+  Reactive(Reactive<String>),
+}
+
+struct ScaleValueVisitor;
+
+impl serde::de::Visitor<'_> for ScaleValueVisitor {
+  type Value = ScaleValue;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a number or a reactive")
+  }
+
+  fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(ScaleValue::Number(v))
+  }
+
+  fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(ScaleValue::Number(v as f64))
+  }
+
+  fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(ScaleValue::Number(v as f64))
+  }
+
+  fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(ScaleValue::Reactive(crate::structs::reactive_string::parse_bytes(
+      value,
+    )))
+  }
+}
+
+impl<'de> Deserialize<'de> for ScaleValue {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_any(ScaleValueVisitor)
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScaleProps {
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+  /// Defaults to `min`
+  value: Option<ScaleValue>,
+  /// Defaults to `0`
+  min: Option<f64>,
+  /// Defaults to `100`
+  max: Option<f64>,
+  /// Defaults to `1`
+  step: Option<f64>,
+  /// Called when the user changes the value, not when it is set from lua
+  on_change: Option<LuaFn>,
+}
+
+#[derive(Debug, Serialize)]
+struct ScaleChangeEvent {
+  id: Option<String>,
+  value: f64,
+}
+
+#[tracker::track]
+pub struct Scale {
+  #[tracker::do_not_track]
+  base: Base,
+  #[tracker::do_not_track]
+  reactive: Option<Reactive<String>>,
+  #[tracker::do_not_track]
+  on_change: Option<LuaFn>,
+  /// An `on_change` call is queued, dragging emits far more changes than lua can keep up with
+  #[tracker::do_not_track]
+  change_pending: bool,
+  /// The value changed again while an `on_change` call was queued
+  #[tracker::do_not_track]
+  change_dirty: bool,
+  value: f64,
+  min: f64,
+  max: f64,
+  step: f64,
+}
+
+impl Scale {
+  fn clamp(&self, value: f64) -> f64 {
+    value.clamp(self.min, self.max.max(self.min))
+  }
+
+  fn read_reactive(&self) -> Option<f64> {
+    let value = self.reactive.as_ref()?.get();
+    match value.trim().parse::<f64>() {
+      Ok(value) => Some(value),
+      Err(_) => {
+        log::error!("Expected a number for scale reactive, received: {:?}", value);
+        None
+      }
+    }
+  }
+
+  fn update_value(&mut self, value: f64) {
+    let value = self.clamp(value);
+    self.set_value(value);
+    if let Some(reactive) = &self.reactive {
+      reactive.set(value.to_string());
+    }
+  }
+
+  /// Queues `on_change` with the current value, unless a call is already queued. That call is followed up with the
+  /// latest value once it ran.
+  fn request_change(&mut self, sender: &ComponentSender<Self>) {
+    let Some(callback) = self.on_change.clone() else {
+      return;
+    };
+    if self.change_pending {
+      self.change_dirty = true;
+      return;
+    }
+    self.change_pending = true;
+    self.change_dirty = false;
+
+    let event = ScaleChangeEvent {
+      id: self.base.id.clone(),
+      value: self.value,
+    };
+    let rx = request_lua_action(&callback, serde_json::to_value(event).unwrap());
+    let input = sender.input_sender().clone();
+    // the receiver resolves once lua ran the handler, or disconnects if the request was dropped
+    relm4::spawn_blocking(move || {
+      let _ = rx.recv();
+      let _ = input.send(ScaleMsg::ChangeHandled);
+    });
+  }
+}
+
+#[relm4::component(pub)]
+impl Component for Scale {
+  type Input = ScaleMsg;
+  type Output = ScaleMsgOut;
+  type Init = ScaleProps;
+  type Widgets = ScaleWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::Scale {
+      #[track = "model.changed(Scale::min() | Scale::max())"]
+      set_range: (model.min, model.max.max(model.min)),
+      #[track = "model.changed(Scale::step())"]
+      set_increments: (model.step, model.step * 10.0),
+      #[track = "model.changed(Scale::value() | Scale::min() | Scale::max())"]
+      set_value: model.clamp(model.value),
+    }
+  }
+
+  fn init(props: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    // change-value is only emitted for user interaction, so values set from lua do not loop back into on_change
+    {
+      let sender = sender.clone();
+      root.connect_change_value(move |_, _, value| {
+        sender.input(ScaleMsg::Change(value));
+        glib::Propagation::Proceed
+      });
+    }
+
+    let min = props.min.unwrap_or(0.0);
+    let (value, reactive) = match props.value {
+      Some(ScaleValue::Number(value)) => (value, None),
+      Some(ScaleValue::Reactive(reactive)) => {
+        *reactive.sender.lock().unwrap() = Some(create_react_sender(sender.input_sender(), ScaleMsg::React));
+        (min, Some(reactive))
+      }
+      None => (min, None),
+    };
+
+    let mut model = Scale {
+      base: props.base.clone().into(),
+      reactive,
+      on_change: props.on_change,
+      change_pending: false,
+      change_dirty: false,
+      value,
+      min,
+      max: props.max.unwrap_or(100.0),
+      step: props.step.unwrap_or(1.0),
+      tracker: 0,
+    };
+
+    if let Some(value) = model.read_reactive() {
+      model.value = value;
+    }
+
+    prepend_css_class_to_model!("scale", model, root);
+    set_initial_base_props!(model, root, props.base);
+
+    let widgets = view_output!();
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      ScaleMsg::LuaHook(hook) => match hook {
+        ScaleMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "scale", root, base)
+        }
+        ScaleMsgHook::GetValue(tx) => {
+          tx.send(self.value).unwrap();
+        }
+        ScaleMsgHook::SetValue(value) => {
+          self.update_value(value);
+        }
+        ScaleMsgHook::GetMin(tx) => {
+          tx.send(self.min).unwrap();
+        }
+        ScaleMsgHook::SetMin(min) => {
+          self.set_min(min);
+        }
+        ScaleMsgHook::GetMax(tx) => {
+          tx.send(self.max).unwrap();
+        }
+        ScaleMsgHook::SetMax(max) => {
+          self.set_max(max);
+        }
+        ScaleMsgHook::GetStep(tx) => {
+          tx.send(self.step).unwrap();
+        }
+        ScaleMsgHook::SetStep(step) => {
+          self.set_step(step);
+        }
+      },
+      ScaleMsg::React => {
+        if let Some(value) = self.read_reactive() {
+          self.set_value(value);
+        }
+      }
+      ScaleMsg::Change(value) => {
+        self.update_value(value);
+        self.request_change(&sender);
+      }
+      ScaleMsg::ChangeHandled => {
+        self.change_pending = false;
+        if self.change_dirty {
+          self.request_change(&sender);
+        }
+      }
+    }
+  }
+}
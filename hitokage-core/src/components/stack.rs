@@ -0,0 +1,270 @@
+use super::app::AppMsg;
+use super::base::Base;
+use super::base::BaseMsgHook;
+use super::base::BaseProps;
+use super::r#box::{BoxMsg, BoxMsgHook, ChildMsg};
+use super::ChildController;
+use super::ChildUserData;
+use crate::components::deserialize_empty_or_seq;
+use crate::generate_base_match_arms;
+use crate::generate_box_children;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::Monitor;
+use gtk4::prelude::*;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::Sender;
+
+const DEFAULT_TRANSITION_DURATION: u32 = 200;
+
+#[derive(Debug, Clone)]
+pub enum StackMsgHook {
+  BaseHook(BaseMsgHook),
+  GetChildren(Sender<Vec<ChildUserData>>),
+  GetVisibleChild(Sender<Option<String>>),
+  SetVisibleChild(String),
+  Next,
+  Previous,
+}
+
+#[derive(Debug)]
+pub enum StackMsg {
+  LuaHook(StackMsgHook),
+  ChildMsg(ChildMsg),
+  AppMsg(AppMsg),
+}
+
+#[derive(Debug)]
+pub enum StackMsgPortable {
+  LuaHook(StackMsgHook),
+}
+
+impl From<StackMsgPortable> for StackMsg {
+  fn from(value: StackMsgPortable) -> Self {
+    match value {
+      StackMsgPortable::LuaHook(luahook) => StackMsg::LuaHook(luahook),
+    }
+  }
+}
+
+impl From<BoxMsg> for StackMsg {
+  fn from(value: BoxMsg) -> Self {
+    match value {
+      BoxMsg::LuaHook(hook) => match hook {
+        BoxMsgHook::BaseHook(base) => StackMsg::LuaHook(StackMsgHook::BaseHook(base)),
+        BoxMsgHook::GetChildren(tx) => StackMsg::LuaHook(StackMsgHook::GetChildren(tx)),
+        // a stack has no homogeneous property of its own
+        BoxMsgHook::GetHomogeneous(_) | BoxMsgHook::SetHomogeneous(_) => StackMsg::AppMsg(AppMsg::NoOp),
+      },
+      BoxMsg::ChildMsg(msg) => StackMsg::ChildMsg(msg),
+      BoxMsg::AppMsg(msg) => StackMsg::AppMsg(msg),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+pub enum StackTransition {
+  None,
+  #[default]
+  Crossfade,
+  SlideRight,
+  SlideLeft,
+  SlideUp,
+  SlideDown,
+  SlideLeftRight,
+  SlideUpDown,
+}
+
+impl From<StackTransition> for gtk::StackTransitionType {
+  fn from(value: StackTransition) -> Self {
+    match value {
+      StackTransition::None => gtk::StackTransitionType::None,
+      StackTransition::Crossfade => gtk::StackTransitionType::Crossfade,
+      StackTransition::SlideRight => gtk::StackTransitionType::SlideRight,
+      StackTransition::SlideLeft => gtk::StackTransitionType::SlideLeft,
+      StackTransition::SlideUp => gtk::StackTransitionType::SlideUp,
+      StackTransition::SlideDown => gtk::StackTransitionType::SlideDown,
+      StackTransition::SlideLeftRight => gtk::StackTransitionType::SlideLeftRight,
+      StackTransition::SlideUpDown => gtk::StackTransitionType::SlideUpDown,
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StackProps {
+  #[serde(flatten)]
+  pub base: BaseProps,
+  /// The id of the child shown first, defaults to the first child
+  pub visible_child: Option<String>,
+  /// Rotate to the next child every `interval` milliseconds
+  pub interval: Option<u64>,
+  /// Rotate when scrolled over, defaults to `true`
+  pub scroll: Option<bool>,
+  /// Defaults to `Crossfade`
+  pub transition_type: Option<StackTransition>,
+  /// Transition duration in milliseconds
+  pub transition_duration: Option<u32>,
+
+  #[serde(default, deserialize_with = "deserialize_empty_or_seq", alias = "widgets")]
+  pub children: Option<Vec<super::Child>>,
+}
+
+pub struct Stack {
+  base: Base,
+  children: Vec<ChildController>,
+  source_id: Option<glib::SourceId>,
+}
+
+impl Stack {
+  /// Moves the visible child by `offset` pages, wrapping around at either end
+  fn rotate(&self, root: &gtk::Stack, offset: isize) {
+    let widgets: Vec<gtk::Widget> = self.children.iter().map(ChildController::widget).collect();
+    if widgets.is_empty() {
+      return;
+    }
+    let current = root
+      .visible_child()
+      .and_then(|visible| widgets.iter().position(|w| *w == visible))
+      .unwrap_or(0);
+    let next = (current as isize + offset).rem_euclid(widgets.len() as isize) as usize;
+    root.set_visible_child(&widgets[next]);
+  }
+}
+
+/// Lets `generate_box_children` add pages to the stack as if it were a box
+trait AppendPage {
+  fn append(&self, child: &impl IsA<gtk::Widget>);
+}
+
+impl AppendPage for gtk::Stack {
+  fn append(&self, child: &impl IsA<gtk::Widget>) {
+    self.add_child(child);
+  }
+}
+
+#[relm4::component(pub)]
+impl Component for Stack {
+  type Input = StackMsg;
+  type Output = AppMsg;
+  type Init = (Monitor, StackProps);
+  type Widgets = StackWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::Stack {}
+  }
+
+  fn init(input: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    let (monitor, props) = input;
+
+    root.set_transition_type(props.transition_type.unwrap_or_default().into());
+    root.set_transition_duration(props.transition_duration.unwrap_or(DEFAULT_TRANSITION_DURATION));
+
+    let source_id = props.interval.map(|interval| {
+      let sender = sender.clone();
+      glib::timeout_add_local(std::time::Duration::from_millis(interval.max(1)), move || {
+        sender.input(StackMsg::LuaHook(StackMsgHook::Next));
+        glib::ControlFlow::Continue
+      })
+    });
+
+    if props.scroll.unwrap_or(true) {
+      let scroll = gtk::EventControllerScroll::new(
+        gtk::EventControllerScrollFlags::VERTICAL | gtk::EventControllerScrollFlags::DISCRETE,
+      );
+      {
+        let sender = sender.clone();
+        scroll.connect_scroll(move |_, _, dy| {
+          let hook = if dy < 0.0 {
+            StackMsgHook::Previous
+          } else {
+            StackMsgHook::Next
+          };
+          sender.input(StackMsg::LuaHook(hook));
+          glib::Propagation::Stop
+        });
+      }
+      root.add_controller(scroll);
+    }
+
+    let mut model = Stack {
+      base: props.base.clone().into(),
+      children: Vec::new(),
+      source_id,
+    };
+
+    prepend_css_class_to_model!("stack", model, root);
+    set_initial_base_props!(model, root, props.base);
+    let widgets = view_output!();
+
+    // children forward into a box channel, which is then mapped into our own messages
+    let (child_sender, child_receiver) = relm4::channel::<BoxMsg>();
+    relm4::spawn_local(child_receiver.forward(sender.input_sender().clone(), StackMsg::from));
+
+    // pages are named after the id of their child, so they can be shown by id
+    let ids: Vec<Option<String>> = props
+      .children
+      .iter()
+      .flatten()
+      .map(|child| child.base().id.clone())
+      .collect();
+    generate_box_children!(props.children, model, monitor, root, &child_sender);
+    for (child, id) in model.children.iter().zip(ids) {
+      if let Some(id) = id {
+        root.page(&child.widget()).set_name(&id);
+      }
+    }
+
+    if let Some(id) = props.visible_child {
+      match root.child_by_name(&id) {
+        Some(child) => root.set_visible_child(&child),
+        None => log::warn!("Stack has no child with id: {}", id),
+      }
+    }
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      StackMsg::LuaHook(hook) => match hook {
+        StackMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "stack", root, base)
+        }
+        StackMsgHook::GetChildren(tx) => {
+          tx.send(self.children.iter().map(ChildUserData::from).collect())
+            .unwrap();
+        }
+        StackMsgHook::GetVisibleChild(tx) => {
+          tx.send(root.visible_child_name().map(|id| id.to_string())).unwrap();
+        }
+        StackMsgHook::SetVisibleChild(id) => match root.child_by_name(&id) {
+          Some(child) => root.set_visible_child(&child),
+          None => log::warn!("Stack has no child with id: {}", id),
+        },
+        StackMsgHook::Next => self.rotate(root, 1),
+        StackMsgHook::Previous => self.rotate(root, -1),
+      },
+      StackMsg::ChildMsg(msg) => match msg {
+        ChildMsg::Remove(child) => {
+          root.remove(&child);
+          self.children.retain(|w| w.widget() != child);
+        }
+      },
+      StackMsg::AppMsg(msg) => {
+        sender.output(msg).unwrap();
+      }
+    }
+  }
+
+  fn shutdown(&mut self, _widgets: &mut Self::Widgets, _output: relm4::Sender<Self::Output>) {
+    if let Some(a) = self.source_id.take() {
+      glib::SourceId::remove(a)
+    }
+  }
+}
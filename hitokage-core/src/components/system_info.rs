@@ -0,0 +1,235 @@
+use super::app::AppMsg;
+use super::base::request_lua_action;
+use super::base::Base;
+use super::base::BaseProps;
+use super::r#box::BoxMsg;
+use crate::components::base::BaseMsgHook;
+use crate::generate_base_match_arms;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::lua_fn::LuaFn;
+use crate::structs::reactive::create_react_sender;
+use crate::structs::reactive::AsReactive;
+use crate::structs::reactive::Reactive;
+use crate::structs::reactive_string_fn::ReactiveStringFn;
+use crate::structs::system::SystemInfoWrapper;
+use crate::structs::system::SystemWrapper;
+use gtk4::prelude::*;
+use relm4::prelude::*;
+use serde::Deserialize;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+
+const DEFAULT_INTERVAL: u64 = 1000;
+
+#[derive(Debug, Clone)]
+pub enum SystemInfoMsgHook {
+  BaseHook(BaseMsgHook),
+  GetFormat(Sender<String>),
+  GetFormatReactive(Sender<Reactive<String>>),
+  SetFormat(String),
+  GetInterval(Sender<u64>),
+  SetInterval(u64),
+}
+
+#[derive(Debug, Clone)]
+pub enum SystemInfoMsg {
+  LuaHook(SystemInfoMsgHook),
+  React,
+  Tick,
+  Callback,
+  /// The result of the `format` lua function
+  Formatted(String),
+}
+
+#[derive(Debug)]
+pub enum SystemInfoMsgOut {
+  RequestSystem(relm4::tokio::sync::oneshot::Sender<SystemWrapper>),
+  RequestLuaAction(
+    Arc<mlua::RegistryKey>,
+    serde_json::Value,
+    std::sync::mpsc::Sender<mlua::Value>,
+  ),
+}
+
+impl From<SystemInfoMsgOut> for AppMsg {
+  fn from(value: SystemInfoMsgOut) -> Self {
+    match value {
+      SystemInfoMsgOut::RequestSystem(tx) => AppMsg::RequestSystem(tx),
+      SystemInfoMsgOut::RequestLuaAction(a, b, c) => AppMsg::RequestLuaAction(a, b, c),
+    }
+  }
+}
+
+impl From<SystemInfoMsgOut> for BoxMsg {
+  fn from(value: SystemInfoMsgOut) -> Self {
+    match value {
+      SystemInfoMsgOut::RequestSystem(tx) => BoxMsg::AppMsg(AppMsg::RequestSystem(tx)),
+      SystemInfoMsgOut::RequestLuaAction(a, b, c) => BoxMsg::AppMsg(AppMsg::RequestLuaAction(a, b, c)),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SystemInfoProps {
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+  format: ReactiveStringFn,
+  /// Refresh interval in milliseconds
+  interval: Option<u64>,
+}
+
+#[tracker::track]
+pub struct SystemInfo {
+  #[tracker::do_not_track]
+  base: Base,
+  #[tracker::do_not_track]
+  source_id: Option<glib::SourceId>,
+  #[tracker::do_not_track]
+  interval: u64,
+  #[tracker::do_not_track]
+  format: Reactive<String>,
+  #[tracker::do_not_track]
+  system: SystemWrapper,
+  info: SystemInfoWrapper,
+  react: bool,
+  #[tracker::do_not_track]
+  callback: Option<LuaFn>,
+}
+
+#[relm4::component(async, pub)]
+impl AsyncComponent for SystemInfo {
+  type Input = SystemInfoMsg;
+  type Output = SystemInfoMsgOut;
+  type Init = SystemInfoProps;
+  type Widgets = SystemInfoWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::Label {
+      #[track = "model.changed(SystemInfo::react() | SystemInfo::info())"]
+      set_label: &model.info.format_with(&model.format.get()),
+    }
+  }
+
+  async fn init(props: Self::Init, root: Self::Root, sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+    let (tx, rx) = relm4::tokio::sync::oneshot::channel::<_>();
+    let _ = sender.output(SystemInfoMsgOut::RequestSystem(tx));
+    let system = match rx.await {
+      Ok(v) => v,
+      Err(err) => {
+        log::error!("Failed to receive system: {}", err);
+        SystemWrapper::new()
+      }
+    };
+
+    let callback = props.format.as_fn();
+    let reactive = props
+      .format
+      .as_reactive(create_react_sender(sender.input_sender(), SystemInfoMsg::React));
+
+    let interval = props.interval.unwrap_or(DEFAULT_INTERVAL);
+    let source_id = create_source(&sender, callback.is_some(), interval);
+
+    let mut model = SystemInfo {
+      base: props.base.clone().into(),
+      source_id: Some(source_id),
+      interval,
+      format: reactive,
+      info: system.system_info(),
+      system,
+      callback,
+      react: false,
+      tracker: 0,
+    };
+
+    prepend_css_class_to_model!("system_info", model, root);
+    set_initial_base_props!(model, root, props.base);
+
+    let widgets = view_output!();
+
+    root.show();
+
+    AsyncComponentParts { model, widgets }
+  }
+
+  async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      SystemInfoMsg::LuaHook(hook) => match hook {
+        SystemInfoMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "system_info", root, base)
+        }
+        SystemInfoMsgHook::GetFormat(tx) => {
+          tx.send(self.format.get()).unwrap();
+        }
+        SystemInfoMsgHook::GetFormatReactive(tx) => {
+          tx.send(self.format.clone()).unwrap();
+        }
+        SystemInfoMsgHook::SetFormat(format) => {
+          let arc = self.format.value.clone();
+          let mut str = arc.lock().unwrap();
+          *str = format;
+          self.set_react(!self.react);
+        }
+        SystemInfoMsgHook::GetInterval(tx) => {
+          tx.send(self.interval).unwrap();
+        }
+        SystemInfoMsgHook::SetInterval(interval) => {
+          self.interval = interval;
+          if let Some(source_id) = self.source_id.take() {
+            source_id.remove();
+          }
+          self.source_id = Some(create_source(&sender, self.callback.is_some(), interval));
+        }
+      },
+      SystemInfoMsg::React => {
+        self.set_react(!self.react);
+      }
+      SystemInfoMsg::Tick => {
+        self.set_info(self.system.system_info());
+      }
+      SystemInfoMsg::Callback => {
+        if let Some(callback) = &self.callback {
+          let rx = request_lua_action(callback, serde_json::to_value(self.info.as_lua_args()).unwrap());
+          let input = sender.input_sender().clone();
+          // the receiver only resolves once the lua scheduler ran the function, or disconnects if it never will
+          relm4::spawn_blocking(move || match rx.recv() {
+            Ok(mlua::Value::String(s)) => {
+              let _ = input.send(SystemInfoMsg::Formatted(s.to_string_lossy()));
+            }
+            Ok(v) => {
+              log::error!("Expected string for system info callback, received: {:?}", v);
+            }
+            Err(_) => {}
+          });
+        }
+      }
+      SystemInfoMsg::Formatted(format) => {
+        self.format.set(format);
+      }
+    }
+  }
+
+  fn shutdown(&mut self, _widgets: &mut Self::Widgets, _sender: relm4::Sender<Self::Output>) {
+    if let Some(a) = self.source_id.take() {
+      glib::SourceId::remove(a)
+    }
+  }
+}
+
+fn create_source(sender: &AsyncComponentSender<SystemInfo>, has_callback: bool, interval: u64) -> glib::SourceId {
+  let sender = sender.clone();
+  let interval = std::time::Duration::from_millis(interval.max(1));
+
+  match has_callback {
+    true => glib::timeout_add_local(interval, move || {
+      sender.input(SystemInfoMsg::Tick);
+      sender.input(SystemInfoMsg::Callback);
+      glib::ControlFlow::Continue
+    }),
+    false => glib::timeout_add_local(interval, move || {
+      sender.input(SystemInfoMsg::Tick);
+      glib::ControlFlow::Continue
+    }),
+  }
+}
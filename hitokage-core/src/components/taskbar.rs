@@ -0,0 +1,308 @@
+use super::base::{Base, BaseMsgHook, BaseProps};
+use crate::event::STATE;
+use crate::handlebar::register_hitokage_helpers;
+use crate::komorebi::{get_container_position, get_focused_window, get_focused_workspace, get_workspace_windows};
+use crate::structs::EllipsizeMode;
+use crate::win_utils::{get_window_icon, set_foreground_window};
+use crate::{generate_base_match_arms, prepend_css_class_to_model, set_initial_base_props};
+use gtk4::prelude::*;
+use handlebars::Handlebars;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+
+#[derive(Debug, Clone)]
+pub enum TaskbarMsgHook {
+  BaseHook(BaseMsgHook),
+  GetFormat(Sender<String>),
+  SetFormat(String),
+  GetMaxLength(Sender<Option<u32>>),
+  SetMaxLength(Option<u32>),
+  GetIconSize(Sender<i32>),
+  SetIconSize(i32),
+}
+
+#[derive(Debug, Clone)]
+pub enum TaskbarMsg {
+  Windows(Vec<TaskbarItem>),
+  FocusWindow(usize),
+  LuaHook(TaskbarMsgHook),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TaskbarProps {
+  /// Handlebars template with `title`, `exe`, `class` and `focused`, defaults to `{{title}}`
+  format: Option<String>,
+  /// Truncates each title to this many characters
+  max_length: Option<u32>,
+  /// Where to place the ellipsis when truncating, defaults to `End`
+  ellipsize: Option<EllipsizeMode>,
+  /// Defaults to `16`
+  icon_size: Option<i32>,
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TaskbarItem {
+  hwnd: isize,
+  title: String,
+  exe: String,
+  class: String,
+  focused: bool,
+}
+
+pub struct Taskbar {
+  base: Base,
+  windows: Vec<TaskbarItem>,
+  icons: HashMap<isize, gdk4::MemoryTexture>,
+  format: String,
+  max_length: Option<u32>,
+  ellipsize: EllipsizeMode,
+  icon_size: i32,
+  monitor_id: u32,
+}
+
+impl Taskbar {
+  /// Focuses a window through komorebi so it stays in sync, komorebi is already allowed to take the foreground. Only
+  /// windows outside of the tiled containers, ie. floating windows, are focused by us.
+  fn focus_window(&self, hwnd: isize) -> anyhow::Result<()> {
+    let state = STATE.read();
+    let workspace = get_focused_workspace(&state, self.monitor_id)?;
+
+    let Some((index, focused, len)) = get_container_position(workspace, hwnd) else {
+      anyhow::ensure!(set_foreground_window(hwnd), "Failed to set the foreground window");
+      return Ok(());
+    };
+
+    // komorebi focuses containers relative to the focused one, so cycle the shorter way around to the index
+    let forward = (index + len - focused) % len;
+    let (direction, steps) = if forward <= len / 2 {
+      (komorebi_client::CycleDirection::Next, forward)
+    } else {
+      (komorebi_client::CycleDirection::Previous, len - forward)
+    };
+
+    // don't drag the cursor along every container on the way
+    let mouse_follows_focus = state
+      .get("mouse_follows_focus")
+      .and_then(|v| v.as_bool())
+      .unwrap_or(false);
+    drop(state);
+
+    if mouse_follows_focus {
+      komorebi_client::send_message(&komorebi_client::SocketMessage::MouseFollowsFocus(false))?;
+    }
+    let result = (0..steps)
+      .try_for_each(|_| komorebi_client::send_message(&komorebi_client::SocketMessage::CycleFocusWindow(direction)));
+    if mouse_follows_focus {
+      komorebi_client::send_message(&komorebi_client::SocketMessage::MouseFollowsFocus(true))?;
+    }
+
+    Ok(result?)
+  }
+
+  fn get_icon(&mut self, hwnd: isize) -> Option<gdk4::MemoryTexture> {
+    if let Some(icon) = self.icons.get(&hwnd) {
+      return Some(icon.clone());
+    }
+    let (width, height, pixels) = get_window_icon(hwnd)?;
+    let icon = gdk4::MemoryTexture::new(
+      width,
+      height,
+      gdk4::MemoryFormat::B8g8r8a8,
+      &glib::Bytes::from_owned(pixels),
+      (width * 4) as usize,
+    );
+    self.icons.insert(hwnd, icon.clone());
+    Some(icon)
+  }
+
+  /// Rebuilds every item, this is cheap enough since it only happens when the window list changes
+  fn update_items(&mut self, root: &gtk::Box, sender: &ComponentSender<Self>) {
+    while let Some(child) = root.first_child() {
+      root.remove(&child);
+    }
+
+    // forget icons of windows that have gone away
+    let hwnds: Vec<isize> = self.windows.iter().map(|w| w.hwnd).collect();
+    self.icons.retain(|hwnd, _| hwnds.contains(hwnd));
+
+    let reg = register_hitokage_helpers(Handlebars::new());
+
+    for (index, window) in self.windows.clone().iter().enumerate() {
+      let item = gtk::Box::new(gtk::Orientation::Horizontal, 4);
+      item.add_css_class("taskbar_item");
+      if window.focused {
+        item.add_css_class("focused");
+      }
+      item.set_tooltip_text(Some(&window.title));
+
+      let image = match self.get_icon(window.hwnd) {
+        Some(icon) => gtk::Image::from_paintable(Some(&icon)),
+        None => gtk::Image::new(),
+      };
+      image.set_pixel_size(self.icon_size);
+      item.append(&image);
+
+      let text = match reg.render_template(&self.format, window) {
+        Ok(text) => text,
+        Err(err) => {
+          log::error!("{:?}", err);
+          window.title.clone()
+        }
+      };
+      // an empty format only shows icons
+      if !text.is_empty() {
+        let label = gtk::Label::new(Some(&text));
+        if let Some(max_length) = self.max_length {
+          label.set_max_width_chars(max_length as i32);
+          label.set_ellipsize(self.ellipsize.into());
+        }
+        item.append(&label);
+      }
+
+      let click = gtk::GestureClick::new();
+      {
+        let sender = sender.clone();
+        click.connect_released(move |_, n_press, _, _| {
+          if n_press == 1 {
+            sender.input(TaskbarMsg::FocusWindow(index));
+          }
+        });
+      }
+      item.add_controller(click);
+
+      root.append(&item);
+    }
+  }
+}
+
+#[relm4::component(pub)]
+impl Component for Taskbar {
+  type Input = TaskbarMsg;
+  type Output = ();
+  type Init = (TaskbarProps, u32); // win id
+  type CommandOutput = ();
+
+  view! {
+    gtk::Box {
+      set_spacing: 4,
+    }
+  }
+
+  fn init(propst: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    let (props, id) = propst;
+
+    let mut model = Taskbar {
+      base: props.base.clone().into(),
+      windows: Vec::new(),
+      icons: HashMap::new(),
+      format: props.format.unwrap_or("{{title}}".to_string()),
+      max_length: props.max_length,
+      ellipsize: props.ellipsize.unwrap_or_default(),
+      icon_size: props.icon_size.unwrap_or(16),
+      monitor_id: id,
+    };
+
+    prepend_css_class_to_model!("taskbar", model, root);
+    set_initial_base_props!(model, root, props.base);
+
+    STATE.subscribe(sender.input_sender(), move |state| {
+      TaskbarMsg::Windows(get_windows(state, id))
+    });
+
+    let widgets = view_output!();
+
+    sender.input(TaskbarMsg::Windows(get_windows(&STATE.read(), id)));
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      TaskbarMsg::Windows(windows) => {
+        if windows != self.windows {
+          self.windows = windows;
+          self.update_items(root, &sender);
+        }
+      }
+      TaskbarMsg::FocusWindow(index) => {
+        if let Some(window) = self.windows.get(index) {
+          if window.focused {
+            return;
+          }
+          log::info!("hitokage is focusing window {}", window.hwnd);
+          if let Err(err) = self.focus_window(window.hwnd) {
+            log::error!("Failed to focus window {}: {:?}", window.hwnd, err);
+          }
+        }
+      }
+      TaskbarMsg::LuaHook(hook) => match hook {
+        TaskbarMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "taskbar", root, base)
+        }
+        TaskbarMsgHook::GetFormat(tx) => {
+          tx.send(self.format.clone()).unwrap();
+        }
+        TaskbarMsgHook::SetFormat(format) => {
+          self.format = format;
+          self.update_items(root, &sender);
+        }
+        TaskbarMsgHook::GetMaxLength(tx) => {
+          tx.send(self.max_length).unwrap();
+        }
+        TaskbarMsgHook::SetMaxLength(max_length) => {
+          self.max_length = max_length;
+          self.update_items(root, &sender);
+        }
+        TaskbarMsgHook::GetIconSize(tx) => {
+          tx.send(self.icon_size).unwrap();
+        }
+        TaskbarMsgHook::SetIconSize(icon_size) => {
+          self.icon_size = icon_size;
+          self.update_items(root, &sender);
+        }
+      },
+    }
+  }
+}
+
+// get the windows of the focused workspace on this monitor from komorebi
+fn get_windows(state: &serde_json::Value, monitor_id: u32) -> Vec<TaskbarItem> {
+  // komorebi has not sent us a state yet
+  if state.is_null() {
+    return Vec::new();
+  }
+
+  let workspace = match get_focused_workspace(state, monitor_id) {
+    Ok(workspace) => workspace,
+    Err(err) => {
+      log::error!("Failed to read focused workspace: {:?}", err);
+      return Vec::new();
+    }
+  };
+
+  let focused_hwnd = get_focused_window(workspace)
+    .and_then(|w| w.get("hwnd"))
+    .and_then(|v| v.as_i64());
+
+  get_workspace_windows(workspace)
+    .filter_map(|window| {
+      let hwnd = window.get("hwnd").and_then(|v| v.as_i64())?;
+      let field = |key: &str| window.get(key).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+      Some(TaskbarItem {
+        hwnd: hwnd as isize,
+        title: field("title"),
+        exe: field("exe"),
+        class: field("class"),
+        focused: Some(hwnd) == focused_hwnd,
+      })
+    })
+    .collect()
+}
@@ -0,0 +1,461 @@
+use super::app::AppMsg;
+use super::base::request_lua_action;
+use super::base::Base;
+use super::base::BaseProps;
+use super::r#box::BoxMsg;
+use crate::components::base::BaseMsgHook;
+use crate::generate_base_match_arms;
+use crate::handlebar::register_hitokage_helpers;
+use crate::prepend_css_class;
+use crate::prepend_css_class_to_model;
+use crate::set_initial_base_props;
+use crate::structs::lua_fn::LuaFn;
+use crate::structs::reactive::create_react_sender;
+use crate::structs::reactive::AsReactive;
+use crate::structs::reactive::Reactive;
+use crate::structs::reactive_string_fn::ReactiveStringFn;
+use gtk4::prelude::*;
+use handlebars::Handlebars;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use systemstat::Platform;
+use systemstat::System;
+
+const CPU_SENSOR: &str = "cpu";
+
+#[derive(Debug, Clone)]
+pub enum TemperatureMsgHook {
+  BaseHook(BaseMsgHook),
+  GetFormat(Sender<String>),
+  GetFormatReactive(Sender<Reactive<String>>),
+  SetFormat(String),
+  GetSensor(Sender<Option<String>>),
+  SetSensor(Option<String>),
+  GetWarn(Sender<Option<f32>>),
+  SetWarn(Option<f32>),
+  GetCrit(Sender<Option<f32>>),
+  SetCrit(Option<f32>),
+}
+
+#[derive(Debug, Clone)]
+pub enum TemperatureMsg {
+  LuaHook(TemperatureMsgHook),
+  React,
+  Tick,
+  Callback,
+  /// The result of the `format` lua function
+  Formatted(String),
+}
+
+#[derive(Debug)]
+pub enum TemperatureMsgOut {
+  RequestLuaAction(
+    Arc<mlua::RegistryKey>,
+    serde_json::Value,
+    std::sync::mpsc::Sender<mlua::Value>,
+  ),
+}
+
+impl From<TemperatureMsgOut> for AppMsg {
+  fn from(value: TemperatureMsgOut) -> Self {
+    match value {
+      TemperatureMsgOut::RequestLuaAction(a, b, c) => AppMsg::RequestLuaAction(a, b, c),
+      #[allow(unreachable_patterns)]
+      _ => AppMsg::NoOp,
+    }
+  }
+}
+
+impl From<TemperatureMsgOut> for BoxMsg {
+  fn from(value: TemperatureMsgOut) -> Self {
+    match value {
+      TemperatureMsgOut::RequestLuaAction(a, b, c) => BoxMsg::AppMsg(AppMsg::RequestLuaAction(a, b, c)),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TemperatureProps {
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+  format: ReactiveStringFn,
+  sensor: Option<String>,
+  warn: Option<f32>,
+  crit: Option<f32>,
+}
+
+#[tracker::track]
+pub struct Temperature {
+  #[tracker::do_not_track]
+  base: Base,
+  sensors: SensorsWrapper,
+  #[tracker::do_not_track]
+  sensor: Option<String>,
+  #[tracker::do_not_track]
+  warn: Option<f32>,
+  #[tracker::do_not_track]
+  crit: Option<f32>,
+  #[tracker::do_not_track]
+  source_id: Option<glib::SourceId>,
+  #[tracker::do_not_track]
+  format: Reactive<String>,
+  #[tracker::do_not_track]
+  sys: System,
+  react: bool,
+  #[tracker::do_not_track]
+  callback: Option<LuaFn>,
+}
+
+#[relm4::component(pub)]
+impl Component for Temperature {
+  type Input = TemperatureMsg;
+  type Output = TemperatureMsgOut;
+  type Init = TemperatureProps;
+  type Widgets = TemperatureWidgets;
+  type CommandOutput = ();
+
+  view! {
+    gtk::Label {
+      #[track = "model.changed(Temperature::react() | Temperature::sensors())"]
+      set_label: &format_temperature(&model.format.get(), &model.as_lua_args()),
+    }
+  }
+
+  fn init(props: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    let callback = props.format.as_fn();
+    let reactive = props
+      .format
+      .as_reactive(create_react_sender(sender.input_sender(), TemperatureMsg::React));
+
+    let source_id = {
+      let sender = sender.clone();
+
+      match callback {
+        Some(_) => glib::timeout_add_local(std::time::Duration::from_secs(1), move || {
+          sender.input(TemperatureMsg::Tick);
+          sender.input(TemperatureMsg::Callback);
+          glib::ControlFlow::Continue
+        }),
+        None => glib::timeout_add_local(std::time::Duration::from_secs(1), move || {
+          sender.input(TemperatureMsg::Tick);
+          glib::ControlFlow::Continue
+        }),
+      }
+    };
+
+    let sys = System::new();
+
+    let mut model = Temperature {
+      base: props.base.clone().into(),
+      sensors: SensorsWrapper::read(&sys),
+      sensor: props.sensor,
+      warn: props.warn,
+      crit: props.crit,
+      source_id: Some(source_id),
+      format: reactive,
+      callback,
+      react: false,
+      tracker: 0,
+      sys,
+    };
+
+    prepend_css_class_to_model!("temperature", model, root);
+    set_initial_base_props!(model, root, props.base);
+    // the threshold classes of the first reading, rather than only from the first tick on
+    model.update_classes(&root);
+
+    let widgets = view_output!();
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      TemperatureMsg::LuaHook(hook) => match hook {
+        TemperatureMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "temperature", root, base)
+        }
+        TemperatureMsgHook::GetFormat(tx) => {
+          tx.send(self.format.get()).unwrap();
+        }
+        TemperatureMsgHook::GetFormatReactive(tx) => {
+          tx.send(self.format.clone()).unwrap();
+        }
+        TemperatureMsgHook::SetFormat(format) => {
+          let arc = self.format.value.clone();
+          let mut str = arc.lock().unwrap();
+          *str = format;
+          self.set_react(!self.react);
+        }
+        TemperatureMsgHook::GetSensor(tx) => {
+          tx.send(self.sensor.clone()).unwrap();
+        }
+        TemperatureMsgHook::SetSensor(sensor) => {
+          self.sensor = sensor;
+          self.update_classes(root);
+          self.set_react(!self.react);
+        }
+        TemperatureMsgHook::GetWarn(tx) => {
+          tx.send(self.warn).unwrap();
+        }
+        TemperatureMsgHook::SetWarn(warn) => {
+          self.warn = warn;
+          self.update_classes(root);
+          self.set_react(!self.react);
+        }
+        TemperatureMsgHook::GetCrit(tx) => {
+          tx.send(self.crit).unwrap();
+        }
+        TemperatureMsgHook::SetCrit(crit) => {
+          self.crit = crit;
+          self.update_classes(root);
+          self.set_react(!self.react);
+        }
+      },
+      TemperatureMsg::React => {
+        self.set_react(!self.react);
+      }
+      TemperatureMsg::Tick => {
+        self.set_sensors(SensorsWrapper::read(&self.sys));
+        self.update_classes(root);
+      }
+      TemperatureMsg::Callback => {
+        if let Some(callback) = &self.callback {
+          let rx = request_lua_action(callback, serde_json::to_value(self.as_lua_args()).unwrap());
+          let input = sender.input_sender().clone();
+          // the receiver only resolves once the lua scheduler ran the function, or disconnects if it never will
+          relm4::spawn_blocking(move || match rx.recv() {
+            Ok(mlua::Value::String(s)) => {
+              let _ = input.send(TemperatureMsg::Formatted(s.to_string_lossy()));
+            }
+            Ok(v) => {
+              log::error!("Expected string for temperature callback, received: {:?}", v);
+            }
+            Err(_) => {}
+          });
+        }
+      }
+      TemperatureMsg::Formatted(format) => {
+        self.format.set(format);
+      }
+    }
+  }
+
+  fn shutdown(&mut self, _widgets: &mut Self::Widgets, _output: relm4::Sender<Self::Output>) {
+    if let Some(a) = self.source_id.take() {
+      glib::SourceId::remove(a)
+    }
+  }
+}
+
+impl Temperature {
+  fn as_lua_args(&self) -> TemperatureInfo {
+    let selected = self.sensors.select(self.sensor.as_deref());
+
+    TemperatureInfo {
+      temperature: selected.map_or(0.0, |s| s.temperature),
+      sensor: selected.map_or(String::new(), |s| s.name.clone()),
+      warn: self.warn,
+      crit: self.crit,
+      sensors: self.sensors.sensors.clone(),
+    }
+  }
+
+  fn update_classes(&mut self, root: &gtk::Label) {
+    self.base.classes_temp = generate_temperature_classes(
+      self.sensors.select(self.sensor.as_deref()).map(|s| s.temperature),
+      self.warn,
+      self.crit,
+    );
+    let joined = prepend_css_class!(self.base.classes.clone(), self.base.classes_temp.clone());
+    let classes_ref: Vec<&str> = joined.iter().map(AsRef::as_ref).collect();
+    root.set_css_classes(&classes_ref);
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct SensorInfo {
+  name: String,
+  label: String,
+  temperature: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct SensorsWrapper {
+  sensors: Vec<SensorInfo>,
+}
+
+impl SensorsWrapper {
+  fn read(sys: &System) -> Self {
+    let mut sensors = Vec::new();
+
+    match sys.cpu_temp() {
+      Ok(temperature) => sensors.push(SensorInfo {
+        name: CPU_SENSOR.to_string(),
+        label: CPU_SENSOR.to_string(),
+        temperature,
+      }),
+      Err(err) => {
+        log::debug!("Failed to fetch cpu temperature: {}", err);
+      }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+      sensors.extend(linux::read_hwmon());
+      sensors.extend(linux::read_thermal_zones());
+    }
+
+    SensorsWrapper { sensors }
+  }
+
+  /// Finds a sensor by its name or label, otherwise the first sensor available
+  fn select(&self, sensor: Option<&str>) -> Option<&SensorInfo> {
+    match sensor {
+      Some(sensor) => self
+        .sensors
+        .iter()
+        .find(|s| s.name == sensor)
+        .or_else(|| self.sensors.iter().find(|s| s.label == sensor)),
+      None => self.sensors.first(),
+    }
+  }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+  use super::SensorInfo;
+  use std::fs;
+  use std::path::Path;
+
+  fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+  }
+
+  fn read_millidegrees(path: &Path) -> Option<f32> {
+    read_trimmed(path)?.parse::<f32>().ok().map(|t| t / 1000.0)
+  }
+
+  /// Reads `/sys/class/hwmon/hwmon*/temp*_input`, named `hwmon{n}/temp{m}`
+  pub(super) fn read_hwmon() -> Vec<SensorInfo> {
+    let mut sensors = Vec::new();
+
+    let Ok(entries) = fs::read_dir("/sys/class/hwmon") else {
+      return sensors;
+    };
+
+    let mut hwmons: Vec<_> = entries.flatten().map(|e| e.path()).collect();
+    hwmons.sort();
+
+    for hwmon in hwmons {
+      let hwmon_name = hwmon.file_name().unwrap_or_default().to_string_lossy().to_string();
+      let device = read_trimmed(&hwmon.join("name")).unwrap_or_else(|| hwmon_name.clone());
+
+      let Ok(files) = fs::read_dir(&hwmon) else {
+        continue;
+      };
+
+      let mut inputs: Vec<String> = files
+        .flatten()
+        .map(|f| f.file_name().to_string_lossy().to_string())
+        .filter(|f| f.starts_with("temp") && f.ends_with("_input"))
+        .collect();
+      inputs.sort();
+
+      for input in inputs {
+        let Some(temperature) = read_millidegrees(&hwmon.join(&input)) else {
+          continue;
+        };
+        let prefix = input.trim_end_matches("_input");
+        let label = read_trimmed(&hwmon.join(format!("{}_label", prefix))).unwrap_or_else(|| device.clone());
+
+        sensors.push(SensorInfo {
+          name: format!("{}/{}", hwmon_name, prefix),
+          label,
+          temperature,
+        });
+      }
+    }
+
+    sensors
+  }
+
+  /// Reads `/sys/class/thermal/thermal_zone*/temp`, named `thermal_zone{n}`
+  pub(super) fn read_thermal_zones() -> Vec<SensorInfo> {
+    let mut sensors = Vec::new();
+
+    let Ok(entries) = fs::read_dir("/sys/class/thermal") else {
+      return sensors;
+    };
+
+    let mut zones: Vec<_> = entries
+      .flatten()
+      .map(|e| e.path())
+      .filter(|p| {
+        p.file_name()
+          .is_some_and(|f| f.to_string_lossy().starts_with("thermal_zone"))
+      })
+      .collect();
+    zones.sort();
+
+    for zone in zones {
+      let Some(temperature) = read_millidegrees(&zone.join("temp")) else {
+        continue;
+      };
+      let name = zone.file_name().unwrap_or_default().to_string_lossy().to_string();
+      let label = read_trimmed(&zone.join("type")).unwrap_or_else(|| name.clone());
+
+      sensors.push(SensorInfo {
+        name,
+        label,
+        temperature,
+      });
+    }
+
+    sensors
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct TemperatureInfo {
+  temperature: f32,
+  sensor: String,
+  warn: Option<f32>,
+  crit: Option<f32>,
+  sensors: Vec<SensorInfo>,
+}
+
+fn format_temperature(format: &str, info: &TemperatureInfo) -> String {
+  let reg = register_hitokage_helpers(Handlebars::new());
+
+  match reg.render_template(format, info) {
+    Ok(name) => return name,
+    Err(err) => {
+      log::error!("{:?}", err);
+    }
+  };
+
+  "".to_owned()
+}
+
+fn generate_temperature_classes(temperature: Option<f32>, warn: Option<f32>, crit: Option<f32>) -> Vec<String> {
+  let mut class_names = vec!["temperature".to_string()];
+
+  if let Some(temperature) = temperature {
+    if warn.is_some_and(|warn| temperature >= warn) {
+      class_names.push("warn".to_string());
+    }
+    if crit.is_some_and(|crit| temperature >= crit) {
+      class_names.push("crit".to_string());
+    }
+  }
+
+  class_names
+}
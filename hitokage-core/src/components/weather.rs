@@ -403,6 +403,9 @@ impl AsyncComponent for Weather {
       }
       WeatherMsg::RequestForecast => {
         self.set_forecast(request_forecast_from_station(&self.weather_station).await);
+        self
+          .base
+          .set_tooltip_args(weather_format_args(&self.forecast, &self.map), root);
       }
       WeatherMsg::Callback(tx) => {
         if let Some(callback) = &self.callback {
@@ -603,6 +606,17 @@ fn format_temperature(forecast: &WeatherForecast, map: &WeatherIcons, format: &s
     return map.unknown.clone();
   }
 
+  match reg.render_template(format, &weather_format_args(forecast, map)) {
+    Ok(name) => return name,
+    Err(err) => {
+      log::error!("{:?}", err);
+    }
+  };
+
+  String::new()
+}
+
+fn weather_format_args(forecast: &WeatherForecast, map: &WeatherIcons) -> HashMap<String, String> {
   let mut args = HashMap::new();
 
   args.insert("temp_celsius".to_string(), forecast.temperature.to_string());
@@ -612,12 +626,5 @@ fn format_temperature(forecast: &WeatherForecast, map: &WeatherIcons, format: &s
   );
   args.insert("icon".to_string(), forecast.weather_code_to_icon(map));
 
-  match reg.render_template(format, &args) {
-    Ok(name) => return name,
-    Err(err) => {
-      log::error!("{:?}", err);
-    }
-  };
-
-  String::new()
+  args
 }
@@ -0,0 +1,183 @@
+use super::base::{Base, BaseMsgHook, BaseProps};
+use crate::event::STATE;
+use crate::handlebar::register_hitokage_helpers;
+use crate::komorebi::{get_focused_window, get_focused_workspace};
+use crate::structs::EllipsizeMode;
+use crate::{generate_base_match_arms, prepend_css_class_to_model, set_initial_base_props};
+use gtk4::prelude::*;
+use handlebars::Handlebars;
+use relm4::prelude::*;
+use relm4::ComponentParts;
+use relm4::ComponentSender;
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::Sender;
+
+#[derive(Debug, Clone)]
+pub enum WindowTitleMsgHook {
+  BaseHook(BaseMsgHook),
+  GetFormat(Sender<String>),
+  SetFormat(String),
+  GetMaxLength(Sender<Option<u32>>),
+  SetMaxLength(Option<u32>),
+  GetEllipsize(Sender<EllipsizeMode>),
+  SetEllipsize(EllipsizeMode),
+}
+
+#[derive(Debug, Clone)]
+pub enum WindowTitleMsg {
+  Window(Option<WindowInfo>),
+  LuaHook(WindowTitleMsgHook),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WindowTitleProps {
+  /// Handlebars template with `title`, `exe` and `class`, defaults to `{{title}}`
+  format: Option<String>,
+  /// Truncates the label to this many characters
+  max_length: Option<u32>,
+  /// Where to place the ellipsis when truncating, defaults to `End`
+  ellipsize: Option<EllipsizeMode>,
+  #[serde(flatten)]
+  pub(crate) base: BaseProps,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct WindowInfo {
+  title: String,
+  exe: String,
+  class: String,
+}
+
+#[tracker::track]
+pub struct WindowTitle {
+  #[tracker::do_not_track]
+  base: Base,
+  window: Option<WindowInfo>,
+  format: String,
+  max_length: Option<u32>,
+  ellipsize: EllipsizeMode,
+}
+
+#[relm4::component(pub)]
+impl Component for WindowTitle {
+  type Input = WindowTitleMsg;
+  type Output = ();
+  type Init = (WindowTitleProps, u32); // win id
+  type CommandOutput = ();
+
+  view! {
+    gtk::Label {
+      #[track = "model.changed(WindowTitle::window() | WindowTitle::format())"]
+      set_label: &format_window_title(&model.format, &model.window),
+      #[track = "model.changed(WindowTitle::max_length())"]
+      set_max_width_chars: model.max_length.map(|v| v as i32).unwrap_or(-1),
+      #[track = "model.changed(WindowTitle::max_length() | WindowTitle::ellipsize())"]
+      set_ellipsize: match model.max_length {
+        Some(_) => model.ellipsize.into(),
+        None => gtk4::pango::EllipsizeMode::None,
+      },
+    }
+  }
+
+  fn init(propst: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+    let (props, id) = propst;
+
+    let mut model = WindowTitle {
+      base: props.base.clone().into(),
+      window: get_window(&STATE.read(), id),
+      format: props.format.unwrap_or("{{title}}".to_string()),
+      max_length: props.max_length,
+      ellipsize: props.ellipsize.unwrap_or_default(),
+      tracker: 0,
+    };
+
+    prepend_css_class_to_model!("window_title", model, root);
+    set_initial_base_props!(model, root, props.base);
+    model.base.set_tooltip_args(&model.window, &root);
+
+    STATE.subscribe(sender.input_sender(), move |state| {
+      WindowTitleMsg::Window(get_window(state, id))
+    });
+
+    let widgets = view_output!();
+
+    root.show();
+
+    ComponentParts { model, widgets }
+  }
+
+  fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>, root: &Self::Root) {
+    match msg {
+      WindowTitleMsg::Window(window) => {
+        if window != self.window {
+          self.base.set_tooltip_args(&window, root);
+          self.set_window(window);
+        }
+      }
+      WindowTitleMsg::LuaHook(hook) => match hook {
+        WindowTitleMsgHook::BaseHook(base) => {
+          generate_base_match_arms!(self, "window_title", root, base)
+        }
+        WindowTitleMsgHook::GetFormat(tx) => {
+          tx.send(self.format.clone()).unwrap();
+        }
+        WindowTitleMsgHook::SetFormat(format) => {
+          self.set_format(format);
+        }
+        WindowTitleMsgHook::GetMaxLength(tx) => {
+          tx.send(self.max_length).unwrap();
+        }
+        WindowTitleMsgHook::SetMaxLength(max_length) => {
+          self.set_max_length(max_length);
+        }
+        WindowTitleMsgHook::GetEllipsize(tx) => {
+          tx.send(self.ellipsize).unwrap();
+        }
+        WindowTitleMsgHook::SetEllipsize(ellipsize) => {
+          self.set_ellipsize(ellipsize);
+        }
+      },
+    }
+  }
+}
+
+// get the focused window on this monitor from komorebi
+fn get_window(state: &serde_json::Value, monitor_id: u32) -> Option<WindowInfo> {
+  // komorebi has not sent us a state yet
+  if state.is_null() {
+    return None;
+  }
+
+  let workspace = match get_focused_workspace(state, monitor_id) {
+    Ok(workspace) => workspace,
+    Err(err) => {
+      log::error!("Failed to read focused workspace: {:?}", err);
+      return None;
+    }
+  };
+
+  let window = get_focused_window(workspace)?;
+  let field = |key: &str| window.get(key).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+
+  Some(WindowInfo {
+    title: field("title"),
+    exe: field("exe"),
+    class: field("class"),
+  })
+}
+
+fn format_window_title(format: &str, window: &Option<WindowInfo>) -> String {
+  let Some(window) = window else {
+    return "".to_owned();
+  };
+
+  let reg = register_hitokage_helpers(Handlebars::new());
+
+  match reg.render_template(format, window) {
+    Ok(title) => title,
+    Err(err) => {
+      log::error!("{:?}", err);
+      window.title.clone()
+    }
+  }
+}
@@ -1,12 +1,11 @@
 use super::base::{Base, BaseMsgHook, BaseProps};
-use crate::event::STATE;
-use crate::handlebar::register_hitokage_helpers;
+use crate::structs::Monitor;
+use crate::workspace_provider::komorebi::KomorebiProvider;
+use crate::workspace_provider::{WorkspaceProvider, WorkspaceProviderKind};
 use crate::{generate_base_match_arms, prepend_css_class, prepend_css_class_to_model, set_initial_base_props};
-use anyhow::Context;
 use gtk4::prelude::*;
 use gtk4::Constraint;
 use gtk4::ConstraintLayout;
-use handlebars::Handlebars;
 use relm4::prelude::*;
 use relm4::ComponentParts;
 use relm4::ComponentSender;
@@ -14,9 +13,16 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::mpsc::Sender;
-use std::sync::Mutex;
-
-type WorkspaceState = (Option<String>, bool, bool);
+use std::sync::{Arc, Mutex};
+
+/// A workspace as it is shown on the bar
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkspaceState {
+  /// The formatted label, the workspace's position is shown instead when this is `None`
+  pub name: Option<String>,
+  pub focused: bool,
+  pub visible: bool,
+}
 
 #[derive(Debug, Clone)]
 pub enum WorkspaceMsgHook {
@@ -31,6 +37,7 @@ pub enum WorkspaceMsgHook {
 pub enum WorkspaceMsg {
   Workspaces(Vec<WorkspaceState>),
   FocusWorkspace(usize),
+  Scroll(f64),
   LuaHook(WorkspaceMsgHook),
 }
 
@@ -39,14 +46,16 @@ pub struct WorkspaceProps {
   item_width: Option<u32>,
   item_height: Option<u32>,
   format: Option<String>,
+  /// Defaults to whichever window manager hitokage is running under
+  provider: Option<WorkspaceProviderKind>,
   #[serde(flatten)]
-  base: BaseProps,
+  pub(crate) base: BaseProps,
 }
 
 pub struct Workspace {
   flowbox: gtk4::FlowBox,
-  id: u32, // win id
-  // workspaces: Vec<WorkspaceState>,
+  provider: Arc<dyn WorkspaceProvider>,
+  workspaces: Vec<WorkspaceState>,
   constraint_layout: ConstraintLayout,
   workspaces_to_check_constraints: Rc<Mutex<HashMap<i32, Vec<Constraint>>>>, // this maps a workspace id to the constraints that should be reevaluated every workspace change
 
@@ -56,11 +65,35 @@ pub struct Workspace {
   base: Base,
 }
 
+impl Workspace {
+  fn refresh(&self) {
+    update_workspaces(
+      &self.flowbox,
+      &self.workspaces,
+      &self.constraint_layout,
+      Rc::clone(&self.workspaces_to_check_constraints),
+      self.item_width,
+      self.item_height,
+    );
+  }
+
+  fn focus_workspace(&self, index: usize) {
+    log::info!("hitokage is focusing workspace {}", index);
+    // the provider may block on a socket, the new workspaces come back through the subscription
+    let provider = Arc::clone(&self.provider);
+    relm4::spawn_blocking(move || {
+      if let Err(err) = provider.focus_workspace(index) {
+        log::error!("Failed to focus workspace {}: {:?}", index, err);
+      }
+    });
+  }
+}
+
 #[relm4::component(pub)]
 impl Component for Workspace {
   type Input = WorkspaceMsg;
   type Output = ();
-  type Init = (WorkspaceProps, u32); // win id
+  type Init = (WorkspaceProps, Monitor);
   type CommandOutput = ();
 
   view! {
@@ -76,7 +109,7 @@ impl Component for Workspace {
   }
 
   fn init(propst: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
-    let (props, id) = propst;
+    let (props, monitor) = propst;
 
     let widgets = view_output!();
     let flowbox = widgets.flowbox.clone();
@@ -98,15 +131,38 @@ impl Component for Workspace {
       });
     }
 
+    {
+      let sender = sender.clone();
+      let scroll = gtk::EventControllerScroll::new(
+        gtk::EventControllerScrollFlags::VERTICAL | gtk::EventControllerScrollFlags::DISCRETE,
+      );
+      scroll.connect_scroll(move |_, _, dy| {
+        sender.input(WorkspaceMsg::Scroll(dy));
+        glib::Propagation::Stop
+      });
+      root.add_controller(scroll);
+    }
+
     let constraint_layout = ConstraintLayout::new();
     flowbox.set_layout_manager(Some(constraint_layout.clone()));
 
     let item_width = props.item_width.unwrap_or(16) as i32;
     let item_height = props.item_height.unwrap_or(16) as i32;
 
+    let kind = props.provider.unwrap_or_else(WorkspaceProviderKind::detect);
+    let provider = kind.create(&monitor).unwrap_or_else(|err| {
+      log::error!(
+        "Failed to create {:?} workspace provider, falling back to komorebi: {:?}",
+        kind,
+        err
+      );
+      Arc::new(KomorebiProvider::new(&monitor))
+    });
+
     let mut model = Workspace {
       flowbox: flowbox.clone(),
-      id,
+      provider,
+      workspaces: Vec::new(),
       constraint_layout,
       workspaces_to_check_constraints: Rc::new(Mutex::new(HashMap::new())),
       item_width,
@@ -118,15 +174,8 @@ impl Component for Workspace {
     prepend_css_class_to_model!("workspace", model, root);
     set_initial_base_props!(model, root, props.base);
 
-    STATE.subscribe(sender.input_sender(), move |state| {
-      // we only care about the most recent state
-
-      // TODO @codyduong change this to only care about change_workspace events.
-
-      let workspaces = get_workspaces(&state.clone(), id, &props.format);
-
-      WorkspaceMsg::Workspaces(workspaces.unwrap())
-    });
+    // TODO @codyduong change this to only care about change_workspace events.
+    model.provider.subscribe(model.format.clone(), sender.input_sender());
 
     ComponentParts { model, widgets }
   }
@@ -134,31 +183,32 @@ impl Component for Workspace {
   fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>, root: &Self::Root) {
     match msg {
       WorkspaceMsg::Workspaces(workspaces) => {
-        update_workspaces(
-          &self.flowbox,
-          &workspaces,
-          &self.constraint_layout,
-          Rc::clone(&self.workspaces_to_check_constraints),
-          self.item_width,
-          self.item_height,
-        );
+        self.workspaces = workspaces;
+        self.refresh();
       }
       WorkspaceMsg::FocusWorkspace(i) => {
-        let state = STATE.read();
-        if let Some((workspace_index, _)) = get_workspaces(&state, self.id, &self.format)
-          .unwrap()
-          .iter()
-          .enumerate()
-          .find(|(_, workspace)| workspace.1)
-        {
+        if let Some(workspace_index) = self.workspaces.iter().position(|workspace| workspace.focused) {
           if workspace_index != i {
-            log::info!("hitokage is focusing workspace {}", i);
-            let _ = komorebi_client::send_message(&komorebi_client::SocketMessage::FocusWorkspaceNumber(i));
+            self.focus_workspace(i);
           }
         } else {
           log::error!("We failed to find any focused workspace? What happened!")
         }
       }
+      WorkspaceMsg::Scroll(dy) => {
+        let workspaces = &self.workspaces;
+        if let Some(current) = workspaces.iter().position(|workspace| workspace.focused) {
+          // only move between workspaces that are shown, empty ones are hidden
+          let target = if dy < 0.0 {
+            (0..current).rev().find(|i| workspaces[*i].visible)
+          } else {
+            (current + 1..workspaces.len()).find(|i| workspaces[*i].visible)
+          };
+          if let Some(i) = target {
+            self.focus_workspace(i);
+          }
+        }
+      }
       WorkspaceMsg::LuaHook(hook) => match hook {
         WorkspaceMsgHook::BaseHook(base) => {
           generate_base_match_arms!(self, "workspace", root, base)
@@ -168,125 +218,20 @@ impl Component for Workspace {
         }
         WorkspaceMsgHook::SetItemHeight(item_height) => {
           self.item_width = item_height as i32;
-          let state = STATE.read();
-          let workspaces = get_workspaces(&state.clone(), self.id, &self.format).unwrap();
-          update_workspaces(
-            &self.flowbox,
-            &workspaces,
-            &self.constraint_layout,
-            Rc::clone(&self.workspaces_to_check_constraints),
-            self.item_width,
-            self.item_height,
-          );
+          self.refresh();
         }
         WorkspaceMsgHook::GetItemWidth(tx) => {
           tx.send(self.item_width as u32).unwrap();
         }
         WorkspaceMsgHook::SetItemWidth(item_width) => {
           self.item_width = item_width as i32;
-          let state = STATE.read();
-          let workspaces = get_workspaces(&state.clone(), self.id, &self.format).unwrap();
-          update_workspaces(
-            &self.flowbox,
-            &workspaces,
-            &self.constraint_layout,
-            Rc::clone(&self.workspaces_to_check_constraints),
-            self.item_width,
-            self.item_height,
-          );
+          self.refresh();
         }
       },
     }
   }
 }
 
-// get workspace from komorebi
-fn get_workspaces(
-  state: &serde_json::Value,
-  monitor_id: u32,
-  format: &Option<String>,
-) -> anyhow::Result<Vec<WorkspaceState>> {
-  let mut workspaces_vec: Vec<WorkspaceState> = Vec::new();
-
-  let monitors = state
-    .get("monitors")
-    .context("Missing 'monitors'")?
-    .as_object()
-    .context("Invalid 'monitors' format")?;
-
-  let elements = monitors
-    .get("elements")
-    .context("Missing 'elements' in 'monitors'")?
-    .as_array()
-    .context("Invalid 'elements' format in 'monitors'")?;
-
-  let monitor = elements
-    .iter()
-    .find(|monitor| {
-      monitor
-        .as_object()
-        .and_then(|v| v.get("id"))
-        .and_then(|v| v.as_u64())
-        .map(|id| id == monitor_id as u64)
-        .unwrap_or(false)
-    })
-    .context("Monitor with specified ID not found")?;
-
-  let workspaces = monitor.get("workspaces").context("Missing 'workspaces' in monitor")?;
-
-  let elements = workspaces
-    .get("elements")
-    .context("Missing 'elements' in 'workspaces'")?
-    .as_array()
-    .context("Invalid 'elements' format in 'workspaces'")?;
-
-  for (index, workspace) in elements.iter().enumerate() {
-    let name = {
-      let name = workspace.get("name").and_then(|v| v.as_str()).map(String::from);
-
-      if let Some(ref format) = format {
-        let reg = register_hitokage_helpers(Handlebars::new());
-
-        let mut args = HashMap::new();
-        args.insert("name", name.clone().unwrap_or_default());
-        args.insert("index", index.to_string());
-
-        match reg.render_template(format, &args) {
-          Ok(name) => Some(name),
-          Err(err) => {
-            log::error!("{:?}", err);
-            name
-          }
-        }
-      } else {
-        name
-      }
-    };
-
-    // check if we actually have anything in the workspace, if not don't show it
-    let has_content = workspace
-      .get("containers")
-      .and_then(|v| v.as_object())
-      .and_then(|v| v.get("elements"))
-      .and_then(|v| v.as_array())
-      .map_or(false, |elements| !elements.is_empty())
-      || workspace
-        .get("floating_windows")
-        .and_then(|v| v.as_array())
-        .map_or(false, |floating| !floating.is_empty());
-
-    workspaces_vec.push((name, false, has_content));
-  }
-
-  if let Some(focused) = workspaces.get("focused").and_then(|v| v.as_u64()).map(|v| v as usize) {
-    if let Some(workspace_possible) = workspaces_vec.get(focused) {
-      workspaces_vec[focused] = (workspace_possible.0.clone(), true, true);
-    }
-  }
-
-  Ok(workspaces_vec)
-}
-
 fn update_workspaces(
   flowbox: &gtk4::FlowBox,
   workspaces: &[WorkspaceState],
@@ -300,39 +245,32 @@ fn update_workspaces(
     match flowbox.child_at_index(i as i32) {
       Some(child) => {
         match workspaces.get(i) {
-          Some(workspace) => match workspace {
-            (name, true, _) => {
-              child
-                .first_child()
-                .unwrap()
-                .downcast::<gtk4::Label>()
-                .unwrap()
-                .set_label(&name.clone().unwrap_or((i + 1).to_string()));
+          Some(workspace) => {
+            child
+              .first_child()
+              .unwrap()
+              .downcast::<gtk4::Label>()
+              .unwrap()
+              .set_label(&workspace.name.clone().unwrap_or((i + 1).to_string()));
+            if workspace.focused {
               child.set_visible(true);
               flowbox.select_child(&child);
-            }
-            (name, false, is_visible) => {
-              child
-                .first_child()
-                .unwrap()
-                .downcast::<gtk4::Label>()
-                .unwrap()
-                .set_label(&name.clone().unwrap_or((i + 1).to_string()));
-              child.set_visible(*is_visible);
+            } else {
+              child.set_visible(workspace.visible);
               flowbox.unselect_child(&child);
             }
-          },
+          }
           None => flowbox.remove(&child),
         };
       }
       None => match workspaces.get(i) {
-        Some((name, is_focused, _)) => {
-          let label = gtk::Label::new(Some(&name.clone().unwrap_or((i + 1).to_string())));
+        Some(workspace) => {
+          let label = gtk::Label::new(Some(&workspace.name.clone().unwrap_or((i + 1).to_string())));
           label.set_hexpand(false);
           label.set_vexpand(false);
           flowbox.append(&label);
           let child = &flowbox.child_at_index(i as i32).unwrap();
-          if *is_focused {
+          if workspace.focused {
             flowbox.select_child(child);
           }
           child.set_size_request(width, height);
@@ -363,7 +301,7 @@ fn update_workspaces(
 
       let first_visible_or_focused = workspaces
         .iter()
-        .position(|(_, is_focused, is_visible)| *is_visible || *is_focused);
+        .position(|workspace| workspace.visible || workspace.focused);
 
       if let Some(first) = first_visible_or_focused {
         if i == first {
@@ -403,7 +341,7 @@ fn update_workspaces(
 
       let last_visible_or_focused = workspaces
         .iter()
-        .rposition(|(_, is_focused, is_visible)| *is_visible || *is_focused);
+        .rposition(|workspace| workspace.visible || workspace.focused);
 
       if let Some(last) = last_visible_or_focused {
         if i == last {
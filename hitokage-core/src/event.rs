@@ -2,6 +2,8 @@ use crate::structs::lua_action::LuaActionRequest;
 use relm4::SharedState;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::task::{Context, Poll, Waker};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct EventNotif {
@@ -13,5 +15,46 @@ pub struct EventNotif {
 pub static STATE: SharedState<serde_json::Value> = SharedState::new(); // this only stores the newest state
 pub static EVENT: SharedState<VecDeque<EventNotif>> = SharedState::new();
 pub static NEW_EVENT: SharedState<bool> = SharedState::new(); // if the state has changed since we last read the state
-pub static CONFIG_UPDATE: SharedState<bool> = SharedState::new(); // if we updated init.lua
 pub static LUA_ACTION_REQUESTS: SharedState<VecDeque<LuaActionRequest>> = SharedState::new();
+pub const MAX_LUA_ACTION_REQUESTS: usize = 50; // requests past this are dropped until lua catches up
+pub static LUA_WAKER: LuaWaker = LuaWaker::new(); // wakes the lua scheduler when something lua may be waiting on changes
+
+/// Wakes the lua scheduler, wakes sent while it is busy are not lost but coalesced into one
+#[derive(Debug)]
+pub struct LuaWaker {
+  inner: Mutex<(bool, Option<Waker>)>,
+}
+
+impl LuaWaker {
+  pub const fn new() -> Self {
+    LuaWaker {
+      inner: Mutex::new((false, None)),
+    }
+  }
+
+  pub fn wake(&self) {
+    let mut inner = self.inner.lock().unwrap();
+    inner.0 = true;
+    if let Some(waker) = inner.1.take() {
+      waker.wake();
+    }
+  }
+
+  /// Consumes a pending wake, otherwise registers the context to be woken by the next one
+  pub fn poll_woken(&self, cx: &mut Context<'_>) -> Poll<()> {
+    let mut inner = self.inner.lock().unwrap();
+    if inner.0 {
+      inner.0 = false;
+      Poll::Ready(())
+    } else {
+      inner.1 = Some(cx.waker().clone());
+      Poll::Pending
+    }
+  }
+}
+
+impl Default for LuaWaker {
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -0,0 +1,160 @@
+use anyhow::Context;
+
+/// Finds the monitor with the given id in a komorebi state
+pub fn get_monitor(state: &serde_json::Value, monitor_id: u32) -> anyhow::Result<&serde_json::Value> {
+  state
+    .get("monitors")
+    .context("Missing 'monitors'")?
+    .get("elements")
+    .context("Missing 'elements' in 'monitors'")?
+    .as_array()
+    .context("Invalid 'elements' format in 'monitors'")?
+    .iter()
+    .find(|monitor| {
+      monitor
+        .get("id")
+        .and_then(|v| v.as_u64())
+        .map(|id| id == monitor_id as u64)
+        .unwrap_or(false)
+    })
+    .context("Monitor with specified ID not found")
+}
+
+/// Finds the focused workspace of the monitor with the given id in a komorebi state
+pub fn get_focused_workspace(state: &serde_json::Value, monitor_id: u32) -> anyhow::Result<&serde_json::Value> {
+  let workspaces = get_monitor(state, monitor_id)?
+    .get("workspaces")
+    .context("Missing 'workspaces' in monitor")?;
+
+  let focused = workspaces
+    .get("focused")
+    .and_then(|v| v.as_u64())
+    .context("Missing 'focused' in 'workspaces'")? as usize;
+
+  workspaces
+    .get("elements")
+    .context("Missing 'elements' in 'workspaces'")?
+    .as_array()
+    .context("Invalid 'elements' format in 'workspaces'")?
+    .get(focused)
+    .context("Focused workspace not found")
+}
+
+/// Returns the focused element of a komorebi ring, ie. `{ "elements": [...], "focused": 0 }`
+pub fn get_focused_element(ring: &serde_json::Value) -> Option<&serde_json::Value> {
+  let focused = ring.get("focused")?.as_u64()? as usize;
+  ring.get("elements")?.as_array()?.get(focused)
+}
+
+/// Finds the focused window of a workspace, preferring the monocle container and then the maximized window
+pub fn get_focused_window(workspace: &serde_json::Value) -> Option<&serde_json::Value> {
+  if let Some(monocle) = workspace.get("monocle_container").filter(|v| !v.is_null()) {
+    return monocle.get("windows").and_then(get_focused_element);
+  }
+  if let Some(maximized) = workspace.get("maximized_window").filter(|v| !v.is_null()) {
+    return Some(maximized);
+  }
+  workspace
+    .get("containers")
+    .and_then(get_focused_element)
+    .and_then(|container| container.get("windows"))
+    .and_then(get_focused_element)
+}
+
+/// Finds the index of the monitor with the given id, this is what komorebi commands expect
+pub fn get_monitor_index(state: &serde_json::Value, monitor_id: u32) -> anyhow::Result<usize> {
+  state
+    .get("monitors")
+    .and_then(|v| v.get("elements"))
+    .and_then(|v| v.as_array())
+    .context("Missing 'elements' in 'monitors'")?
+    .iter()
+    .position(|monitor| monitor.get("id").and_then(|v| v.as_u64()) == Some(monitor_id as u64))
+    .context("Monitor with specified ID not found")
+}
+
+/// Finds the index of the monitor with the given id and the index of its focused workspace
+pub fn get_focused_workspace_index(state: &serde_json::Value, monitor_id: u32) -> anyhow::Result<(usize, usize)> {
+  let monitor_index = get_monitor_index(state, monitor_id)?;
+
+  let workspace_index = get_monitor(state, monitor_id)?
+    .get("workspaces")
+    .and_then(|v| v.get("focused"))
+    .and_then(|v| v.as_u64())
+    .context("Missing 'focused' in 'workspaces'")? as usize;
+
+  Ok((monitor_index, workspace_index))
+}
+
+/// Returns the layout of a workspace, ie. `BSP`, or `Custom` for custom layouts
+pub fn get_layout_name(workspace: &serde_json::Value) -> String {
+  workspace
+    .get("layout")
+    .and_then(|layout| layout.get("Default"))
+    .and_then(|layout| layout.as_str())
+    .unwrap_or("Custom")
+    .to_string()
+}
+
+/// Returns the containers of a workspace, monocle and maximized windows are taken out of the containers by komorebi
+pub fn get_containers(workspace: &serde_json::Value) -> impl Iterator<Item = &serde_json::Value> {
+  workspace
+    .get("containers")
+    .and_then(|v| v.get("elements"))
+    .and_then(|v| v.as_array())
+    .into_iter()
+    .flatten()
+    .chain(workspace.get("monocle_container").filter(|v| !v.is_null()))
+}
+
+/// Finds the index of the tiled container holding the window with `hwnd`, along with the index of the focused container
+/// and the number of containers, ie. `(index, focused, len)`
+pub fn get_container_position(workspace: &serde_json::Value, hwnd: isize) -> Option<(usize, usize, usize)> {
+  let containers = workspace.get("containers")?;
+  let elements = containers.get("elements")?.as_array()?;
+  let focused = containers.get("focused")?.as_u64()? as usize;
+
+  let index = elements.iter().position(|container| {
+    container
+      .get("windows")
+      .and_then(|v| v.get("elements"))
+      .and_then(|v| v.as_array())
+      .is_some_and(|windows| {
+        windows
+          .iter()
+          .any(|window| window.get("hwnd").and_then(|v| v.as_i64()) == Some(hwnd as i64))
+      })
+  })?;
+
+  Some((index, focused, elements.len()))
+}
+
+/// Returns the focused window of every container of a workspace, followed by the maximized and floating windows
+pub fn get_workspace_windows(workspace: &serde_json::Value) -> impl Iterator<Item = &serde_json::Value> {
+  get_containers(workspace)
+    .filter_map(|container| container.get("windows").and_then(get_focused_element))
+    .chain(workspace.get("maximized_window").filter(|v| !v.is_null()))
+    .chain(
+      workspace
+        .get("floating_windows")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten(),
+    )
+}
+
+/// Counts every window of a workspace, including the ones hidden in stacked containers
+pub fn get_window_count(workspace: &serde_json::Value) -> usize {
+  let stacked: usize = get_containers(workspace)
+    .filter_map(|container| container.get("windows").and_then(|v| v.get("elements")))
+    .filter_map(|v| v.as_array())
+    .map(Vec::len)
+    .sum();
+  let maximized = workspace.get("maximized_window").map_or(0, |v| !v.is_null() as usize);
+  let floating = workspace
+    .get("floating_windows")
+    .and_then(|v| v.as_array())
+    .map_or(0, Vec::len);
+
+  stacked + maximized + floating
+}
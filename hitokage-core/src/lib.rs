@@ -4,8 +4,10 @@ pub mod deserializer;
 pub mod event;
 pub mod flowbox;
 pub mod handlebar;
+pub mod komorebi;
 pub mod structs;
 pub mod win_utils;
+pub mod workspace_provider;
 
 pub trait RelmContainerExtManual: 'static {
   fn container_add<T: glib::object::IsA<gtk4::Widget>>(&self, widget: &T);
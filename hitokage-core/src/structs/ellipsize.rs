@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub enum EllipsizeMode {
+  None,
+  Start,
+  Middle,
+  #[default]
+  End,
+}
+
+impl From<EllipsizeMode> for gtk4::pango::EllipsizeMode {
+  fn from(item: EllipsizeMode) -> Self {
+    match item {
+      EllipsizeMode::None => gtk4::pango::EllipsizeMode::None,
+      EllipsizeMode::Start => gtk4::pango::EllipsizeMode::Start,
+      EllipsizeMode::Middle => gtk4::pango::EllipsizeMode::Middle,
+      EllipsizeMode::End => gtk4::pango::EllipsizeMode::End,
+    }
+  }
+}
+
+impl From<gtk4::pango::EllipsizeMode> for EllipsizeMode {
+  fn from(item: gtk4::pango::EllipsizeMode) -> Self {
+    match item {
+      gtk4::pango::EllipsizeMode::Start => EllipsizeMode::Start,
+      gtk4::pango::EllipsizeMode::Middle => EllipsizeMode::Middle,
+      gtk4::pango::EllipsizeMode::End => EllipsizeMode::End,
+      _ => EllipsizeMode::None,
+    }
+  }
+}
@@ -12,15 +12,16 @@ impl UserData for LuaActionRequest {
   fn add_methods<M: mlua::UserDataMethods<Self>>(methods: &mut M) {
     methods.add_method_mut("call", |lua, this, _: ()| {
       log::error!("my fridge is on fire");
+      // taken before calling, so the receiver disconnects right away if the function errors
+      let f = this
+        .f
+        .take()
+        .expect("We attempted to send on an dropped component or an already evaluated lua action request");
       let func: mlua::Function = lua.registry_value(&this.id)?;
       let args = lua.to_value(&this.args.clone()).unwrap();
       let res = func.call::<mlua::Value>(args)?;
-      this
-        .f
-        .take()
-        .expect("We attempted to send on an dropped component or an already evaluated lua action request")
-        .send(res)
-        .unwrap();
+      // the receiver may already be gone, ie. fire and forget event handlers, so a failed send is not an error
+      let _ = f.send(res);
       Ok(())
     });
   }
@@ -0,0 +1,524 @@
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlaybackStatus {
+  Playing,
+  Paused,
+  #[default]
+  Stopped,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MediaState {
+  pub player: String,
+  pub title: String,
+  pub artists: Vec<String>,
+  pub album: String,
+  pub status: PlaybackStatus,
+  pub position: Duration,
+  pub length: Option<Duration>,
+}
+
+/// A source of media information and playback controls.
+///
+/// `state` returns `Ok(None)` when no player is available, which is not considered an error.
+pub trait MediaPlayer {
+  fn state(&mut self) -> anyhow::Result<Option<MediaState>>;
+  fn play_pause(&mut self) -> anyhow::Result<()>;
+  fn next(&mut self) -> anyhow::Result<()>;
+  fn previous(&mut self) -> anyhow::Result<()>;
+}
+
+/// A player which holds its state in memory. Used on platforms without a media backend.
+#[derive(Debug, Default)]
+pub struct StubPlayer {
+  pub state: Option<MediaState>,
+}
+
+impl MediaPlayer for StubPlayer {
+  fn state(&mut self) -> anyhow::Result<Option<MediaState>> {
+    Ok(self.state.clone())
+  }
+
+  fn play_pause(&mut self) -> anyhow::Result<()> {
+    if let Some(state) = self.state.as_mut() {
+      state.status = match state.status {
+        PlaybackStatus::Playing => PlaybackStatus::Paused,
+        PlaybackStatus::Paused | PlaybackStatus::Stopped => PlaybackStatus::Playing,
+      };
+    }
+    Ok(())
+  }
+
+  fn next(&mut self) -> anyhow::Result<()> {
+    Ok(())
+  }
+
+  fn previous(&mut self) -> anyhow::Result<()> {
+    Ok(())
+  }
+}
+
+#[cfg(target_os = "linux")]
+pub use mpris_player::MprisPlayer;
+
+#[cfg(target_os = "linux")]
+mod mpris_player {
+  use super::{MediaPlayer, MediaState, PlaybackStatus};
+  use mpris::{FindingError, Metadata, Player, PlayerFinder};
+  use std::time::Duration;
+
+  /// A player backed by the MPRIS D-Bus interface
+  pub struct MprisPlayer {
+    finder: PlayerFinder,
+    name: Option<String>,
+    player: Option<Player>,
+  }
+
+  impl MprisPlayer {
+    /// Connects to the session bus. If `name` is specified only the player with that identity is used, otherwise
+    /// the most active player is used.
+    pub fn new(name: Option<String>) -> anyhow::Result<Self> {
+      Ok(Self::with_finder(PlayerFinder::new()?, name))
+    }
+
+    /// Uses an existing finder, ie. one created with `PlayerFinder::for_connection`
+    pub fn with_finder(finder: PlayerFinder, name: Option<String>) -> Self {
+      MprisPlayer {
+        finder,
+        name,
+        player: None,
+      }
+    }
+
+    fn player(&mut self) -> anyhow::Result<Option<&Player>> {
+      if self.player.as_ref().is_some_and(|player| player.is_running()) {
+        return Ok(self.player.as_ref());
+      }
+
+      let found = match &self.name {
+        Some(name) => self.finder.find_by_name(name),
+        None => self.finder.find_active(),
+      };
+
+      self.player = match found {
+        Ok(player) => Some(player),
+        Err(FindingError::NoPlayerFound) => None,
+        Err(err) => return Err(err.into()),
+      };
+
+      Ok(self.player.as_ref())
+    }
+  }
+
+  /// Maps the `Metadata` and `PlaybackStatus` properties of a player, missing metadata is left empty
+  fn parse_state(identity: &str, metadata: &Metadata, status: mpris::PlaybackStatus, position: Duration) -> MediaState {
+    MediaState {
+      player: identity.to_string(),
+      title: metadata.title().unwrap_or_default().to_string(),
+      artists: metadata
+        .artists()
+        .unwrap_or_default()
+        .into_iter()
+        .map(String::from)
+        .collect(),
+      album: metadata.album_name().unwrap_or_default().to_string(),
+      status: match status {
+        mpris::PlaybackStatus::Playing => PlaybackStatus::Playing,
+        mpris::PlaybackStatus::Paused => PlaybackStatus::Paused,
+        mpris::PlaybackStatus::Stopped => PlaybackStatus::Stopped,
+      },
+      position,
+      length: metadata.length(),
+    }
+  }
+
+  impl MediaPlayer for MprisPlayer {
+    fn state(&mut self) -> anyhow::Result<Option<MediaState>> {
+      let Some(player) = self.player()? else {
+        return Ok(None);
+      };
+
+      Ok(Some(parse_state(
+        player.identity(),
+        &player.get_metadata()?,
+        player.get_playback_status()?,
+        // not every player reports a position
+        player.get_position().unwrap_or(Duration::ZERO),
+      )))
+    }
+
+    fn play_pause(&mut self) -> anyhow::Result<()> {
+      if let Some(player) = self.player()? {
+        player.play_pause()?;
+      }
+      Ok(())
+    }
+
+    fn next(&mut self) -> anyhow::Result<()> {
+      if let Some(player) = self.player()? {
+        player.next()?;
+      }
+      Ok(())
+    }
+
+    fn previous(&mut self) -> anyhow::Result<()> {
+      if let Some(player) = self.player()? {
+        player.previous()?;
+      }
+      Ok(())
+    }
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use super::*;
+    use mpris::MetadataValue;
+    use std::collections::HashMap;
+
+    fn metadata(properties: Vec<(&str, MetadataValue)>) -> Metadata {
+      properties
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect::<HashMap<_, _>>()
+        .into()
+    }
+
+    fn status(status: &str) -> mpris::PlaybackStatus {
+      status.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_full_metadata() {
+      let metadata = metadata(vec![
+        ("mpris:trackid", MetadataValue::from("/org/mpris/MediaPlayer2/Track/1")),
+        ("xesam:title", MetadataValue::from("Title")),
+        (
+          "xesam:artist",
+          MetadataValue::Array(vec![MetadataValue::from("Artist"), MetadataValue::from("Featured")]),
+        ),
+        ("xesam:album", MetadataValue::from("Album")),
+        ("mpris:length", MetadataValue::I64(215_000_000)),
+      ]);
+
+      let state = parse_state("Spotify", &metadata, status("Playing"), Duration::from_secs(42));
+      assert_eq!(
+        state,
+        MediaState {
+          player: "Spotify".to_string(),
+          title: "Title".to_string(),
+          artists: vec!["Artist".to_string(), "Featured".to_string()],
+          album: "Album".to_string(),
+          status: PlaybackStatus::Playing,
+          position: Duration::from_secs(42),
+          length: Some(Duration::from_secs(215)),
+        }
+      );
+    }
+
+    #[test]
+    fn parses_unsigned_length() {
+      let metadata = metadata(vec![("mpris:length", MetadataValue::U64(1_500_000))]);
+      let state = parse_state("mpv", &metadata, status("Paused"), Duration::ZERO);
+      assert_eq!(state.length, Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn leaves_missing_metadata_empty() {
+      let state = parse_state("firefox", &metadata(Vec::new()), status("Stopped"), Duration::ZERO);
+      assert_eq!(
+        state,
+        MediaState {
+          player: "firefox".to_string(),
+          status: PlaybackStatus::Stopped,
+          ..Default::default()
+        }
+      );
+    }
+
+    #[test]
+    fn accepts_a_single_artist() {
+      let metadata = metadata(vec![("xesam:artist", MetadataValue::from("Artist"))]);
+      let state = parse_state("vlc", &metadata, status("Playing"), Duration::ZERO);
+      assert_eq!(state.artists, vec!["Artist".to_string()]);
+    }
+
+    #[test]
+    fn ignores_mistyped_metadata() {
+      let metadata = metadata(vec![
+        ("xesam:title", MetadataValue::I32(1)),
+        ("xesam:artist", MetadataValue::Bool(true)),
+        ("mpris:length", MetadataValue::F64(1.5)),
+      ]);
+      let state = parse_state("vlc", &metadata, status("Paused"), Duration::ZERO);
+      assert_eq!(state.title, "");
+      assert!(state.artists.is_empty());
+      assert_eq!(state.length, None);
+    }
+
+    #[test]
+    fn parses_playback_status() {
+      let parse = |value: &str| parse_state("", &metadata(Vec::new()), status(value), Duration::ZERO).status;
+      assert_eq!(parse("Playing"), PlaybackStatus::Playing);
+      assert_eq!(parse("Paused"), PlaybackStatus::Paused);
+      assert_eq!(parse("Stopped"), PlaybackStatus::Stopped);
+      assert!("Buffering".parse::<mpris::PlaybackStatus>().is_err());
+    }
+  }
+}
+
+#[cfg(windows)]
+pub use gsmtc_player::GsmtcPlayer;
+
+#[cfg(windows)]
+mod gsmtc_player {
+  use super::{MediaPlayer, MediaState, PlaybackStatus};
+  use std::time::Duration;
+  use windows::Foundation::TimeSpan;
+  use windows::Media::Control::{
+    GlobalSystemMediaTransportControlsSession, GlobalSystemMediaTransportControlsSessionManager,
+    GlobalSystemMediaTransportControlsSessionPlaybackStatus,
+  };
+
+  /// A player backed by the Windows global system media transport controls, ie. the media flyout
+  pub struct GsmtcPlayer {
+    manager: GlobalSystemMediaTransportControlsSessionManager,
+    name: Option<String>,
+  }
+
+  impl GsmtcPlayer {
+    /// If `name` is specified only a session whose app id contains it is used, ie. `"Spotify"`, otherwise the
+    /// session Windows considers current is used.
+    pub fn new(name: Option<String>) -> anyhow::Result<Self> {
+      Ok(GsmtcPlayer {
+        manager: GlobalSystemMediaTransportControlsSessionManager::RequestAsync()?.get()?,
+        name,
+      })
+    }
+
+    fn session(&self) -> anyhow::Result<Option<GlobalSystemMediaTransportControlsSession>> {
+      let Some(name) = &self.name else {
+        // fails with a null session when nothing is playing
+        return Ok(self.manager.GetCurrentSession().ok());
+      };
+
+      let name = name.to_lowercase();
+      let sessions = self.manager.GetSessions()?;
+      for i in 0..sessions.Size()? {
+        let session = sessions.GetAt(i)?;
+        if session
+          .SourceAppUserModelId()?
+          .to_string()
+          .to_lowercase()
+          .contains(&name)
+        {
+          return Ok(Some(session));
+        }
+      }
+      Ok(None)
+    }
+  }
+
+  fn to_duration(time: TimeSpan) -> Duration {
+    // a TimeSpan counts 100ns ticks
+    Duration::from_nanos(time.Duration.max(0) as u64 * 100)
+  }
+
+  impl MediaPlayer for GsmtcPlayer {
+    fn state(&mut self) -> anyhow::Result<Option<MediaState>> {
+      let Some(session) = self.session()? else {
+        return Ok(None);
+      };
+
+      let properties = session.TryGetMediaPropertiesAsync()?.get()?;
+      let timeline = session.GetTimelineProperties()?;
+      let status = match session.GetPlaybackInfo()?.PlaybackStatus()? {
+        GlobalSystemMediaTransportControlsSessionPlaybackStatus::Playing => PlaybackStatus::Playing,
+        GlobalSystemMediaTransportControlsSessionPlaybackStatus::Paused => PlaybackStatus::Paused,
+        _ => PlaybackStatus::Stopped,
+      };
+      let artist = properties.Artist()?.to_string();
+      let length = to_duration(timeline.EndTime()?).saturating_sub(to_duration(timeline.StartTime()?));
+
+      Ok(Some(MediaState {
+        player: session.SourceAppUserModelId()?.to_string(),
+        title: properties.Title()?.to_string(),
+        artists: if artist.is_empty() { Vec::new() } else { vec![artist] },
+        album: properties.AlbumTitle()?.to_string(),
+        status,
+        position: to_duration(timeline.Position()?),
+        length: (!length.is_zero()).then_some(length),
+      }))
+    }
+
+    fn play_pause(&mut self) -> anyhow::Result<()> {
+      if let Some(session) = self.session()? {
+        session.TryTogglePlayPauseAsync()?.get()?;
+      }
+      Ok(())
+    }
+
+    fn next(&mut self) -> anyhow::Result<()> {
+      if let Some(session) = self.session()? {
+        session.TrySkipNextAsync()?.get()?;
+      }
+      Ok(())
+    }
+
+    fn previous(&mut self) -> anyhow::Result<()> {
+      if let Some(session) = self.session()? {
+        session.TrySkipPreviousAsync()?.get()?;
+      }
+      Ok(())
+    }
+  }
+}
+
+/// Creates the media player for the current platform, falling back to a [`StubPlayer`]
+pub fn default_player(name: Option<String>) -> Box<dyn MediaPlayer> {
+  #[cfg(target_os = "linux")]
+  {
+    match MprisPlayer::new(name) {
+      Ok(player) => return Box::new(player),
+      Err(err) => {
+        log::error!("Failed to connect to the session bus: {}", err);
+      }
+    }
+  }
+
+  #[cfg(windows)]
+  {
+    match GsmtcPlayer::new(name) {
+      Ok(player) => return Box::new(player),
+      Err(err) => {
+        log::error!("Failed to connect to the media transport controls: {}", err);
+      }
+    }
+  }
+
+  #[cfg(not(any(target_os = "linux", windows)))]
+  {
+    let _ = name;
+    log::warn!("Media is not supported on this platform");
+  }
+
+  Box::new(StubPlayer::default())
+}
+
+pub fn read_state(player: &mut dyn MediaPlayer) -> Option<MediaState> {
+  match player.state() {
+    Ok(state) => state,
+    Err(err) => {
+      log::error!("Failed to fetch media information: {}", err);
+      None
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaCommand {
+  PlayPause,
+  Next,
+  Previous,
+}
+
+/// Polls a [`MediaPlayer`] on its own thread, so slow backends never block the bar.
+///
+/// The player is created on that thread as backends are not `Send`. The thread stops once the worker is dropped or
+/// `on_state` returns `false`.
+#[derive(Debug)]
+pub struct MediaWorker {
+  commands: mpsc::Sender<MediaCommand>,
+}
+
+impl MediaWorker {
+  pub fn spawn<P, F>(interval: Duration, make_player: P, on_state: F) -> Self
+  where
+    P: FnOnce() -> Box<dyn MediaPlayer> + Send + 'static,
+    F: Fn(Option<MediaState>) -> bool + Send + 'static,
+  {
+    let (commands, rx) = mpsc::channel::<MediaCommand>();
+
+    std::thread::spawn(move || {
+      let mut player = make_player();
+      loop {
+        if !on_state(read_state(player.as_mut())) {
+          break;
+        }
+
+        let result = match rx.recv_timeout(interval) {
+          Ok(MediaCommand::PlayPause) => player.play_pause(),
+          Ok(MediaCommand::Next) => player.next(),
+          Ok(MediaCommand::Previous) => player.previous(),
+          Err(RecvTimeoutError::Timeout) => Ok(()),
+          Err(RecvTimeoutError::Disconnected) => break,
+        };
+        if let Err(err) = result {
+          log::error!("Failed to control media playback: {}", err);
+        }
+      }
+    });
+
+    MediaWorker { commands }
+  }
+
+  /// Runs a command on the player, the new state is reported right after
+  pub fn send(&self, command: MediaCommand) {
+    let _ = self.commands.send(command);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn stub_state(status: PlaybackStatus) -> MediaState {
+    MediaState {
+      player: "stub".to_string(),
+      title: "Title".to_string(),
+      artists: vec!["Artist".to_string()],
+      status,
+      ..Default::default()
+    }
+  }
+
+  fn spawn_stub(state: Option<MediaState>) -> (MediaWorker, mpsc::Receiver<Option<MediaState>>) {
+    let (tx, rx) = mpsc::channel();
+    let worker = MediaWorker::spawn(
+      // long enough that only commands trigger a new state
+      Duration::from_secs(60),
+      move || Box::new(StubPlayer { state }),
+      move |state| tx.send(state).is_ok(),
+    );
+    (worker, rx)
+  }
+
+  #[test]
+  fn reports_state_on_spawn() {
+    let (_worker, rx) = spawn_stub(Some(stub_state(PlaybackStatus::Paused)));
+    let state = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(state, Some(stub_state(PlaybackStatus::Paused)));
+  }
+
+  #[test]
+  fn reports_state_after_command() {
+    let (worker, rx) = spawn_stub(Some(stub_state(PlaybackStatus::Paused)));
+    rx.recv_timeout(Duration::from_secs(5)).unwrap();
+
+    worker.send(MediaCommand::PlayPause);
+    let state = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(state.map(|state| state.status), Some(PlaybackStatus::Playing));
+
+    worker.send(MediaCommand::PlayPause);
+    let state = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(state.map(|state| state.status), Some(PlaybackStatus::Paused));
+  }
+
+  #[test]
+  fn reports_no_player() {
+    let (_worker, rx) = spawn_stub(None);
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)).unwrap(), None);
+  }
+}
@@ -1,11 +1,16 @@
 pub mod align;
 pub mod css_class;
+pub mod ellipsize;
 pub mod monitor;
+pub mod orientation;
 pub use align::*;
 pub use css_class::*;
+pub use ellipsize::*;
 pub use monitor::*;
+pub use orientation::*;
 pub mod lua_action;
 pub mod lua_fn;
+pub mod media;
 pub mod reactive;
 pub mod reactive_string;
 pub mod reactive_string_fn;
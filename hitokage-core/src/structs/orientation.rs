@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub enum Orientation {
+  #[default]
+  Horizontal,
+  Vertical,
+}
+
+impl From<Orientation> for gtk4::Orientation {
+  fn from(item: Orientation) -> Self {
+    match item {
+      Orientation::Horizontal => gtk4::Orientation::Horizontal,
+      Orientation::Vertical => gtk4::Orientation::Vertical,
+    }
+  }
+}
+
+impl From<gtk4::Orientation> for Orientation {
+  fn from(item: gtk4::Orientation) -> Self {
+    match item {
+      gtk4::Orientation::Vertical => Orientation::Vertical,
+      _ => Orientation::Horizontal,
+    }
+  }
+}
@@ -40,6 +40,9 @@ where
       }
 
       *v = v_set;
+      drop(v);
+      // lua threads may be waiting on this value
+      crate::event::LUA_WAKER.wake();
     }
   }
 }
@@ -12,6 +12,7 @@ use systemstat::System;
 #[derive(Clone)]
 pub struct SystemWrapper {
   system: Arc<System>,
+  host: Arc<HostInfo>,
   last_battery: Arc<Mutex<Option<(Instant, systemstat::BatteryLife)>>>,
   battery_semaphore: Arc<Semaphore>,
 }
@@ -20,6 +21,7 @@ impl fmt::Debug for SystemWrapper {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     f.debug_struct("SystemWrapper")
       .field("system", &"todo")
+      .field("host", &self.host)
       .field("last_battery", &self.last_battery)
       .field("battery_semaphore", &self.battery_semaphore)
       .finish()
@@ -30,6 +32,7 @@ impl SystemWrapper {
   pub fn new() -> Self {
     Self {
       system: Arc::new(System::new()),
+      host: Arc::new(HostInfo::new()),
       last_battery: Arc::new(Mutex::new(None)),
       battery_semaphore: Arc::new(Semaphore::new(1)),
     }
@@ -56,6 +59,34 @@ impl SystemWrapper {
 
     Ok(battery)
   }
+
+  pub fn system_info(&self) -> SystemInfoWrapper {
+    // load average is not supported on windows, so don't bother logging it
+    let load_average = self.system.load_average().ok();
+
+    let uptime = self.system.uptime().map_or_else(
+      |err| {
+        log::error!("Failed to fetch uptime: {}", err);
+        None
+      },
+      Some,
+    );
+
+    let boot_time = self.system.boot_time().map_or_else(
+      |err| {
+        log::error!("Failed to fetch boot time: {}", err);
+        None
+      },
+      |time| Some(time.unix_timestamp()),
+    );
+
+    SystemInfoWrapper {
+      host: self.host.clone(),
+      uptime,
+      load_average,
+      boot_time,
+    }
+  }
 }
 
 impl Default for SystemWrapper {
@@ -73,6 +104,17 @@ impl BatteryWrapper {
   pub fn format_with(&self, icons: &BatteryIcons, format: &str) -> String {
     let reg = register_hitokage_helpers(Handlebars::new());
 
+    match reg.render_template(format, &self.format_args(icons)) {
+      Ok(name) => return name,
+      Err(err) => {
+        log::error!("{:?}", err);
+      }
+    };
+
+    "".to_owned()
+  }
+
+  pub fn format_args(&self, icons: &BatteryIcons) -> HashMap<String, String> {
     let mut args = HashMap::new();
 
     if let Some(battery) = &self.battery {
@@ -100,14 +142,7 @@ impl BatteryWrapper {
       args.insert("icon".to_string(), icons.unknown.clone());
     }
 
-    match reg.render_template(format, &args) {
-      Ok(name) => return name,
-      Err(err) => {
-        log::error!("{:?}", err);
-      }
-    };
-
-    "".to_owned()
+    args
   }
 
   pub(crate) fn as_lua_args(&self) -> BatteryInfo {
@@ -241,3 +276,128 @@ impl Default for BatteryIcons {
     }
   }
 }
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct HostInfo {
+  hostname: String,
+  os: String,
+  kernel: String,
+}
+
+impl HostInfo {
+  #[cfg(target_os = "windows")]
+  fn new() -> Self {
+    HostInfo {
+      hostname: std::env::var("COMPUTERNAME").unwrap_or_default(),
+      os: format!("Windows {}", crate::win_utils::get_windows_version()),
+      kernel: crate::win_utils::get_kernel_version(),
+    }
+  }
+
+  #[cfg(not(target_os = "windows"))]
+  fn new() -> Self {
+    let read = |path: &str| {
+      std::fs::read_to_string(path)
+        .map(|s| s.trim().to_string())
+        .unwrap_or_default()
+    };
+
+    let os = read("/etc/os-release")
+      .lines()
+      .find_map(|line| line.strip_prefix("PRETTY_NAME="))
+      .map(|name| name.trim_matches('"').to_string())
+      .unwrap_or_else(|| std::env::consts::OS.to_string());
+
+    HostInfo {
+      hostname: read("/proc/sys/kernel/hostname"),
+      os,
+      kernel: read("/proc/sys/kernel/osrelease"),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct SystemInfoWrapper {
+  host: Arc<HostInfo>,
+  uptime: Option<Duration>,
+  load_average: Option<systemstat::LoadAverage>,
+  boot_time: Option<i64>,
+}
+
+impl SystemInfoWrapper {
+  pub fn format_with(&self, format: &str) -> String {
+    let reg = register_hitokage_helpers(Handlebars::new());
+
+    match reg.render_template(format, &self.as_lua_args()) {
+      Ok(name) => return name,
+      Err(err) => {
+        log::error!("{:?}", err);
+      }
+    };
+
+    "".to_owned()
+  }
+
+  pub(crate) fn as_lua_args(&self) -> SystemInfoData {
+    let uptime = self.uptime.map_or(0, |uptime| uptime.as_secs());
+    let (load1, load5, load15) = self
+      .load_average
+      .as_ref()
+      .map_or((0.0, 0.0, 0.0), |load| (load.one, load.five, load.fifteen));
+    let boot_time = self
+      .boot_time
+      .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+      .map_or(String::new(), |time| {
+        time
+          .with_timezone(&chrono::Local)
+          .format("%Y-%m-%d %H:%M:%S")
+          .to_string()
+      });
+
+    SystemInfoData {
+      uptime,
+      uptime_days: uptime / 86_400,
+      uptime_hours: uptime % 86_400 / 3_600,
+      uptime_minutes: uptime % 3_600 / 60,
+      load1,
+      load5,
+      load15,
+      hostname: self.host.hostname.clone(),
+      os: self.host.os.clone(),
+      kernel: self.host.kernel.clone(),
+      boot_time,
+      boot_timestamp: self.boot_time.unwrap_or_default(),
+    }
+  }
+}
+
+impl PartialEq for SystemInfoWrapper {
+  fn eq(&self, other: &Self) -> bool {
+    self.host == other.host
+      && self.uptime.map(|u| u.as_secs()) == other.uptime.map(|u| u.as_secs())
+      && self.boot_time == other.boot_time
+      && self
+        .load_average
+        .as_ref()
+        .zip(other.load_average.as_ref())
+        .map_or(true, |(a, b)| {
+          a.one == b.one && a.five == b.five && a.fifteen == b.fifteen
+        })
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct SystemInfoData {
+  uptime: u64,
+  uptime_days: u64,
+  uptime_hours: u64,
+  uptime_minutes: u64,
+  load1: f32,
+  load5: f32,
+  load15: f32,
+  hostname: String,
+  os: String,
+  kernel: String,
+  boot_time: String,
+  boot_timestamp: i64,
+}
@@ -1,5 +1,11 @@
+use std::ffi::c_void;
 use std::mem::MaybeUninit;
 use windows::Wdk::System::SystemServices::RtlGetVersion;
+use windows::Win32::Foundation::{HWND, LPARAM, WPARAM};
+use windows::Win32::Graphics::Gdi::{
+  CreateCompatibleDC, DeleteDC, DeleteObject, GetDIBits, GetObjectW, BITMAP, BITMAPINFO, BITMAPINFOHEADER, BI_RGB,
+  DIB_RGB_COLORS, HBITMAP, HDC,
+};
 use windows::Win32::System::SystemInformation::OSVERSIONINFOW;
 use windows::Win32::{System::Threading::GetCurrentThreadId, UI::WindowsAndMessaging::*};
 
@@ -15,23 +21,137 @@ pub fn get_current_thread_id() -> u32 {
   unsafe { GetCurrentThreadId() }
 }
 
-pub fn get_windows_version() -> u32 {
+fn rtl_get_version() -> Option<OSVERSIONINFOW> {
   unsafe {
     let mut os_info = MaybeUninit::<OSVERSIONINFOW>::zeroed();
     let os_info_ptr = os_info.as_mut_ptr();
     (*os_info_ptr).dwOSVersionInfoSize = std::mem::size_of::<OSVERSIONINFOW>() as u32;
 
     if RtlGetVersion(os_info_ptr).is_ok() {
-      let build = os_info.assume_init().dwBuildNumber;
-      if build >= 22000 {
-        11
-      } else if build >= 10240 {
-        10
-      } else {
-        0
-      }
+      Some(os_info.assume_init())
     } else {
-      panic!("Failed to get Windows version")
+      None
+    }
+  }
+}
+
+/// Returns the NT kernel version, ie. `10.0.22631`
+pub fn get_kernel_version() -> String {
+  match rtl_get_version() {
+    Some(os_info) => format!(
+      "{}.{}.{}",
+      os_info.dwMajorVersion, os_info.dwMinorVersion, os_info.dwBuildNumber
+    ),
+    None => {
+      log::error!("Failed to get Windows version");
+      String::new()
     }
   }
 }
+
+pub fn get_windows_version() -> u32 {
+  let build = rtl_get_version().expect("Failed to get Windows version").dwBuildNumber;
+  if build >= 22000 {
+    11
+  } else if build >= 10240 {
+    10
+  } else {
+    0
+  }
+}
+
+/// Returns the small icon of a window as BGRA pixels, ie. `(width, height, pixels)`
+pub fn get_window_icon(hwnd: isize) -> Option<(i32, i32, Vec<u8>)> {
+  unsafe {
+    let hwnd = HWND(hwnd);
+
+    // don't let a hung window block the bar
+    let send_get_icon = |kind: u32| {
+      let mut result = 0usize;
+      SendMessageTimeoutW(
+        hwnd,
+        WM_GETICON,
+        WPARAM(kind as usize),
+        LPARAM(0),
+        SMTO_ABORTIFHUNG,
+        100,
+        Some(&mut result),
+      );
+      result as isize
+    };
+
+    let hicon = [
+      send_get_icon(ICON_SMALL2),
+      send_get_icon(ICON_SMALL),
+      GetClassLongPtrW(hwnd, GCLP_HICONSM) as isize,
+      GetClassLongPtrW(hwnd, GCLP_HICON) as isize,
+    ]
+    .into_iter()
+    .find(|hicon| *hicon != 0)?;
+
+    let mut info = ICONINFO::default();
+    GetIconInfo(HICON(hicon), &mut info).ok()?;
+    let pixels = get_bitmap_pixels(info.hbmColor);
+    let _ = DeleteObject(info.hbmColor);
+    let _ = DeleteObject(info.hbmMask);
+    pixels
+  }
+}
+
+unsafe fn get_bitmap_pixels(bitmap: HBITMAP) -> Option<(i32, i32, Vec<u8>)> {
+  if bitmap.is_invalid() {
+    return None;
+  }
+
+  let mut bm = BITMAP::default();
+  if GetObjectW(
+    bitmap,
+    std::mem::size_of::<BITMAP>() as i32,
+    Some(&mut bm as *mut _ as *mut c_void),
+  ) == 0
+  {
+    return None;
+  }
+  let (width, height) = (bm.bmWidth, bm.bmHeight);
+
+  let mut bmi = BITMAPINFO {
+    bmiHeader: BITMAPINFOHEADER {
+      biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+      biWidth: width,
+      // negative height gives us rows top to bottom
+      biHeight: -height,
+      biPlanes: 1,
+      biBitCount: 32,
+      biCompression: BI_RGB.0,
+      ..Default::default()
+    },
+    ..Default::default()
+  };
+  let mut pixels = vec![0u8; (width * height * 4) as usize];
+
+  let hdc = CreateCompatibleDC(HDC(0));
+  let lines = GetDIBits(
+    hdc,
+    bitmap,
+    0,
+    height as u32,
+    Some(pixels.as_mut_ptr() as *mut c_void),
+    &mut bmi,
+    DIB_RGB_COLORS,
+  );
+  let _ = DeleteDC(hdc);
+  if lines == 0 {
+    return None;
+  }
+
+  // legacy icons have no alpha channel at all
+  if pixels.chunks(4).all(|p| p[3] == 0) {
+    pixels.chunks_mut(4).for_each(|p| p[3] = 255);
+  }
+
+  Some((width, height, pixels))
+}
+
+pub fn set_foreground_window(hwnd: isize) -> bool {
+  unsafe { SetForegroundWindow(HWND(hwnd)).as_bool() }
+}
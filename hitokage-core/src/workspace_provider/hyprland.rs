@@ -0,0 +1,266 @@
+use super::{WorkspaceInfo, WorkspaceProvider};
+use crate::components::workspace::WorkspaceMsg;
+use crate::structs::Monitor;
+use anyhow::Context;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+// window focus and title changes don't affect workspaces
+const IGNORED_EVENTS: [&str; 3] = ["activewindow", "activewindowv2", "windowtitle"];
+
+#[derive(Clone)]
+pub struct HyprlandProvider {
+  /// The directory holding `.socket.sock` and `.socket2.sock`
+  socket_dir: PathBuf,
+  /// The name of the bar's monitor, ie. `DP-1`
+  monitor: Option<String>,
+}
+
+impl HyprlandProvider {
+  pub fn new(monitor: &Monitor) -> anyhow::Result<Self> {
+    let signature = std::env::var("HYPRLAND_INSTANCE_SIGNATURE").context("HYPRLAND_INSTANCE_SIGNATURE is not set")?;
+
+    // hyprland moved its sockets from /tmp into the runtime dir
+    let socket_dir = std::env::var_os("XDG_RUNTIME_DIR")
+      .map(|dir| PathBuf::from(dir).join("hypr").join(&signature))
+      .filter(|dir| dir.exists())
+      .unwrap_or_else(|| PathBuf::from("/tmp/hypr").join(&signature));
+
+    Ok(HyprlandProvider {
+      socket_dir,
+      monitor: monitor.connector.clone(),
+    })
+  }
+
+  fn request(&self, command: &str) -> anyhow::Result<String> {
+    let mut stream = UnixStream::connect(self.socket_dir.join(".socket.sock"))?;
+    stream.write_all(command.as_bytes())?;
+    let mut reply = String::new();
+    stream.read_to_string(&mut reply)?;
+    Ok(reply)
+  }
+
+  fn request_json(&self, command: &str) -> anyhow::Result<serde_json::Value> {
+    Ok(serde_json::from_str(&self.request(&format!("j/{}", command))?)?)
+  }
+
+  /// The workspaces on this monitor ordered by id, special workspaces are left out
+  fn monitor_workspaces(&self) -> anyhow::Result<Vec<serde_json::Value>> {
+    let workspaces = self.request_json("workspaces")?;
+    let mut workspaces: Vec<serde_json::Value> = workspaces
+      .as_array()
+      .context("Invalid workspaces reply")?
+      .iter()
+      .filter(|workspace| workspace.get("id").and_then(|v| v.as_i64()).unwrap_or(-1) > 0)
+      .filter(|workspace| match &self.monitor {
+        Some(monitor) => workspace.get("monitor").and_then(|v| v.as_str()) == Some(monitor),
+        None => true,
+      })
+      .cloned()
+      .collect();
+    workspaces.sort_by_key(|workspace| workspace.get("id").and_then(|v| v.as_i64()));
+    Ok(workspaces)
+  }
+}
+
+impl WorkspaceProvider for HyprlandProvider {
+  fn get_workspaces(&self) -> anyhow::Result<Vec<WorkspaceInfo>> {
+    let workspaces = self.monitor_workspaces()?;
+    let monitors = self.request_json("monitors")?;
+    let clients = self.request_json("clients")?;
+    // hyprland only has a global layout
+    let layout = self
+      .request_json("getoption general:layout")
+      .ok()
+      .and_then(|option| option.get("str").and_then(|v| v.as_str()).map(String::from))
+      .unwrap_or_default();
+
+    let active_workspace = monitors
+      .as_array()
+      .context("Invalid monitors reply")?
+      .iter()
+      .find(|monitor| match &self.monitor {
+        Some(name) => monitor.get("name").and_then(|v| v.as_str()) == Some(name),
+        None => monitor.get("focused").and_then(|v| v.as_bool()).unwrap_or(false),
+      })
+      .and_then(|monitor| monitor.get("activeWorkspace"))
+      .and_then(|workspace| workspace.get("id"))
+      .and_then(|v| v.as_i64());
+
+    Ok(
+      workspaces
+        .iter()
+        .map(|workspace| {
+          let id = workspace.get("id").and_then(|v| v.as_i64());
+          let exes = clients
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|client| {
+              client
+                .get("workspace")
+                .and_then(|v| v.get("id"))
+                .and_then(|v| v.as_i64())
+                == id
+            })
+            .filter_map(|client| client.get("class").and_then(|v| v.as_str()).map(String::from))
+            .collect();
+
+          WorkspaceInfo {
+            name: workspace.get("name").and_then(|v| v.as_str()).map(String::from),
+            focused: id.is_some() && id == active_workspace,
+            window_count: workspace.get("windows").and_then(|v| v.as_u64()).unwrap_or(0) as usize,
+            layout: layout.clone(),
+            exes,
+          }
+        })
+        .collect(),
+    )
+  }
+
+  fn focus_workspace(&self, index: usize) -> anyhow::Result<()> {
+    let workspaces = self.monitor_workspaces()?;
+    let id = workspaces
+      .get(index)
+      .and_then(|workspace| workspace.get("id"))
+      .and_then(|v| v.as_i64())
+      .context("Workspace not found")?;
+
+    let reply = self.request(&format!("dispatch workspace {}", id))?;
+    anyhow::ensure!(reply.trim() == "ok", "Failed to focus workspace {}: {}", id, reply);
+    Ok(())
+  }
+
+  fn subscribe(&self, format: Option<String>, sender: &relm4::Sender<WorkspaceMsg>) {
+    let provider = self.clone();
+    let sender = sender.clone();
+
+    std::thread::spawn(move || -> anyhow::Result<()> {
+      let stream = UnixStream::connect(provider.socket_dir.join(".socket2.sock"))?;
+      if sender
+        .send(WorkspaceMsg::Workspaces(provider.workspaces(&format)))
+        .is_err()
+      {
+        return Ok(());
+      }
+
+      for line in BufReader::new(stream).lines() {
+        let line = match line {
+          Ok(line) => line,
+          Err(err) => {
+            log::error!("Lost connection to the hyprland event socket: {:?}", err);
+            return Err(err.into());
+          }
+        };
+        if is_ignored_event(&line) {
+          continue;
+        }
+        // the component is gone
+        if sender
+          .send(WorkspaceMsg::Workspaces(provider.workspaces(&format)))
+          .is_err()
+        {
+          return Ok(());
+        }
+      }
+      Ok(())
+    });
+  }
+}
+
+/// Events look like `workspace>>2`
+fn is_ignored_event(line: &str) -> bool {
+  let event = line.split(">>").next().unwrap_or_default();
+  IGNORED_EVENTS.contains(&event)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::os::unix::net::UnixListener;
+  use std::sync::mpsc;
+
+  /// Answers one command per connection and closes it like hyprland does, and forwards every command it got
+  fn mock_socket(name: &str, replies: Vec<String>) -> (PathBuf, mpsc::Receiver<String>, std::thread::JoinHandle<()>) {
+    let socket_dir = std::env::temp_dir().join(format!("hitokage-hyprland-{}-{}", std::process::id(), name));
+    std::fs::create_dir_all(&socket_dir).unwrap();
+    let path = socket_dir.join(".socket.sock");
+    let _ = std::fs::remove_file(&path);
+    let listener = UnixListener::bind(&path).unwrap();
+    let (tx, rx) = mpsc::channel();
+
+    let handle = std::thread::spawn(move || {
+      for reply in replies {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut command = [0u8; 1024];
+        let length = stream.read(&mut command).unwrap();
+        tx.send(String::from_utf8_lossy(&command[..length]).to_string())
+          .unwrap();
+        stream.write_all(reply.as_bytes()).unwrap();
+      }
+    });
+
+    (socket_dir, rx, handle)
+  }
+
+  #[test]
+  fn filters_and_sorts_monitor_workspaces() {
+    let workspaces = serde_json::json!([
+      { "id": 3, "name": "3", "monitor": "DP-1" },
+      { "id": -98, "name": "special:scratchpad", "monitor": "DP-1" },
+      { "id": 2, "name": "2", "monitor": "HDMI-A-1" },
+      { "id": 1, "name": "1", "monitor": "DP-1" },
+    ]);
+    let (socket_dir, commands, handle) = mock_socket("workspaces", vec![workspaces.to_string()]);
+
+    let provider = HyprlandProvider {
+      socket_dir: socket_dir.clone(),
+      monitor: Some("DP-1".to_string()),
+    };
+    let names: Vec<_> = provider
+      .monitor_workspaces()
+      .unwrap()
+      .iter()
+      .map(|workspace| workspace["name"].as_str().unwrap().to_string())
+      .collect();
+
+    assert_eq!(names, ["1", "3"]);
+    assert_eq!(commands.recv().unwrap(), "j/workspaces");
+
+    handle.join().unwrap();
+    let _ = std::fs::remove_dir_all(&socket_dir);
+  }
+
+  #[test]
+  fn focuses_workspace_by_id() {
+    let workspaces = serde_json::json!([
+      { "id": 5, "name": "5", "monitor": "DP-1" },
+      { "id": 4, "name": "4", "monitor": "DP-1" },
+    ]);
+    let (socket_dir, commands, handle) = mock_socket("focus", vec![workspaces.to_string(), "ok".to_string()]);
+
+    let provider = HyprlandProvider {
+      socket_dir: socket_dir.clone(),
+      monitor: Some("DP-1".to_string()),
+    };
+    provider.focus_workspace(1).unwrap();
+
+    assert_eq!(commands.recv().unwrap(), "j/workspaces");
+    assert_eq!(commands.recv().unwrap(), "dispatch workspace 5");
+
+    handle.join().unwrap();
+    let _ = std::fs::remove_dir_all(&socket_dir);
+  }
+
+  #[test]
+  fn ignores_window_focus_and_title_events() {
+    assert!(is_ignored_event("activewindow>>kitty,~"));
+    assert!(is_ignored_event("activewindowv2>>5599dd2a1b30"));
+    assert!(is_ignored_event("windowtitle>>5599dd2a1b30"));
+    assert!(!is_ignored_event("workspace>>2"));
+    assert!(!is_ignored_event("openwindow>>5599dd2a1b30,2,kitty,~"));
+    // `activewindow` must match the whole event name
+    assert!(!is_ignored_event("activewindowfoo>>1"));
+  }
+}
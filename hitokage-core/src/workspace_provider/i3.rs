@@ -0,0 +1,354 @@
+use super::{WorkspaceInfo, WorkspaceProvider, WorkspaceProviderKind};
+use crate::components::workspace::WorkspaceMsg;
+use crate::structs::Monitor;
+use anyhow::Context;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+// https://i3wm.org/docs/ipc.html, sway speaks the same protocol
+const MAGIC: &[u8; 6] = b"i3-ipc";
+const RUN_COMMAND: u32 = 0;
+const GET_WORKSPACES: u32 = 1;
+const SUBSCRIBE: u32 = 2;
+const GET_TREE: u32 = 4;
+const WINDOW_EVENT: u32 = 0x80000003;
+
+// window focus and title changes don't affect workspaces
+const IGNORED_WINDOW_CHANGES: [&str; 4] = ["focus", "title", "mark", "urgent"];
+
+#[derive(Clone)]
+pub struct I3Provider {
+  socket_path: PathBuf,
+  /// The output of the bar's monitor, ie. `DP-1`
+  output: Option<String>,
+}
+
+impl I3Provider {
+  pub fn new(kind: WorkspaceProviderKind, monitor: &Monitor) -> anyhow::Result<Self> {
+    let socket_path = match kind {
+      WorkspaceProviderKind::Sway => std::env::var("SWAYSOCK").context("SWAYSOCK is not set")?,
+      _ => match std::env::var("I3SOCK") {
+        Ok(path) => path,
+        Err(_) => {
+          let output = std::process::Command::new("i3").arg("--get-socketpath").output()?;
+          String::from_utf8(output.stdout)?.trim().to_string()
+        }
+      },
+    };
+
+    Ok(I3Provider {
+      socket_path: socket_path.into(),
+      output: monitor.connector.clone(),
+    })
+  }
+
+  fn request(&self, message_type: u32, payload: &str) -> anyhow::Result<serde_json::Value> {
+    let mut stream = UnixStream::connect(&self.socket_path)?;
+    send_message(&mut stream, message_type, payload)?;
+    let (_, reply) = read_message(&mut stream)?;
+    Ok(reply)
+  }
+
+  /// The workspaces on this output, as returned by `GET_WORKSPACES`
+  fn output_workspaces(&self) -> anyhow::Result<Vec<serde_json::Value>> {
+    let workspaces = self.request(GET_WORKSPACES, "")?;
+    Ok(
+      workspaces
+        .as_array()
+        .context("Invalid workspaces reply")?
+        .iter()
+        .filter(|workspace| match &self.output {
+          Some(output) => workspace.get("output").and_then(|v| v.as_str()) == Some(output),
+          None => true,
+        })
+        .cloned()
+        .collect(),
+    )
+  }
+}
+
+impl WorkspaceProvider for I3Provider {
+  fn get_workspaces(&self) -> anyhow::Result<Vec<WorkspaceInfo>> {
+    let workspaces = self.output_workspaces()?;
+    let tree = self.request(GET_TREE, "")?;
+
+    let mut nodes = Vec::new();
+    find_workspace_nodes(&tree, &mut nodes);
+
+    Ok(
+      workspaces
+        .iter()
+        .map(|workspace| {
+          let name = workspace.get("name").and_then(|v| v.as_str());
+          let node = nodes
+            .iter()
+            .find(|node| node.get("name").and_then(|v| v.as_str()) == name);
+
+          let mut windows = Vec::new();
+          if let Some(node) = node {
+            find_windows(node, &mut windows);
+          }
+
+          WorkspaceInfo {
+            name: name.map(String::from),
+            // the workspace shown on this output, rather than the one with keyboard focus
+            focused: workspace.get("visible").and_then(|v| v.as_bool()).unwrap_or(false),
+            window_count: windows.len(),
+            layout: node
+              .and_then(|node| node.get("layout"))
+              .and_then(|v| v.as_str())
+              .unwrap_or_default()
+              .to_string(),
+            exes: windows.iter().map(|window| window_app(window)).collect(),
+          }
+        })
+        .collect(),
+    )
+  }
+
+  fn focus_workspace(&self, index: usize) -> anyhow::Result<()> {
+    let workspaces = self.output_workspaces()?;
+    let name = workspaces
+      .get(index)
+      .and_then(|workspace| workspace.get("name"))
+      .and_then(|v| v.as_str())
+      .context("Workspace not found")?;
+
+    let command = format!("workspace \"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""));
+    let reply = self.request(RUN_COMMAND, &command)?;
+    let success = reply
+      .get(0)
+      .and_then(|v| v.get("success"))
+      .and_then(|v| v.as_bool())
+      .unwrap_or(false);
+    anyhow::ensure!(success, "Failed to run '{}': {}", command, reply);
+    Ok(())
+  }
+
+  fn subscribe(&self, format: Option<String>, sender: &relm4::Sender<WorkspaceMsg>) {
+    let provider = self.clone();
+    let sender = sender.clone();
+
+    std::thread::spawn(move || -> anyhow::Result<()> {
+      let mut stream = UnixStream::connect(&provider.socket_path)?;
+      send_message(&mut stream, SUBSCRIBE, r#"["workspace","window"]"#)?;
+      let (_, reply) = read_message(&mut stream)?;
+      anyhow::ensure!(
+        reply.get("success").and_then(|v| v.as_bool()).unwrap_or(false),
+        "Failed to subscribe: {}",
+        reply
+      );
+
+      let mut event = None;
+      loop {
+        if !event.as_ref().is_some_and(is_ignored_event) {
+          // the component is gone
+          if sender
+            .send(WorkspaceMsg::Workspaces(provider.workspaces(&format)))
+            .is_err()
+          {
+            return Ok(());
+          }
+        }
+
+        match read_message(&mut stream) {
+          Ok(message) => event = Some(message),
+          Err(err) => {
+            log::error!("Lost connection to the i3 ipc socket: {:?}", err);
+            return Err(err);
+          }
+        }
+      }
+    });
+  }
+}
+
+fn is_ignored_event((message_type, event): &(u32, serde_json::Value)) -> bool {
+  *message_type == WINDOW_EVENT
+    && event
+      .get("change")
+      .and_then(|v| v.as_str())
+      .is_some_and(|change| IGNORED_WINDOW_CHANGES.contains(&change))
+}
+
+fn send_message(stream: &mut UnixStream, message_type: u32, payload: &str) -> std::io::Result<()> {
+  let mut message = Vec::with_capacity(14 + payload.len());
+  message.extend_from_slice(MAGIC);
+  message.extend_from_slice(&(payload.len() as u32).to_ne_bytes());
+  message.extend_from_slice(&message_type.to_ne_bytes());
+  message.extend_from_slice(payload.as_bytes());
+  stream.write_all(&message)
+}
+
+fn read_message(stream: &mut UnixStream) -> anyhow::Result<(u32, serde_json::Value)> {
+  let mut header = [0u8; 14];
+  stream.read_exact(&mut header)?;
+  anyhow::ensure!(&header[0..6] == MAGIC, "Invalid i3 ipc magic");
+
+  let length = u32::from_ne_bytes(header[6..10].try_into()?) as usize;
+  let message_type = u32::from_ne_bytes(header[10..14].try_into()?);
+
+  let mut payload = vec![0u8; length];
+  stream.read_exact(&mut payload)?;
+  Ok((message_type, serde_json::from_slice(&payload)?))
+}
+
+fn children(node: &serde_json::Value) -> impl Iterator<Item = &serde_json::Value> {
+  ["nodes", "floating_nodes"]
+    .into_iter()
+    .filter_map(|key| node.get(key).and_then(|v| v.as_array()))
+    .flatten()
+}
+
+fn find_workspace_nodes<'a>(node: &'a serde_json::Value, nodes: &mut Vec<&'a serde_json::Value>) {
+  if node.get("type").and_then(|v| v.as_str()) == Some("workspace") {
+    nodes.push(node);
+    return;
+  }
+  children(node).for_each(|child| find_workspace_nodes(child, nodes));
+}
+
+fn find_windows<'a>(node: &'a serde_json::Value, windows: &mut Vec<&'a serde_json::Value>) {
+  // i3 sets `window` on x11 windows, sway sets `pid` on both wayland and xwayland windows
+  let is_window = node.get("window").is_some_and(|v| !v.is_null()) || node.get("pid").is_some_and(|v| !v.is_null());
+  if is_window {
+    windows.push(node);
+    return;
+  }
+  children(node).for_each(|child| find_windows(child, windows));
+}
+
+fn window_app(window: &serde_json::Value) -> String {
+  window
+    .get("app_id")
+    .and_then(|v| v.as_str())
+    .or_else(|| {
+      window
+        .get("window_properties")
+        .and_then(|v| v.get("class"))
+        .and_then(|v| v.as_str())
+    })
+    .unwrap_or_default()
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::os::unix::net::UnixListener;
+  use std::sync::mpsc;
+
+  /// Answers one request per connection like i3 does, and forwards every request it got
+  fn mock_socket(
+    name: &str,
+    replies: Vec<serde_json::Value>,
+  ) -> (PathBuf, mpsc::Receiver<(u32, String)>, std::thread::JoinHandle<()>) {
+    let path = std::env::temp_dir().join(format!("hitokage-i3-{}-{}.sock", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    let listener = UnixListener::bind(&path).unwrap();
+    let (tx, rx) = mpsc::channel();
+
+    let handle = std::thread::spawn(move || {
+      for reply in replies {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut header = [0u8; 14];
+        stream.read_exact(&mut header).unwrap();
+        assert_eq!(&header[0..6], MAGIC);
+        let length = u32::from_ne_bytes(header[6..10].try_into().unwrap()) as usize;
+        let message_type = u32::from_ne_bytes(header[10..14].try_into().unwrap());
+        let mut payload = vec![0u8; length];
+        stream.read_exact(&mut payload).unwrap();
+        tx.send((message_type, String::from_utf8(payload).unwrap())).unwrap();
+
+        send_message(&mut stream, message_type, &reply.to_string()).unwrap();
+      }
+    });
+
+    (path, rx, handle)
+  }
+
+  #[test]
+  fn frames_messages() {
+    let reply = serde_json::json!([{ "name": "1", "output": "DP-1" }]);
+    let (path, requests, handle) = mock_socket("frames", vec![reply.clone()]);
+
+    let mut stream = UnixStream::connect(&path).unwrap();
+    send_message(&mut stream, GET_WORKSPACES, "payload").unwrap();
+    assert_eq!(read_message(&mut stream).unwrap(), (GET_WORKSPACES, reply));
+    assert_eq!(requests.recv().unwrap(), (GET_WORKSPACES, "payload".to_string()));
+
+    handle.join().unwrap();
+    let _ = std::fs::remove_file(&path);
+  }
+
+  #[test]
+  fn rejects_invalid_magic() {
+    let (mut client, mut server) = UnixStream::pair().unwrap();
+    server.write_all(b"i4-ipc\x00\x00\x00\x00\x00\x00\x00\x00").unwrap();
+    assert!(read_message(&mut client).is_err());
+  }
+
+  #[test]
+  fn finds_nested_and_floating_windows() {
+    let workspace = serde_json::json!({
+      "type": "workspace",
+      "window": null,
+      "nodes": [
+        { "window": 1, "window_properties": { "class": "Firefox" } },
+        { "layout": "tabbed", "nodes": [{ "pid": 2, "app_id": "foot" }, { "pid": 3, "app_id": "kitty" }] },
+      ],
+      "floating_nodes": [{ "nodes": [{ "pid": 4, "app_id": "pavucontrol" }] }],
+    });
+
+    let mut windows = Vec::new();
+    find_windows(&workspace, &mut windows);
+    assert_eq!(
+      windows.iter().map(|window| window_app(window)).collect::<Vec<_>>(),
+      ["Firefox", "foot", "kitty", "pavucontrol"]
+    );
+  }
+
+  #[test]
+  fn quotes_focus_command() {
+    let (path, requests, handle) = mock_socket(
+      "focus",
+      vec![
+        serde_json::json!([
+          { "name": "other", "output": "HDMI-1" },
+          { "name": "1: \"web\" \\ mail", "output": "DP-1" },
+        ]),
+        serde_json::json!([{ "success": true }]),
+      ],
+    );
+
+    let provider = I3Provider {
+      socket_path: path.clone(),
+      output: Some("DP-1".to_string()),
+    };
+    provider.focus_workspace(0).unwrap();
+
+    assert_eq!(requests.recv().unwrap(), (GET_WORKSPACES, String::new()));
+    assert_eq!(
+      requests.recv().unwrap(),
+      (RUN_COMMAND, r#"workspace "1: \"web\" \\ mail""#.to_string())
+    );
+
+    handle.join().unwrap();
+    let _ = std::fs::remove_file(&path);
+  }
+
+  #[test]
+  fn ignores_window_title_and_focus_events() {
+    let window_event = |change: &str| (WINDOW_EVENT, serde_json::json!({ "change": change }));
+    assert!(is_ignored_event(&window_event("title")));
+    assert!(is_ignored_event(&window_event("focus")));
+    assert!(!is_ignored_event(&window_event("new")));
+    assert!(!is_ignored_event(&window_event("close")));
+    // workspace events share the change names with window events
+    assert!(!is_ignored_event(&(
+      0x80000000,
+      serde_json::json!({ "change": "focus" })
+    )));
+  }
+}
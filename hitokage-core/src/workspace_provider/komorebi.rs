@@ -0,0 +1,85 @@
+use super::{format_workspaces, WorkspaceInfo, WorkspaceProvider};
+use crate::components::workspace::WorkspaceMsg;
+use crate::event::STATE;
+use crate::komorebi::{get_layout_name, get_monitor, get_monitor_index, get_window_count, get_workspace_windows};
+use crate::structs::Monitor;
+use anyhow::Context;
+
+pub struct KomorebiProvider {
+  monitor_id: u32, // win id
+}
+
+impl KomorebiProvider {
+  pub fn new(monitor: &Monitor) -> Self {
+    KomorebiProvider {
+      monitor_id: monitor.id as u32,
+    }
+  }
+}
+
+/// The workspaces on `monitor_id` in a komorebi state
+fn state_workspaces(state: &serde_json::Value, monitor_id: u32) -> anyhow::Result<Vec<WorkspaceInfo>> {
+  // komorebi has not sent us a state yet
+  if state.is_null() {
+    return Ok(Vec::new());
+  }
+
+  let workspaces = get_monitor(state, monitor_id)?
+    .get("workspaces")
+    .context("Missing 'workspaces' in monitor")?;
+
+  let focused = workspaces.get("focused").and_then(|v| v.as_u64()).map(|v| v as usize);
+
+  let elements = workspaces
+    .get("elements")
+    .context("Missing 'elements' in 'workspaces'")?
+    .as_array()
+    .context("Invalid 'elements' format in 'workspaces'")?;
+
+  Ok(
+    elements
+      .iter()
+      .enumerate()
+      .map(|(index, workspace)| WorkspaceInfo {
+        name: workspace.get("name").and_then(|v| v.as_str()).map(String::from),
+        focused: focused == Some(index),
+        window_count: get_window_count(workspace),
+        layout: get_layout_name(workspace),
+        exes: get_workspace_windows(workspace)
+          .filter_map(|window| window.get("exe").and_then(|v| v.as_str()))
+          .map(String::from)
+          .collect(),
+      })
+      .collect(),
+  )
+}
+
+impl WorkspaceProvider for KomorebiProvider {
+  fn get_workspaces(&self) -> anyhow::Result<Vec<WorkspaceInfo>> {
+    state_workspaces(&STATE.read(), self.monitor_id)
+  }
+
+  fn focus_workspace(&self, index: usize) -> anyhow::Result<()> {
+    // focus a workspace on this monitor, rather than whichever monitor komorebi has focused
+    let monitor_index = get_monitor_index(&STATE.read(), self.monitor_id)?;
+    komorebi_client::send_message(&komorebi_client::SocketMessage::FocusMonitorWorkspaceNumber(
+      monitor_index,
+      index,
+    ))?;
+    Ok(())
+  }
+
+  fn subscribe(&self, format: Option<String>, sender: &relm4::Sender<WorkspaceMsg>) {
+    let _ = sender.send(WorkspaceMsg::Workspaces(self.workspaces(&format)));
+
+    // we only care about the most recent state, which is still locked while subscribers run
+    let monitor_id = self.monitor_id;
+    STATE.subscribe(sender, move |state| {
+      let workspaces = state_workspaces(state, monitor_id).unwrap_or_else(|err| {
+        log::error!("Failed to get workspaces: {:?}", err);
+        Vec::new()
+      });
+      WorkspaceMsg::Workspaces(format_workspaces(workspaces, &format))
+    });
+  }
+}
@@ -0,0 +1,123 @@
+use crate::components::workspace::{WorkspaceMsg, WorkspaceState};
+use crate::handlebar::register_hitokage_helpers;
+use crate::structs::Monitor;
+use handlebars::Handlebars;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[cfg(unix)]
+pub mod hyprland;
+#[cfg(unix)]
+pub mod i3;
+pub mod komorebi;
+
+/// A single workspace as reported by a window manager
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct WorkspaceInfo {
+  pub name: Option<String>,
+  pub focused: bool,
+  /// Every window on the workspace, including stacked and floating windows
+  pub window_count: usize,
+  /// ie. `BSP` for komorebi, `splith` for i3 and sway, `dwindle` for hyprland
+  pub layout: String,
+  /// The exe, class or app id of each window
+  pub exes: Vec<String>,
+}
+
+/// Feeds the `Workspace` component from a window manager
+pub trait WorkspaceProvider: Send + Sync {
+  /// The workspaces on the bar's monitor, in order
+  fn get_workspaces(&self) -> anyhow::Result<Vec<WorkspaceInfo>>;
+
+  /// Focuses the workspace at `index` of [`WorkspaceProvider::get_workspaces`] on the bar's monitor
+  fn focus_workspace(&self, index: usize) -> anyhow::Result<()>;
+
+  /// Sends [`WorkspaceMsg::Workspaces`] once and again whenever the workspaces may have changed.
+  ///
+  /// The workspaces are fetched off the main thread, since most providers talk to a socket.
+  fn subscribe(&self, format: Option<String>, sender: &relm4::Sender<WorkspaceMsg>);
+
+  fn workspaces(&self, format: &Option<String>) -> Vec<WorkspaceState> {
+    match self.get_workspaces() {
+      Ok(workspaces) => format_workspaces(workspaces, format),
+      Err(err) => {
+        log::error!("Failed to get workspaces: {:?}", err);
+        Vec::new()
+      }
+    }
+  }
+}
+
+pub fn format_workspaces(workspaces: Vec<WorkspaceInfo>, format: &Option<String>) -> Vec<WorkspaceState> {
+  let reg = register_hitokage_helpers(Handlebars::new());
+
+  workspaces
+    .into_iter()
+    .enumerate()
+    .map(|(index, workspace)| {
+      let name = match format {
+        Some(format) => {
+          let args = serde_json::json!({
+            "name": workspace.name.clone().unwrap_or_default(),
+            "index": index.to_string(),
+            "window_count": workspace.window_count,
+            "is_empty": workspace.window_count == 0,
+            "layout": workspace.layout,
+            "exes": workspace.exes,
+          });
+
+          match reg.render_template(format, &args) {
+            Ok(name) => Some(name),
+            Err(err) => {
+              log::error!("{:?}", err);
+              workspace.name
+            }
+          }
+        }
+        None => workspace.name,
+      };
+
+      WorkspaceState {
+        name,
+        focused: workspace.focused,
+        // empty workspaces are hidden unless they are focused
+        visible: workspace.focused || workspace.window_count > 0,
+      }
+    })
+    .collect()
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum WorkspaceProviderKind {
+  Komorebi,
+  I3,
+  Sway,
+  Hyprland,
+}
+
+impl WorkspaceProviderKind {
+  /// Picks a provider from the environment hitokage is running in
+  pub fn detect() -> Self {
+    if std::env::var_os("HYPRLAND_INSTANCE_SIGNATURE").is_some() {
+      WorkspaceProviderKind::Hyprland
+    } else if std::env::var_os("SWAYSOCK").is_some() {
+      WorkspaceProviderKind::Sway
+    } else if std::env::var_os("I3SOCK").is_some() {
+      WorkspaceProviderKind::I3
+    } else {
+      WorkspaceProviderKind::Komorebi
+    }
+  }
+
+  pub fn create(self, monitor: &Monitor) -> anyhow::Result<Arc<dyn WorkspaceProvider>> {
+    match self {
+      WorkspaceProviderKind::Komorebi => Ok(Arc::new(komorebi::KomorebiProvider::new(monitor))),
+      #[cfg(unix)]
+      WorkspaceProviderKind::I3 | WorkspaceProviderKind::Sway => Ok(Arc::new(i3::I3Provider::new(self, monitor)?)),
+      #[cfg(unix)]
+      WorkspaceProviderKind::Hyprland => Ok(Arc::new(hyprland::HyprlandProvider::new(monitor)?)),
+      #[cfg(not(unix))]
+      _ => anyhow::bail!("{:?} is not supported on this platform", self),
+    }
+  }
+}
@@ -0,0 +1,35 @@
+---@meta hitokage.api.sandbox
+
+-------------------------------------------------------------------------------
+---Represents the sandbox module
+---
+---Limits what a misbehaving config can take from the rest of the system. The lua runtime may allocate up to 512MB,
+---and a callback, ie. a subscription, a timer or a component action, may run for up to 1000ms at a time before it is
+---stopped with an error. Time a callback spends waiting, ie. in `hitokage.sleep_ms`, does not count against its
+---budget.
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    hitokage.sandbox.set_memory_limit(1024)
+---    hitokage.sandbox.set_callback_budget(5000)
+---    ```
+---
+---@class sandbox
+local sandbox = {}
+
+---Sets how much memory the lua runtime may allocate, in megabytes. Allocations past the limit fail with a memory
+---error. `0` removes the limit.
+---@param mb number
+---@return nil
+function sandbox.set_memory_limit(mb) end
+
+---Sets how long a callback may run at a time, in milliseconds. `0` removes the budget.
+---@param ms number
+---@return nil
+function sandbox.set_callback_budget(ms) end
+
+return sandbox
@@ -0,0 +1,75 @@
+---@meta hitokage.api.timer
+
+-------------------------------------------------------------------------------
+---Represents a running timer
+---@class TimerHandle
+local timer_handle = {}
+
+---Stops the timer, the callback will not run again until the timer is reset
+---@return nil
+function timer_handle:cancel() end
+
+---Restarts the countdown from now. A cancelled or finished timer is started again
+---@return nil
+function timer_handle:reset() end
+
+---Whether the timer will run its callback again
+---@return boolean
+function timer_handle:is_active() end
+
+-------------------------------------------------------------------------------
+---Represents the timer module
+---
+---Timers run on wall-clock time. A callback that takes longer than the interval skips the ticks it missed instead of
+---delaying every tick after it.
+---@class timer
+local timer = {}
+
+---Runs `callback` every `ms` milliseconds
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    local interval = hitokage.timer.interval(1000, function()
+---    	hitokage.debug("About 1000ms has passed")
+---    end)
+---
+---    -- later
+---    interval:cancel()
+---    ```
+---
+---@param ms number
+---@param callback function
+---@return TimerHandle
+function timer.interval(ms, callback) end
+
+---Runs `callback` once after `ms` milliseconds
+---@param ms number
+---@param callback function
+---@return TimerHandle
+function timer.after(ms, callback) end
+
+---Runs `callback` on a cron schedule. Accepts the usual five fields, or six and seven with leading seconds and trailing
+---years.
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    -- every weekday at 9:00
+---    hitokage.timer.cron("0 9 * * Mon-Fri", function()
+---    	hitokage.info("Good morning")
+---    end)
+---    ```
+---
+---@param expr string
+---@param callback function
+---@return TimerHandle
+function timer.cron(expr, callback) end
+
+return timer
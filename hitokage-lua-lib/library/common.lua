@@ -6,3 +6,11 @@
 
 ---@class UnknownAlign
 ---@field __Unknown number
+
+---Links to Orientation in 'hitokage-core\src\structs\orientation.rs'
+
+---@alias Orientation 'Horizontal' | 'Vertical'
+
+---Links to EllipsizeMode in 'hitokage-core\src\structs\ellipsize.rs'
+
+---@alias EllipsizeMode 'None' | 'Start' | 'Middle' | 'End'
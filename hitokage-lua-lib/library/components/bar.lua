@@ -5,7 +5,7 @@
 ---@field x integer?
 ---@field y integer?
 
--- ---@alias BarPosition 'Top' | 'Bottom'
+---@alias BarPosition 'Top' | 'Bottom' | 'Left' | 'Right'
 
 --------------------------------------------------------------------------------
 
@@ -37,10 +37,51 @@
 ---<!--@mkdocs-include <a href="/hitokage/api/Bar" title="Bar">`Bar`</a> -->
 ---
 ---@class BarProps : BoxProps
+---
+---Defaults to the width of the monitor, or `24` for `'Left'` and `'Right'` bars.
+---
 ---@field width integer?
+---
+---Defaults to `24`, or the height of the monitor for `'Left'` and `'Right'` bars.
+---
 ---@field height integer?
 ---@field offset BarOffset?
--- ---@field position BarPosition?
+---
+---The screen edge the bar is attached to. Defaults to `'Top'`.
+---
+---`'Left'` and `'Right'` bars lay out their children vertically unless `orientation` is set.
+---
+---@field position BarPosition?
+---
+---<!--@mkdocs-include Children placed at the start of the bar. See <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a>-->
+---
+---`start`, `center` and `end` are laid out after `children`. The `center` children stay centered on the bar regardless
+---of the size of the `start` and `end` children. Each section is a box with the css class of its name, ie. `.center`.
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    bar_props = {
+---      start = { { Workspace = { item_height = 24, item_width = 24 } } },
+---      center = { { Clock = { format = "%a %b %u %r" } } },
+---      ["end"] = { { Battery = { format = battery_str } } },
+---    }
+---    ```
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/class.CenterBox.html)
+---
+---@field start ComponentProps
+---
+---<!--@mkdocs-include Children kept centered on the bar. See <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a>-->
+---
+---@field center ComponentProps
+---
+---<!--@mkdocs-include Children placed at the end of the bar. See <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a>-->
+---
+---@field end ComponentProps
 
 --------------------------------------------------------------------------------
 ---Links to BarUserData in 'hitokage-lua\src\components\bar.rs'
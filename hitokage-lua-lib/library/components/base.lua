@@ -1,5 +1,23 @@
 ---@meta hitokage.components.base
 
+--------------------------------------------------------------------------------
+---Links to EventContext in 'hitokage-core\src\components\base.rs'
+
+---The context passed to event handlers.
+---@class EventContext
+---
+---The id of the component, if one was provided.
+---@field id string | nil
+---
+---The mouse button which was pressed, `1` is primary, `2` is middle, `3` is secondary. `nil` for scroll and hover events.
+---@field button number | nil
+---
+---The scroll direction. `nil` for click and hover events.
+---@field direction 'Up' | 'Down' | nil
+---
+---The modifiers held during the event.
+---@field modifiers table<number, 'shift' | 'ctrl' | 'alt' | 'super'>
+
 --------------------------------------------------------------------------------
 ---Links to BaseProps in 'hitokage-core\src\components\base.rs'
 
@@ -46,12 +64,57 @@
 ---
 ---@field id string?
 ---
+---Called when the widget is clicked with any mouse button. Use `ctx.button` to tell them apart.
+---
+---<!--@mkdocs-include
+---!!! example
+---
+---    ```lua
+---    on_click = function(ctx)
+---      if ctx.button == 3 then
+---        hitokage.debug("right clicked", ctx.id)
+---      end
+---    end
+---    ```
+----->
+---
+---@field on_click fun(ctx: EventContext)?
+---
+---Called when scrolling over the widget. Use `ctx.direction` to tell them apart.
+---
+---@field on_scroll fun(ctx: EventContext)?
+---
+---Called when the pointer enters the widget.
+---
+---@field on_hover_enter fun(ctx: EventContext)?
+---
+---Called when the pointer leaves the widget.
+---
+---@field on_hover_leave fun(ctx: EventContext)?
+---
 ---Whether to expand horizontally. Defaults to `false`
 ---
 ---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Widget.hexpand.html)
 ---
 ---@field hexpand boolean?
 ---
+---Text shown when hovering the widget.
+---
+---For components with a `format`, such as `Cpu`, `Memory`, `Battery` and `Weather`, this is a template rendered against the
+---same arguments as `format`. A function is called with those arguments, or with `{ id = id }` for other components, each time the
+---pointer enters the widget.
+---
+---<!--@mkdocs-include
+---!!! example
+---
+---    ```lua
+---    tooltip = "core0 {{round (mult core0_usage 100) 1}}% core1 {{round (mult core1_usage 100) 1}}%"
+---    ```
+----->
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Widget.tooltip-text.html)
+---
+---@field tooltip string | ReactiveString | fun(args: table): string?
+---
 ---Sets the vertical alignment of `widget`. Defaults to `'Fill'`.
 ---
 ---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Widget.valign.html)
@@ -193,6 +256,21 @@ function base_instance:get_size_request() end
 ---@return nil
 function base_instance:set_size_request(size) end
 
+---Gets the tooltip text, or template for components with a `format`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.Widget.get_tooltip_text.html)
+---
+---@return string | nil
+function base_instance:get_tooltip() end
+
+---Sets the tooltip text, or template for components with a `format`. Setting `nil` removes the tooltip.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.Widget.set_tooltip_text.html)
+---
+---@param tooltip string?
+---@return nil
+function base_instance:set_tooltip(tooltip) end
+
 ---Gets the vertical alignment of `widget`.
 ---
 ---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.Widget.get_valign.html)
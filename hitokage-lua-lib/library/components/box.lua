@@ -2,7 +2,7 @@
 
 ---Any native component within `hitokage`.
 ---
----@alias Component Battery | Box | Clock | Cpu | Icon | Label | Memory | Weather | Workspace
+---@alias Component Battery | Box | Button | Clock | Cpu | Disk | Graph | Icon | KomorebiLayout | Label | Level | Media | Memory | Network | Revealer | Scale | Stack | SystemInfo | Taskbar | Temperature | Weather | WindowTitle | Workspace
 
 ---An array of any native components props within `hitokage`.
 ---
@@ -10,18 +10,38 @@
 ---
 ---* <a href="/hitokage/api/WrapBatteryProps" title="WrapBatteryProps">`WrapBatteryProps`</a>
 ---* <a href="/hitokage/api/WrapBoxProps" title="WrapBoxProps">`WrapBoxProps`</a>
+---* <a href="/hitokage/api/WrapButtonProps" title="WrapButtonProps">`WrapButtonProps`</a>
 ---* <a href="/hitokage/api/WrapClockProps" title="WrapClockProps">`WrapClockProps`</a>
 ---* <a href="/hitokage/api/WrapCpuProps" title="WrapCpuProps">`WrapCpuProps`</a>
+---* <a href="/hitokage/api/WrapDiskProps" title="WrapDiskProps">`WrapDiskProps`</a>
+---* <a href="/hitokage/api/WrapGraphProps" title="WrapGraphProps">`WrapGraphProps`</a>
 ---* <a href="/hitokage/api/WrapIconProps" title="WrapIconProps">`WrapIconProps`</a>
+---* <a href="/hitokage/api/WrapKomorebiLayoutProps" title="WrapKomorebiLayoutProps">`WrapKomorebiLayoutProps`</a>
 ---* <a href="/hitokage/api/WrapIconProps" title="WrapLabelProps">`WrapLabelProps`</a>
+---* <a href="/hitokage/api/WrapLevelProps" title="WrapLevelProps">`WrapLevelProps`</a>
+---* <a href="/hitokage/api/WrapMediaProps" title="WrapMediaProps">`WrapMediaProps`</a>
 ---* <a href="/hitokage/api/WrapIconProps" title="WrapMemoryProps">`WrapMemoryProps`</a>
+---* <a href="/hitokage/api/WrapNetworkProps" title="WrapNetworkProps">`WrapNetworkProps`</a>
+---* <a href="/hitokage/api/WrapRevealerProps" title="WrapRevealerProps">`WrapRevealerProps`</a>
+---* <a href="/hitokage/api/WrapScaleProps" title="WrapScaleProps">`WrapScaleProps`</a>
+---* <a href="/hitokage/api/WrapStackProps" title="WrapStackProps">`WrapStackProps`</a>
+---* <a href="/hitokage/api/WrapSystemInfoProps" title="WrapSystemInfoProps">`WrapSystemInfoProps`</a>
+---* <a href="/hitokage/api/WrapTaskbarProps" title="WrapTaskbarProps">`WrapTaskbarProps`</a>
+---* <a href="/hitokage/api/WrapTemperatureProps" title="WrapTemperatureProps">`WrapTemperatureProps`</a>
 ---* <a href="/hitokage/api/WrapIconProps" title="WrapWeatherProps">`WrapWeatherProps`</a>
+---* <a href="/hitokage/api/WrapWindowTitleProps" title="WrapWindowTitleProps">`WrapWindowTitleProps`</a>
 ---* <a href="/hitokage/api/WrapIconProps" title="WrapWorkspaceProps">`WrapWorkspaceProps`</a>
 ---
 ---Used in the following:
 ---
 ---* <a href="/hitokage/api/BarProps#attr-children" title="BarProps#attr-children">`BarProps.children`</a>
+---* <a href="/hitokage/api/BarProps#attr-start" title="BarProps#attr-start">`BarProps.start`</a>
+---* <a href="/hitokage/api/BarProps#attr-center" title="BarProps#attr-center">`BarProps.center`</a>
+---* <a href="/hitokage/api/BarProps#attr-end" title="BarProps#attr-end">`BarProps.end`</a>
 ---* <a href="/hitokage/api/WrapBoxProps/BoxProps#attr-children" title="BoxProps#attr-children">`BoxProps.children`</a>
+---* <a href="/hitokage/api/WrapRevealerProps/RevealerProps#attr-children" title="RevealerProps#attr-children">`RevealerProps.children`</a>
+---* <a href="/hitokage/api/WrapRevealerProps/RevealerProps#attr-trigger" title="RevealerProps#attr-trigger">`RevealerProps.trigger`</a>
+---* <a href="/hitokage/api/WrapStackProps/StackProps#attr-children" title="StackProps#attr-children">`StackProps.children`</a>
 ---
 ----->
 ---
@@ -39,7 +59,7 @@
 ---    }
 ---    ```
 ---
----@alias ComponentProps table<number, WrapBatteryProps | WrapBoxProps | WrapClockProps | WrapCpuProps | WrapIconProps | WrapLabelProps | WrapMemoryProps | WrapWeatherProps | WrapWorkspaceProps>?
+---@alias ComponentProps table<number, WrapBatteryProps | WrapBoxProps | WrapButtonProps | WrapClockProps | WrapCpuProps | WrapDiskProps | WrapGraphProps | WrapIconProps | WrapKomorebiLayoutProps | WrapLabelProps | WrapLevelProps | WrapMediaProps | WrapMemoryProps | WrapNetworkProps | WrapRevealerProps | WrapScaleProps | WrapStackProps | WrapSystemInfoProps | WrapTaskbarProps | WrapTemperatureProps | WrapWeatherProps | WrapWindowTitleProps | WrapWorkspaceProps>?
 
 --------------------------------------------------------------------------------
 ---Links to BoxProps in 'hitokage-core\src\components\box.rs'
@@ -117,6 +137,12 @@
 ---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Box.homogeneous.html)
 ---
 ---@field homogeneous boolean?
+---
+---The direction children are laid out in. Defaults to `'Horizontal'`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Orientable.orientation.html)
+---
+---@field orientation Orientation?
 
 --------------------------------------------------------------------------------
 ---Links to BoxUserData hitokage-lua\src\components\box.rs
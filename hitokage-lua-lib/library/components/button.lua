@@ -0,0 +1,60 @@
+---@meta hitokage.components.button
+
+--------------------------------------------------------------------------------
+---Links to ButtonProps in 'hitokage-core\src\components\button.rs'
+
+---@class ButtonProps : BaseProps
+---
+---The contents of the button.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Button.label.html)
+---
+---@field label string | ReactiveString
+---
+---Called when the button is clicked with the middle mouse button, instead of `on_click`.
+---@field on_middle_click fun(ctx: EventContext)?
+---
+---Called when the button is clicked with the secondary mouse button, instead of `on_click`.
+---@field on_right_click fun(ctx: EventContext)?
+---
+---Called when scrolling up over the button.
+---@field on_scroll_up fun(ctx: EventContext)?
+---
+---Called when scrolling down over the button.
+---@field on_scroll_down fun(ctx: EventContext)?
+---
+---Called when the pointer enters the button.
+---@field on_hover fun(ctx: EventContext)?
+
+--------------------------------------------------------------------------------
+---Links to ButtonUserData hitokage-lua\src\components\button.rs
+
+---@class Button : Base
+---
+---@field type 'Button'
+local button_instance = {}
+
+---Get the type of widget
+---@return 'Button'
+function button_instance:get_type() end
+
+---Fetches the text from a button.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.Button.get_label.html)
+---
+---@return string
+function button_instance:get_label() end
+
+---Fetches the reactive text from a button.
+---
+---@return string
+---@nodiscard
+function button_instance:get_label_reactive() end
+
+---Sets the text of the button.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.Button.set_label.html)
+---
+---@param string string
+---@return nil
+function button_instance:set_label(string) end
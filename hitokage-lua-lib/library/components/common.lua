@@ -11,25 +11,67 @@
 ---@class WrapBoxProps
 ---@field Box BoxProps
 ---
+---@class WrapButtonProps
+---@field Button ButtonProps
+---
 ---@class WrapClockProps
 ---@field Clock ClockProps
 ---
 ---@class WrapCpuProps
 ---@field Cpu CpuProps
 ---
+---@class WrapDiskProps
+---@field Disk DiskProps
+---
+---@class WrapGraphProps
+---@field Graph GraphProps
+---
 ---@class WrapIconProps
 ---@field Icon IconProps
 ---
+---@class WrapKomorebiLayoutProps
+---@field KomorebiLayout KomorebiLayoutProps
+---
 ---@class WrapLabelProps
 ---@field Label LabelProps
 ---
+---@class WrapLevelProps
+---@field Level LevelProps
+---
+---@class WrapMediaProps
+---@field Media MediaProps
+---
 ---@class WrapMemoryProps
 ---@field Memory MemoryProps
 ---
+---@class WrapNetworkProps
+---@field Network NetworkProps
+---
+---@class WrapRevealerProps
+---@field Revealer RevealerProps
+---
+---@class WrapScaleProps
+---@field Scale ScaleProps
+---
+---@class WrapStackProps
+---@field Stack StackProps
+---
+---@class WrapSystemInfoProps
+---@field SystemInfo SystemInfoProps
+---
+---@class WrapTaskbarProps
+---@field Taskbar TaskbarProps
+---
+---@class WrapTemperatureProps
+---@field Temperature TemperatureProps
+---
 ---@class WrapWeatherProps
 ---@field Weather WeatherProps
 ---
+---@class WrapWindowTitleProps
+---@field WindowTitle WindowTitleProps
+---
 ---@class WrapWorkspaceProps
 ---@field Workspace WorkspaceProps
 
----@alias WrapComponentProps WrapBatteryProps | WrapBoxProps | WrapClockProps | WrapCpuProps | WrapIconProps | WrapLabelProps | WrapMemoryProps | WrapWeatherProps | WrapWorkspaceProps>
+---@alias WrapComponentProps WrapBatteryProps | WrapBoxProps | WrapButtonProps | WrapClockProps | WrapCpuProps | WrapDiskProps | WrapGraphProps | WrapIconProps | WrapKomorebiLayoutProps | WrapLabelProps | WrapLevelProps | WrapMediaProps | WrapMemoryProps | WrapNetworkProps | WrapRevealerProps | WrapScaleProps | WrapStackProps | WrapSystemInfoProps | WrapTaskbarProps | WrapTemperatureProps | WrapWeatherProps | WrapWindowTitleProps | WrapWorkspaceProps>
@@ -0,0 +1,147 @@
+---@meta hitokage.components.disk
+
+--------------------------------------------------------------------------------
+---Links to DiskProps in 'hitokage-core\src\components\disk.rs'
+
+---A native component within `hitokage` that displays current disk usage.
+---
+---Adds the css classes `usage-10` through `usage-100` based on the combined usage of all mounts, and
+---`mount0-usage-10` through `mount0-usage-100` for each individual mount.
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    local disk_str = '{{round used 1}}/{{round total 1}} GiB ({{round percent 0}}%)'
+---
+---    ---@type DiskProps
+---    disk_props = { format = disk_str, mount = { "C:\\", "D:\\" } }
+---
+---    monitor:attach({
+---      children = {
+---        Disk = disk_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`Disk`](lua://Disk)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/Disk" title="Disk">`Disk`</a> -->
+---
+---@class DiskProps : BaseProps
+---
+---A handlebars template string or function accepts DiskInfo and returns a handlebars template string
+---
+---@field format string | ReactiveString | fun(DiskInfo: DiskInfo): string
+---
+---The mount path or list of mount paths to display. Defaults to `"C:\"` on Windows and `"/"` elsewhere.
+---
+---@field mount string | string[] | nil
+
+--------------------------------------------------------------------------------
+---Links to DiskUserData hitokage-lua\src\components\disk.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`DiskProps`](lua://DiskProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapDiskProps/DiskProps" title="DiskProps">`DiskProps`</a> -->
+---
+---> A native component within `hitokage` that displays current disk usage.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type DiskProps
+---    disk_props = { id = "disk1", format = "{{round percent 0}}%" }
+---
+---    bar = monitor:attach({
+---      children = {
+---        Disk = disk_props,
+---      },
+---    })
+---
+---    disk1 = bar:get_child_by_id("disk1")
+---    disk1:set_mounts("C:\\", "D:\\")
+---    ```
+---
+---@class Disk : Base
+---
+---@field type 'Disk'
+local disk_instance = {}
+
+---Get the type of widget
+---@return 'Disk'
+function disk_instance:get_type() end
+
+---Get the format string
+---@return string
+function disk_instance:get_format() end
+
+---Get the reactive format string
+---@return ReactiveString
+---@nodiscard
+function disk_instance:get_format_reactive() end
+
+---Set the format string
+---@param string string
+---@return nil
+function disk_instance:set_format(string) end
+
+---Get the displayed mount paths
+---@return string[]
+function disk_instance:get_mounts() end
+
+---Set the displayed mount paths
+---@param ... string | string[]
+---@return nil
+function disk_instance:set_mounts(...) end
+
+--------------------------------------------------------------------------------
+---Links to DiskInfo hitokage-core\src\components\disk.rs
+
+---Sizes are in GiB, and `percent` ranges from `0` to `100`. The top level fields are the sum of all mounts.
+---
+---`avail` is the free space usable without root, which is less than `free` on filesystems that reserve blocks for
+---root.
+---
+---@class DiskInfo
+---
+---@field used number
+---@field free number
+---@field avail number
+---@field total number
+---@field percent number
+---@field mounts DiskMountInfo[]
+
+---@class DiskMountInfo
+---
+---@field mount string
+---@field name string
+---@field fs_type string
+---@field used number
+---@field free number
+---@field avail number
+---@field total number
+---@field percent number
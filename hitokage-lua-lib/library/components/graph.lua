@@ -0,0 +1,130 @@
+---@meta hitokage.components.graph
+
+--------------------------------------------------------------------------------
+---Links to GraphProps in 'hitokage-core\src\components\graph.rs'
+
+---A native component within `hitokage` that draws a rolling history of a numeric series.
+---
+---The line is drawn with the css `color`, and the area beneath it with the same color at a quarter of the opacity.
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type GraphProps
+---    graph_props = { source = "Cpu", length = 30, width_request = 60 }
+---
+---    monitor:attach({
+---      children = {
+---        Graph = graph_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`Graph`](lua://Graph)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/Graph" title="Graph">`Graph`</a> -->
+---
+---@class GraphProps : BaseProps
+---
+---Where samples are read from. `Cpu` and `Memory` are percentages, `NetworkRx` and `NetworkTx` are KiB/s on the default
+---interface. A reactive must hold a number, ie. `reactive:set(tostring(value))`.
+---
+---@field source GraphSource
+---
+---The number of samples to keep. Defaults to `60`.
+---
+---@field length integer?
+---
+---The sample interval in milliseconds. Defaults to `1000`.
+---
+---@field interval integer?
+---
+---The value drawn at the bottom of the graph. Defaults to `0`.
+---
+---@field min number?
+---
+---The value drawn at the top of the graph. Defaults to `100` for `Cpu` and `Memory`, otherwise the largest sample.
+---
+---@field max number?
+
+---@alias GraphSource 'Cpu' | 'Memory' | 'NetworkRx' | 'NetworkTx' | ReactiveString
+
+--------------------------------------------------------------------------------
+---Links to GraphUserData hitokage-lua\src\components\graph.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`GraphProps`](lua://GraphProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapGraphProps/GraphProps" title="GraphProps">`GraphProps`</a> -->
+---
+---> A native component within `hitokage` that draws a rolling history of a numeric series.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type GraphProps
+---    graph_props = { id = "graph1", source = "NetworkRx" }
+---
+---    bar = monitor:attach({
+---      children = {
+---        Graph = graph_props,
+---      },
+---    })
+---
+---    graph1 = bar:get_child_by_id("graph1")
+---    graph1:set_max(1024)
+---    ```
+---
+---@class Graph : Base
+---
+---@field type 'Graph'
+local graph_instance = {}
+
+---Get the type of widget
+---@return 'Graph'
+function graph_instance:get_type() end
+
+---Get the samples currently in the history, oldest first
+---@return number[]
+function graph_instance:get_samples() end
+
+---Get the value drawn at the bottom of the graph
+---@return number
+function graph_instance:get_min() end
+
+---Set the value drawn at the bottom of the graph, `nil` resets it to `0`
+---@param min number?
+---@return nil
+function graph_instance:set_min(min) end
+
+---Get the value drawn at the top of the graph, `nil` if it scales to the largest sample
+---@return number?
+function graph_instance:get_max() end
+
+---Set the value drawn at the top of the graph, `nil` scales to the largest sample
+---@param max number?
+---@return nil
+function graph_instance:set_max(max) end
@@ -0,0 +1,150 @@
+---@meta hitokage.components.komorebi_layout
+
+--------------------------------------------------------------------------------
+---Links to KomorebiLayoutProps in 'hitokage-core\src\components\komorebi_layout.rs'
+
+---A native component within `hitokage` that displays the layout of the focused workspace on the bar's monitor.
+---Clicking or scrolling cycles through the layouts, right clicking cycles backwards.
+---
+---This is in the particular context of running `hitokage` with a window/tiling manager:
+---
+---* [`komorebi`](https://github.com/LGUG2Z/komorebi)
+---* [TODO (not supported) `glazewm`](https://github.com/glzr-io/glazewm)
+---
+---The css classes `paused`, `monocle`, `maximized` and `floating` are added while the mode is active.
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type KomorebiLayoutProps
+---    layout_props = {
+---      format = "{{#if monocle}}[M] {{/if}}{{layout}}",
+---      scroll = false,
+---    }
+---
+---    monitor:attach({
+---      children = {
+---        KomorebiLayout = layout_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`KomorebiLayout`](lua://KomorebiLayout)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/KomorebiLayout" title="KomorebiLayout">`KomorebiLayout`</a> -->
+---
+---@class KomorebiLayoutProps : BaseProps
+---
+---A handlebars template string. Defaults to `{{mode}}`.
+---
+---See <!--@mkdocs-ignore-start-->[`KomorebiLayoutInfo`](lua://KomorebiLayoutInfo)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/KomorebiLayoutInfo" title="KomorebiLayoutInfo">`KomorebiLayoutInfo`</a> -->
+---for the available arguments, these are also passed to the `tooltip`.
+---
+---@field format string?
+---
+---Cycle layouts when clicked. Defaults to `true`.
+---
+---@field click boolean?
+---
+---Cycle layouts when scrolled over. Defaults to `true`.
+---
+---@field scroll boolean?
+
+---The arguments available to the `format` of a <!--@mkdocs-ignore-start-->[`KomorebiLayoutProps`](lua://KomorebiLayoutProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapKomorebiLayoutProps/KomorebiLayoutProps" title="KomorebiLayoutProps">`KomorebiLayoutProps`</a> -->
+---@class KomorebiLayoutInfo
+---
+---The layout of the workspace, ie. `BSP`, `Columns`, `Rows`, `VerticalStack` or `Custom`.
+---@field layout string
+---
+---The layout, unless overridden by `Paused`, `Monocle`, `Maximized` or `Float` in that order.
+---@field mode string
+---
+---@field monocle boolean
+---@field maximized boolean
+---@field paused boolean
+---
+---Whether tiling is disabled on the workspace.
+---@field floating boolean
+
+--------------------------------------------------------------------------------
+---Links to KomorebiLayoutUserData hitokage-lua\src\components\komorebi_layout.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`KomorebiLayoutProps`](lua://KomorebiLayoutProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapKomorebiLayoutProps/KomorebiLayoutProps" title="KomorebiLayoutProps">`KomorebiLayoutProps`</a> -->
+---
+---> A native component within `hitokage` that displays the layout of the focused workspace on the bar's monitor.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type KomorebiLayoutProps
+---    layout_props = { id = "layout1", click = false }
+---
+---    bar = monitor:attach({
+---      children = {
+---        KomorebiLayout = layout_props,
+---      },
+---    })
+---
+---    layout1 = bar:get_child_by_id("layout1")
+---    if layout1:get_layout() ~= "BSP" then
+---      layout1:next()
+---    end
+---    ```
+---
+---@class KomorebiLayout : Base
+---
+---@field type 'KomorebiLayout'
+local komorebi_layout_instance = {}
+
+---Get the type of widget
+---@return 'KomorebiLayout'
+function komorebi_layout_instance:get_type() end
+
+---Get the format string
+---@return string
+function komorebi_layout_instance:get_format() end
+
+---Set the format string
+---@param format string
+---@return nil
+function komorebi_layout_instance:set_format(format) end
+
+---Get the layout of the focused workspace, `nil` if komorebi has not reported one yet
+---@return string | nil
+function komorebi_layout_instance:get_layout() end
+
+---Change the focused workspace to the next layout
+---@return nil
+function komorebi_layout_instance:next() end
+
+---Change the focused workspace to the previous layout
+---@return nil
+function komorebi_layout_instance:previous() end
@@ -0,0 +1,138 @@
+---@meta hitokage.components.level
+
+--------------------------------------------------------------------------------
+---Links to LevelProps in 'hitokage-core\src\components\level.rs'
+
+---A native component within `hitokage` that displays a value as a bar.
+---
+---Offsets add css classes to the filled block, ie. `block.filled.low`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/class.LevelBar.html)
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type LevelProps
+---    level_props = { value = "Battery", offsets = { low = 20, high = 80, full = 100 }, width_request = 60 }
+---
+---    monitor:attach({
+---      children = {
+---        Level = level_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`Level`](lua://Level)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/Level" title="Level">`Level`</a> -->
+---
+---@class LevelProps : BaseProps
+---
+---Where the value is read from. `Battery`, `Cpu` and `Memory` are percentages. A reactive must hold a number, ie.
+---`reactive:set(tostring(value))`.
+---
+---@field value LevelValue
+---
+---Defaults to `0`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.LevelBar.min-value.html)
+---
+---@field min number?
+---
+---Defaults to `100` for `Battery`, `Cpu` and `Memory`, otherwise `1`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.LevelBar.max-value.html)
+---
+---@field max number?
+---
+---Named offsets used for css styling. Defaults to `low`, `high` and `full` at 25%, 75% and 100% of the range.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.LevelBar.add_offset_value.html)
+---
+---@field offsets table<string, number>?
+---
+---The refresh interval in milliseconds. Defaults to `1000`.
+---
+---@field interval integer?
+
+---@alias LevelValue 'Battery' | 'Cpu' | 'Memory' | ReactiveString
+
+--------------------------------------------------------------------------------
+---Links to LevelUserData hitokage-lua\src\components\level.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`LevelProps`](lua://LevelProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapLevelProps/LevelProps" title="LevelProps">`LevelProps`</a> -->
+---
+---> A native component within `hitokage` that displays a value as a bar.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type LevelProps
+---    level_props = { id = "level1", value = "Memory" }
+---
+---    bar = monitor:attach({
+---      children = {
+---        Level = level_props,
+---      },
+---    })
+---
+---    level1 = bar:get_child_by_id("level1")
+---    print(level1:get_value())
+---    ```
+---
+---@class Level : Base
+---
+---@field type 'Level'
+local level_instance = {}
+
+---Get the type of widget
+---@return 'Level'
+function level_instance:get_type() end
+
+---Get the current value
+---@return number
+function level_instance:get_value() end
+
+---Get the minimum value
+---@return number
+function level_instance:get_min() end
+
+---Set the minimum value
+---@param min number
+---@return nil
+function level_instance:set_min(min) end
+
+---Get the maximum value
+---@return number
+function level_instance:get_max() end
+
+---Set the maximum value
+---@param max number
+---@return nil
+function level_instance:set_max(max) end
@@ -0,0 +1,154 @@
+---@meta hitokage.components.media
+
+--------------------------------------------------------------------------------
+---Links to MediaProps in 'hitokage-core\src\components\media.rs'
+
+---A native component within `hitokage` that displays the currently playing media, with previous, play/pause and
+---next controls.
+---
+---Media information comes from the media transport controls on Windows, and from MPRIS on Linux.
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type MediaProps
+---    media_props = { format = "{{artist}} - {{title}}" }
+---
+---    monitor:attach({
+---      children = {
+---        Media = media_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`Media`](lua://Media)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/Media" title="Media">`Media`</a> -->
+---
+---@class MediaProps : BaseProps
+---
+---A handlebars template string or function accepts MediaInfo and returns a handlebars template string
+---
+---@field format string | ReactiveString | fun(MediaInfo: MediaInfo): string
+---
+---The player to display, ie. `"Spotify"`. On Windows this matches part of the app id. Defaults to the most active player.
+---
+---@field player string?
+---
+---Whether to show the previous, play/pause and next buttons. Defaults to `true`.
+---
+---@field controls boolean?
+---
+---@field icons MediaIcons?
+
+---@class MediaIcons
+---@field play string?
+---@field pause string?
+---@field next string?
+---@field previous string?
+
+--------------------------------------------------------------------------------
+---Links to MediaUserData hitokage-lua\src\components\media.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`MediaProps`](lua://MediaProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapMediaProps/MediaProps" title="MediaProps">`MediaProps`</a> -->
+---
+---> A native component within `hitokage` that displays the currently playing media.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type MediaProps
+---    media_props = { id = "media1", format = "{{title}}", controls = false }
+---
+---    bar = monitor:attach({
+---      children = {
+---        Media = media_props,
+---      },
+---    })
+---
+---    media1 = bar:get_child_by_id("media1")
+---    media1:play_pause()
+---    ```
+---
+---@class Media : Base
+---
+---@field type 'Media'
+local media_instance = {}
+
+---Get the type of widget
+---@return 'Media'
+function media_instance:get_type() end
+
+---Get the format string
+---@return string
+function media_instance:get_format() end
+
+---Get the reactive format string
+---@return ReactiveString
+---@nodiscard
+function media_instance:get_format_reactive() end
+
+---Set the format string
+---@param string string
+---@return nil
+function media_instance:set_format(string) end
+
+---Get whether the playback controls are shown
+---@return boolean
+function media_instance:get_controls() end
+
+---Set whether the playback controls are shown
+---@param controls boolean
+---@return nil
+function media_instance:set_controls(controls) end
+
+---Toggle playback of the player
+---@return nil
+function media_instance:play_pause() end
+
+---Skip to the next track
+---@return nil
+function media_instance:next() end
+
+---Skip to the previous track
+---@return nil
+function media_instance:previous() end
+
+--------------------------------------------------------------------------------
+---Links to MediaInfo hitokage-core\src\components\media.rs
+
+---@class MediaInfo
+---
+---@field player string
+---@field title string
+---@field artist string Artists joined by `", "`
+---@field album string
+---@field status 'Playing' | 'Paused' | 'Stopped'
+---@field position number Position in seconds
+---@field length number Length in seconds, `0` if unknown
@@ -0,0 +1,128 @@
+---@meta hitokage.components.network
+
+--------------------------------------------------------------------------------
+---Links to NetworkProps in 'hitokage-core\src\components\network.rs'
+
+---A native component within `hitokage` that displays current network throughput.
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    local net_str = '{{interface}} ↓{{pad "left" (round rx_rate 0) 5}}K ↑{{pad "left" (round tx_rate 0) 5}}K'
+---
+---    ---@type NetworkProps
+---    network_props = { format = net_str }
+---
+---    monitor:attach({
+---      children = {
+---        Network = network_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`Network`](lua://Network)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/Network" title="Network">`Network`</a> -->
+---
+---@class NetworkProps : BaseProps
+---
+---A handlebars template string or function accepts NetworkInfo and returns a handlebars template string
+---
+---@field format string | ReactiveString | fun(NetworkInfo: NetworkInfo): string
+---
+---The interface to sample, ie. `"eth0"` or `"Wi-Fi"`. Defaults to the first interface with a non-loopback ipv4 address.
+---
+---@field interface string?
+
+--------------------------------------------------------------------------------
+---Links to NetworkUserData hitokage-lua\src\components\network.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`NetworkProps`](lua://NetworkProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapNetworkProps/NetworkProps" title="NetworkProps">`NetworkProps`</a> -->
+---
+---> A native component within `hitokage` that displays current network throughput.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type NetworkProps
+---    network_props = { id = "network1", format = "{{interface}} {{ip}}" }
+---
+---    bar = monitor:attach({
+---      children = {
+---        Network = network_props,
+---      },
+---    })
+---
+---    network1 = bar:get_child_by_id("network1")
+---    network1:set_interface("eth0")
+---    ```
+---
+---@class Network : Base
+---
+---@field type 'Network'
+local network_instance = {}
+
+---Get the type of widget
+---@return 'Network'
+function network_instance:get_type() end
+
+---Get the format string
+---@return string
+function network_instance:get_format() end
+
+---Get the reactive format string
+---@return ReactiveString
+---@nodiscard
+function network_instance:get_format_reactive() end
+
+---Set the format string
+---@param string string
+---@return nil
+function network_instance:set_format(string) end
+
+---Get the sampled interface, `nil` if the default interface is used
+---@return string?
+function network_instance:get_interface() end
+
+---Set the sampled interface, `nil` to use the default interface
+---@param interface string?
+---@return nil
+function network_instance:set_interface(interface) end
+
+--------------------------------------------------------------------------------
+---Links to NetworkInfo hitokage-core\src\components\network.rs
+
+---@class NetworkInfo
+---
+---@field interface string
+---@field ip string
+---@field rx_rate number Received KiB/s
+---@field tx_rate number Transmitted KiB/s
+---@field rx_total number Total received MiB
+---@field tx_total number Total transmitted MiB
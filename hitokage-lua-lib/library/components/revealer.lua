@@ -0,0 +1,204 @@
+---@meta hitokage.components.revealer
+
+--------------------------------------------------------------------------------
+---Links to RevealerProps in 'hitokage-core\src\components\revealer.rs'
+
+---A native component within `hitokage` that can hold other components, and slides them in or out of view.
+---
+---The `trigger` children are always shown, while the `children` are revealed on hover, on click, or from lua.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/class.Revealer.html)
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type RevealerProps
+---    revealer_props = {
+---      id = "stats",
+---      trigger = {
+---        { Label = { label = "stats" } },
+---      },
+---      children = {
+---        { Memory = { format = mem_str } },
+---        { Network = { format = net_str } },
+---      },
+---      transition_type = "SlideRight",
+---    }
+---
+---    monitor:attach({
+---      children = {
+---        Revealer = revealer_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`Revealer`](lua://Revealer)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/Revealer" title="Revealer">`Revealer`</a> -->
+---
+---@class RevealerProps : BaseProps
+---
+---<!--@mkdocs-include An arrray of children which are revealed. See <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a>-->
+---
+---@field children ComponentProps
+---
+---<!--@mkdocs-include An arrray of children which are always shown, placed before the revealed children. See <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a>-->
+---
+---@field trigger ComponentProps
+---
+---Whether the revealed children should all be the same size.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Box.homogeneous.html)
+---
+---@field homogeneous boolean?
+---
+---The direction the trigger and revealed children are laid out in. Defaults to `'Horizontal'`.
+---
+---Pair `'Vertical'` with a `'SlideDown'` or `'SlideUp'` `transition_type`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Orientable.orientation.html)
+---
+---@field orientation Orientation?
+---
+---Whether the children start revealed. Defaults to `false`.
+---
+---A reactive must hold `"true"` or `"false"`. It is updated whenever the revealer opens or closes, and flipping it
+---opens or closes the revealer.
+---
+---@field revealed RevealedValue?
+---
+---What opens the revealer. Defaults to `'Hover'`.
+---
+---* `'Hover'` opens while the pointer is over the revealer
+---* `'Click'` toggles when the revealer is clicked
+---* `'Manual'` only opens from lua or with `revealed`
+---
+---Without `trigger` children a closed revealer would have no size to hover or click, so for `'Hover'` and `'Click'`
+---it keeps a hit area 8 pixels wide unless `width_request` is set. When `orientation` is `'Vertical'` the hit area is
+---8 pixels tall instead, unless `height_request` is set.
+---
+---@field reveal_on 'Hover' | 'Click' | 'Manual' | nil
+---
+---The animation used when revealing. Defaults to `'SlideRight'`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/enum.RevealerTransitionType.html)
+---
+---@field transition_type 'None' | 'Crossfade' | 'SlideRight' | 'SlideLeft' | 'SlideUp' | 'SlideDown' | nil
+---
+---The animation duration in milliseconds. Defaults to `250`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Revealer.transition-duration.html)
+---
+---@field transition_duration integer?
+
+---@alias RevealedValue boolean | ReactiveString
+
+--------------------------------------------------------------------------------
+---Links to RevealerUserData hitokage-lua\src\components\revealer.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`RevealerProps`](lua://RevealerProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapRevealerProps/RevealerProps" title="RevealerProps">`RevealerProps`</a> -->
+---
+---> A native component within `hitokage` that can hold other components, and slides them in or out of view.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type RevealerProps
+---    revealer_props = { id = "revealer1", reveal_on = "Manual", children = { ... } }
+---
+---    bar = monitor:attach({
+---      children = {
+---        Revealer = revealer_props,
+---      },
+---    })
+---
+---    revealer1 = bar:get_child_by_id("revealer1")
+---    revealer1:toggle()
+---    ```
+---
+---@class Revealer : Base
+---
+---@field type 'Revealer'
+---@field revealed boolean
+local revealer_instance = {}
+
+---Get the type of widget
+---@return 'Revealer'
+function revealer_instance:get_type() end
+
+---Returns whether the revealed children are homogeneous (all children are the same size).
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.Box.get_homogeneous.html)
+---
+---@return boolean
+function revealer_instance:get_homogeneous() end
+
+---Sets whether or not all revealed children are given equal space.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.Box.set_homogeneous.html)
+---
+---@param homogeneous boolean
+---@return nil
+function revealer_instance:set_homogeneous(homogeneous) end
+
+---Get the children on the revealer, starting with the `trigger` children.
+---@return table<number, Component> # A table of Components
+function revealer_instance:get_children() end
+
+---Gets the first item in the widget tree that has the identifier.
+---
+---When `recursive` is set to `true` the search is performed breadth-first,
+---then in order of components on the tree.
+---
+---@param id string The identifier
+---@param recursive boolean? Defaults to `false`
+---@return Component? # A Component
+function revealer_instance:get_child_by_id(id, recursive) end
+
+---Get whether the children are revealed
+---@return boolean
+function revealer_instance:get_revealed() end
+
+---Reveal or hide the children
+---@param revealed boolean
+---@return nil
+function revealer_instance:set_revealed(revealed) end
+
+---Reveal the children if they are hidden, otherwise hide them
+---@return nil
+function revealer_instance:toggle() end
+
+---Get the transition duration in milliseconds
+---@return integer
+function revealer_instance:get_transition_duration() end
+
+---Set the transition duration in milliseconds
+---@param duration integer
+---@return nil
+function revealer_instance:set_transition_duration(duration) end
@@ -0,0 +1,168 @@
+---@meta hitokage.components.scale
+
+--------------------------------------------------------------------------------
+---Links to ScaleProps in 'hitokage-core\src\components\scale.rs'
+
+---A native component within `hitokage` that lets the user pick a value by dragging a slider.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/class.Scale.html)
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    volume = hitokage.reactive.create("50")
+---
+---    ---@type ScaleProps
+---    scale_props = {
+---      value = volume,
+---      min = 0,
+---      max = 100,
+---      step = 5,
+---      width_request = 100,
+---      on_change = function(event)
+---        hitokage.debug("volume set to " .. event.value)
+---      end,
+---    }
+---
+---    monitor:attach({
+---      children = {
+---        Scale = scale_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`Scale`](lua://Scale)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/Scale" title="Scale">`Scale`</a> -->
+---
+---@class ScaleProps : BaseProps
+---
+---The initial value, defaults to `min`. A reactive must hold a number, ie. `reactive:set(tostring(value))`, and is
+---updated whenever the user moves the slider.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.Range.set_value.html)
+---
+---@field value ScaleValue?
+---
+---Defaults to `0`.
+---
+---@field min number?
+---
+---Defaults to `100`.
+---
+---@field max number?
+---
+---The amount the value changes by with the arrow keys. Defaults to `1`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.Range.set_increments.html)
+---
+---@field step number?
+---
+---Called when the user changes the value. It is not called when the value is set from lua.
+---
+---@field on_change fun(event: ScaleChangeEvent)?
+
+---@alias ScaleValue number | ReactiveString
+
+---The event passed to `on_change`.
+---@class ScaleChangeEvent
+---
+---The id of the component, if one was provided.
+---@field id string | nil
+---
+---The new value.
+---@field value number
+
+--------------------------------------------------------------------------------
+---Links to ScaleUserData hitokage-lua\src\components\scale.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`ScaleProps`](lua://ScaleProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapScaleProps/ScaleProps" title="ScaleProps">`ScaleProps`</a> -->
+---
+---> A native component within `hitokage` that lets the user pick a value by dragging a slider.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type ScaleProps
+---    scale_props = { id = "scale1", value = 50 }
+---
+---    bar = monitor:attach({
+---      children = {
+---        Scale = scale_props,
+---      },
+---    })
+---
+---    scale1 = bar:get_child_by_id("scale1")
+---    scale1:set_value(scale1:get_value() + 10)
+---    ```
+---
+---@class Scale : Base
+---
+---@field type 'Scale'
+local scale_instance = {}
+
+---Get the type of widget
+---@return 'Scale'
+function scale_instance:get_type() end
+
+---Get the current value
+---@return number
+function scale_instance:get_value() end
+
+---Set the current value. This does not call `on_change`.
+---@param value number
+---@return nil
+function scale_instance:set_value(value) end
+
+---Get the minimum value
+---@return number
+function scale_instance:get_min() end
+
+---Set the minimum value
+---@param min number
+---@return nil
+function scale_instance:set_min(min) end
+
+---Get the maximum value
+---@return number
+function scale_instance:get_max() end
+
+---Set the maximum value
+---@param max number
+---@return nil
+function scale_instance:set_max(max) end
+
+---Get the step
+---@return number
+function scale_instance:get_step() end
+
+---Set the step
+---@param step number
+---@return nil
+function scale_instance:set_step(step) end
@@ -0,0 +1,158 @@
+---@meta hitokage.components.stack
+
+--------------------------------------------------------------------------------
+---Links to StackProps in 'hitokage-core\src\components\stack.rs'
+
+---A native component within `hitokage` that can hold other components, and shows one of them at a time.
+---
+---The visible child rotates on an interval, when scrolled over, or from lua.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/class.Stack.html)
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type StackProps
+---    stack_props = {
+---      interval = 5000,
+---      transition_type = "SlideUp",
+---      children = {
+---        { Weather = { id = "weather", format = weather_str } },
+---        { Battery = { id = "battery", format = battery_str } },
+---        { Network = { id = "network", format = net_str } },
+---      },
+---    }
+---
+---    monitor:attach({
+---      children = {
+---        Stack = stack_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`Stack`](lua://Stack)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/Stack" title="Stack">`Stack`</a> -->
+---
+---@class StackProps : BaseProps
+---
+---<!--@mkdocs-include An arrray of children, one of which is shown at a time. See <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a>-->
+---
+---@field children ComponentProps
+---
+---The `id` of the child shown first. Defaults to the first child.
+---
+---@field visible_child string?
+---
+---Rotate to the next child every `interval` milliseconds. Defaults to not rotating.
+---
+---@field interval integer?
+---
+---Whether scrolling over the stack rotates through the children. Defaults to `true`.
+---
+---@field scroll boolean?
+---
+---The animation used when changing children. Defaults to `'Crossfade'`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/enum.StackTransitionType.html)
+---
+---@field transition_type 'None' | 'Crossfade' | 'SlideRight' | 'SlideLeft' | 'SlideUp' | 'SlideDown' | 'SlideLeftRight' | 'SlideUpDown' | nil
+---
+---The animation duration in milliseconds. Defaults to `200`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Stack.transition-duration.html)
+---
+---@field transition_duration integer?
+
+--------------------------------------------------------------------------------
+---Links to StackUserData hitokage-lua\src\components\stack.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`StackProps`](lua://StackProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapStackProps/StackProps" title="StackProps">`StackProps`</a> -->
+---
+---> A native component within `hitokage` that can hold other components, and shows one of them at a time.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type StackProps
+---    stack_props = { id = "stack1", children = { ... } }
+---
+---    bar = monitor:attach({
+---      children = {
+---        Stack = stack_props,
+---      },
+---    })
+---
+---    stack1 = bar:get_child_by_id("stack1")
+---    stack1:set_visible_child("battery")
+---    ```
+---
+---@class Stack : Base
+---
+---@field type 'Stack'
+---@field visible_child string?
+local stack_instance = {}
+
+---Get the type of widget
+---@return 'Stack'
+function stack_instance:get_type() end
+
+---Get the children on the stack.
+---@return table<number, Component> # A table of Components
+function stack_instance:get_children() end
+
+---Gets the first item in the widget tree that has the identifier.
+---
+---When `recursive` is set to `true` the search is performed breadth-first,
+---then in order of components on the tree.
+---
+---@param id string The identifier
+---@param recursive boolean? Defaults to `false`
+---@return Component? # A Component
+function stack_instance:get_child_by_id(id, recursive) end
+
+---Get the `id` of the visible child, or `nil` if it has no `id`
+---@return string?
+function stack_instance:get_visible_child() end
+
+---Show the child with the `id`
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/method.Stack.set_visible_child.html)
+---
+---@param id string
+---@return nil
+function stack_instance:set_visible_child(id) end
+
+---Show the next child, wrapping around to the first
+---@return nil
+function stack_instance:next() end
+
+---Show the previous child, wrapping around to the last
+---@return nil
+function stack_instance:previous() end
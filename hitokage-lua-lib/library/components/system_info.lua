@@ -0,0 +1,137 @@
+---@meta hitokage.components.system_info
+
+--------------------------------------------------------------------------------
+---Links to SystemInfoProps in 'hitokage-core\src\components\system_info.rs'
+
+---A native component within `hitokage` that displays information about the host system, such as uptime or load average.
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type SystemInfoProps
+---    system_info_props = {
+---      format = "{{hostname}} up {{uptime_days}}d {{uptime_hours}}h {{uptime_minutes}}m",
+---      interval = 60000,
+---    }
+---
+---    monitor:attach({
+---      children = {
+---        SystemInfo = system_info_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`SystemInfo`](lua://SystemInfo)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/SystemInfo" title="SystemInfo">`SystemInfo`</a> -->
+---
+---@class SystemInfoProps : BaseProps
+---
+---A handlebars template string or function accepts SystemInfoData and returns a handlebars template string
+---
+---@field format string | ReactiveString | fun(SystemInfoData: SystemInfoData): string
+---
+---The refresh interval in milliseconds. Defaults to `1000`.
+---
+---@field interval number?
+
+--------------------------------------------------------------------------------
+---Links to SystemInfoUserData hitokage-lua\src\components\system_info.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`SystemInfoProps`](lua://SystemInfoProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapSystemInfoProps/SystemInfoProps" title="SystemInfoProps">`SystemInfoProps`</a> -->
+---
+---> A native component within `hitokage` that displays information about the host system, such as uptime or load average.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type SystemInfoProps
+---    system_info_props = { id = "system_info1", format = "{{os}} {{kernel}}" }
+---
+---    bar = monitor:attach({
+---      children = {
+---        SystemInfo = system_info_props,
+---      },
+---    })
+---
+---    system_info1 = bar:get_child_by_id("system_info1")
+---    system_info1:set_interval(5000)
+---    ```
+---
+---@class SystemInfo : Base
+---
+---@field type 'SystemInfo'
+local system_info_instance = {}
+
+---Get the type of widget
+---@return 'SystemInfo'
+function system_info_instance:get_type() end
+
+---Get the format string
+---@return string
+function system_info_instance:get_format() end
+
+---Get the reactive format string
+---@return ReactiveString
+---@nodiscard
+function system_info_instance:get_format_reactive() end
+
+---Set the format string
+---@param string string
+---@return nil
+function system_info_instance:set_format(string) end
+
+---Get the refresh interval in milliseconds
+---@return number
+function system_info_instance:get_interval() end
+
+---Set the refresh interval in milliseconds
+---@param interval number
+---@return nil
+function system_info_instance:set_interval(interval) end
+
+--------------------------------------------------------------------------------
+---Links to SystemInfoData hitokage-core\src\structs\system.rs
+
+---Load averages are always `0` on Windows.
+---
+---@class SystemInfoData
+---
+---@field uptime number Uptime in seconds
+---@field uptime_days number
+---@field uptime_hours number Hours past `uptime_days`
+---@field uptime_minutes number Minutes past `uptime_hours`
+---@field load1 number
+---@field load5 number
+---@field load15 number
+---@field hostname string
+---@field os string
+---@field kernel string
+---@field boot_time string Boot time in local time, formatted as `%Y-%m-%d %H:%M:%S`
+---@field boot_timestamp number Boot time as a unix timestamp
@@ -0,0 +1,162 @@
+---@meta hitokage.components.taskbar
+
+--------------------------------------------------------------------------------
+---Links to TaskbarProps in 'hitokage-core\src\components\taskbar.rs'
+
+---A native component within `hitokage` that lists the windows of the focused workspace on the bar's monitor.
+---Each item shows the window's icon and title, clicking an item focuses that window.
+---
+---This is in the particular context of running `hitokage` with a window/tiling manager:
+---
+---* [`komorebi`](https://github.com/LGUG2Z/komorebi)
+---* [TODO (not supported) `glazewm`](https://github.com/glzr-io/glazewm)
+---
+---Each item has the css class `taskbar_item`, and the focused window's item also has `focused`.
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type TaskbarProps
+---    taskbar_props = {
+---      max_length = 24,
+---      icon_size = 20,
+---    }
+---
+---    monitor:attach({
+---      children = {
+---        Taskbar = taskbar_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`Taskbar`](lua://Taskbar)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/Taskbar" title="Taskbar">`Taskbar`</a> -->
+---
+---@class TaskbarProps : BaseProps
+---
+---A handlebars template string used for each item. Defaults to `{{title}}`. An empty string only shows icons.
+---
+---See <!--@mkdocs-ignore-start-->[`TaskbarItem`](lua://TaskbarItem)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/TaskbarItem" title="TaskbarItem">`TaskbarItem`</a> -->
+---for the available arguments.
+---
+---@field format string?
+---
+---Truncates each item to this many characters. By default items are never truncated.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Label.max-width-chars.html)
+---
+---@field max_length integer?
+---
+---Where to place the ellipsis when an item is truncated. Defaults to `'End'`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Label.ellipsize.html)
+---
+---@field ellipsize EllipsizeMode?
+---
+---The size of each icon in pixels. Defaults to `16`.
+---
+---@field icon_size integer?
+
+---The arguments available to the `format` of a <!--@mkdocs-ignore-start-->[`TaskbarProps`](lua://TaskbarProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapTaskbarProps/TaskbarProps" title="TaskbarProps">`TaskbarProps`</a> -->
+---@class TaskbarItem
+---
+---The window handle.
+---@field hwnd integer
+---
+---@field title string
+---
+---The executable of the window, ie. `firefox.exe`.
+---@field exe string
+---
+---@field class string
+---
+---Whether this is the focused window.
+---@field focused boolean
+
+--------------------------------------------------------------------------------
+---Links to TaskbarUserData hitokage-lua\src\components\taskbar.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`TaskbarProps`](lua://TaskbarProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapTaskbarProps/TaskbarProps" title="TaskbarProps">`TaskbarProps`</a> -->
+---
+---> A native component within `hitokage` that lists the windows of the focused workspace on the bar's monitor.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type TaskbarProps
+---    taskbar_props = { id = "taskbar1" }
+---
+---    bar = monitor:attach({
+---      children = {
+---        Taskbar = taskbar_props,
+---      },
+---    })
+---
+---    taskbar1 = bar:get_child_by_id("taskbar1")
+---    taskbar1:set_format("")
+---    ```
+---
+---@class Taskbar : Base
+---
+---@field type 'Taskbar'
+local taskbar_instance = {}
+
+---Get the type of widget
+---@return 'Taskbar'
+function taskbar_instance:get_type() end
+
+---Get the format string
+---@return string
+function taskbar_instance:get_format() end
+
+---Set the format string
+---@param format string
+---@return nil
+function taskbar_instance:set_format(format) end
+
+---Get the maximum length of each item, `nil` if items are never truncated
+---@return integer | nil
+function taskbar_instance:get_max_length() end
+
+---Set the maximum length of each item, `nil` stops truncating items
+---@param max_length integer | nil
+---@return nil
+function taskbar_instance:set_max_length(max_length) end
+
+---Get the icon size in pixels
+---@return integer
+function taskbar_instance:get_icon_size() end
+
+---Set the icon size in pixels
+---@param icon_size integer
+---@return nil
+function taskbar_instance:set_icon_size(icon_size) end
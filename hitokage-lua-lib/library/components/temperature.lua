@@ -0,0 +1,162 @@
+---@meta hitokage.components.temperature
+
+--------------------------------------------------------------------------------
+---Links to TemperatureProps in 'hitokage-core\src\components\temperature.rs'
+
+---A native component within `hitokage` that displays current temperature sensor readings.
+---
+---Adds the css class `warn` when the selected sensor is at or above `warn`, and `crit` when it is at or above `crit`.
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type TemperatureProps
+---    temperature_props = { format = "{{round temperature 0}}°C", warn = 70, crit = 90 }
+---
+---    monitor:attach({
+---      children = {
+---        Temperature = temperature_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`Temperature`](lua://Temperature)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/Temperature" title="Temperature">`Temperature`</a> -->
+---
+---@class TemperatureProps : BaseProps
+---
+---A handlebars template string or function accepts TemperatureInfo and returns a handlebars template string
+---
+---@field format string | ReactiveString | fun(TemperatureInfo: TemperatureInfo): string
+---
+---The name or label of the sensor to display, ie. `"cpu"`, `"hwmon0/temp1"`, `"thermal_zone0"` or `"Package id 0"`.
+---Defaults to the first sensor available.
+---
+---@field sensor string?
+---
+---The temperature in celsius at which the `warn` css class is added
+---
+---@field warn number?
+---
+---The temperature in celsius at which the `crit` css class is added
+---
+---@field crit number?
+
+--------------------------------------------------------------------------------
+---Links to TemperatureUserData hitokage-lua\src\components\temperature.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`TemperatureProps`](lua://TemperatureProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapTemperatureProps/TemperatureProps" title="TemperatureProps">`TemperatureProps`</a> -->
+---
+---> A native component within `hitokage` that displays current temperature sensor readings.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type TemperatureProps
+---    temperature_props = { id = "temperature1", format = "{{round temperature 0}}°C" }
+---
+---    bar = monitor:attach({
+---      children = {
+---        Temperature = temperature_props,
+---      },
+---    })
+---
+---    temperature1 = bar:get_child_by_id("temperature1")
+---    temperature1:set_warn(75)
+---    ```
+---
+---@class Temperature : Base
+---
+---@field type 'Temperature'
+local temperature_instance = {}
+
+---Get the type of widget
+---@return 'Temperature'
+function temperature_instance:get_type() end
+
+---Get the format string
+---@return string
+function temperature_instance:get_format() end
+
+---Get the reactive format string
+---@return ReactiveString
+---@nodiscard
+function temperature_instance:get_format_reactive() end
+
+---Set the format string
+---@param string string
+---@return nil
+function temperature_instance:set_format(string) end
+
+---Get the selected sensor, `nil` if the first sensor available is used
+---@return string?
+function temperature_instance:get_sensor() end
+
+---Set the selected sensor by name or label
+---@param sensor string?
+---@return nil
+function temperature_instance:set_sensor(sensor) end
+
+---Get the warn threshold
+---@return number?
+function temperature_instance:get_warn() end
+
+---Set the warn threshold
+---@param warn number?
+---@return nil
+function temperature_instance:set_warn(warn) end
+
+---Get the crit threshold
+---@return number?
+function temperature_instance:get_crit() end
+
+---Set the crit threshold
+---@param crit number?
+---@return nil
+function temperature_instance:set_crit(crit) end
+
+--------------------------------------------------------------------------------
+---Links to TemperatureInfo hitokage-core\src\components\temperature.rs
+
+---Temperatures are in celsius.
+---
+---@class TemperatureInfo
+---
+---@field temperature number The reading of the selected sensor
+---@field sensor string The name of the selected sensor
+---@field warn number?
+---@field crit number?
+---@field sensors SensorInfo[] Every sensor available
+
+---@class SensorInfo
+---
+---@field name string
+---@field label string
+---@field temperature number
@@ -0,0 +1,153 @@
+---@meta hitokage.components.window_title
+
+--------------------------------------------------------------------------------
+---Links to WindowTitleProps in 'hitokage-core\src\components\window_title.rs'
+
+---A native component within `hitokage` that displays the title of the focused window on the bar's monitor.
+---
+---This is in the particular context of running `hitokage` with a window/tiling manager:
+---
+---* [`komorebi`](https://github.com/LGUG2Z/komorebi)
+---* [TODO (not supported) `glazewm`](https://github.com/glzr-io/glazewm)
+---
+---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type WindowTitleProps
+---    window_title_props = {
+---      format = "{{title}} ({{exe}})",
+---      max_length = 48,
+---      ellipsize = "Middle",
+---      tooltip = "{{class}}",
+---    }
+---
+---    monitor:attach({
+---      children = {
+---        WindowTitle = window_title_props,
+---      },
+---    })
+---    ```
+---
+---The mounted API is documented here: <!--@mkdocs-ignore-start-->[`WindowTitle`](lua://WindowTitle)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WindowTitle" title="WindowTitle">`WindowTitle`</a> -->
+---
+---@class WindowTitleProps : BaseProps
+---
+---A handlebars template string. Defaults to `{{title}}`. The label is empty when no window is focused.
+---
+---See <!--@mkdocs-ignore-start-->[`WindowTitleInfo`](lua://WindowTitleInfo)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WindowTitleInfo" title="WindowTitleInfo">`WindowTitleInfo`</a> -->
+---for the available arguments, these are also passed to the `tooltip`.
+---
+---@field format string?
+---
+---Truncates the label to this many characters. By default the label is never truncated.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Label.max-width-chars.html)
+---
+---@field max_length integer?
+---
+---Where to place the ellipsis when the label is truncated. Defaults to `'End'`.
+---
+---[View gtk4 documentation](https://docs.gtk.org/gtk4/property.Label.ellipsize.html)
+---
+---@field ellipsize EllipsizeMode?
+
+---The arguments available to the `format` of a <!--@mkdocs-ignore-start-->[`WindowTitleProps`](lua://WindowTitleProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapWindowTitleProps/WindowTitleProps" title="WindowTitleProps">`WindowTitleProps`</a> -->
+---@class WindowTitleInfo
+---
+---The title of the focused window.
+---@field title string
+---
+---The executable of the focused window, ie. `firefox.exe`.
+---@field exe string
+---
+---The window class of the focused window.
+---@field class string
+
+--------------------------------------------------------------------------------
+---Links to WindowTitleUserData hitokage-lua\src\components\window_title.rs
+
+---A userdata which corresponds to the mounted version of <!--@mkdocs-ignore-start-->[`WindowTitleProps`](lua://WindowTitleProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapWindowTitleProps/WindowTitleProps" title="WindowTitleProps">`WindowTitleProps`</a> -->
+---
+---> A native component within `hitokage` that displays the title of the focused window on the bar's monitor.
+---
+---This userdata can be retrieved using:
+---<!--@mkdocs-ignore-start-->
+---* [`Box:get_child_by_id`](lua://Box.get_child_by_id)
+---* [`Box:get_children`](lua://Box.get_children)
+---* [`Bar:get_child_by_id`](lua://Bar.get_child_by_id)
+---* [`Bar:get_children`](lua://Bar.get_children)
+---<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include * <a href="/hitokage/api/Box#method-get_child_by_id" title="Box#method-get_child_by_id">`Box:get_child_by_id`</a>
+---* <a href="/hitokage/api/Box#method-get_children" title="Box#method-get_children">`Box:get_children`</a>
+---* <a href="/hitokage/api/Bar#method-get_child_by_id" title="Bar#method-get_child_by_id">`Bar:get_child_by_id`</a>
+---* <a href="/hitokage/api/Bar#method-get_children" title="Bar#method-get_children">`Bar:get_children`</a>
+----->
+---
+---<!--@mkdocs-ignore-next-line-->
+---**Example:**
+---<!--@mkdocs-include
+---    !!! example -->
+---
+---    ```lua
+---    monitor = hitokage.monitor.get_primary()
+---
+---    ---@type WindowTitleProps
+---    window_title_props = { id = "title1", max_length = 32 }
+---
+---    bar = monitor:attach({
+---      children = {
+---        WindowTitle = window_title_props,
+---      },
+---    })
+---
+---    title1 = bar:get_child_by_id("title1")
+---    title1:set_format("{{exe}}: {{title}}")
+---    ```
+---
+---@class WindowTitle : Base
+---
+---@field type 'WindowTitle'
+local window_title_instance = {}
+
+---Get the type of widget
+---@return 'WindowTitle'
+function window_title_instance:get_type() end
+
+---Get the format string
+---@return string
+function window_title_instance:get_format() end
+
+---Set the format string
+---@param format string
+---@return nil
+function window_title_instance:set_format(format) end
+
+---Get the maximum length, `nil` if the label is never truncated
+---@return integer | nil
+function window_title_instance:get_max_length() end
+
+---Set the maximum length, `nil` stops truncating the label
+---@param max_length integer | nil
+---@return nil
+function window_title_instance:set_max_length(max_length) end
+
+---Get where the ellipsis is placed when truncating
+---@return EllipsizeMode
+function window_title_instance:get_ellipsize() end
+
+---Set where the ellipsis is placed when truncating
+---@param ellipsize EllipsizeMode
+---@return nil
+function window_title_instance:set_ellipsize(ellipsize) end
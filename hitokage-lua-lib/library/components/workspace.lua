@@ -8,8 +8,12 @@
 ---This is in the particular context of running `hitokage` with a window/tiling manager:
 ---
 ---* [`komorebi`](https://github.com/LGUG2Z/komorebi)
+---* [`i3`](https://i3wm.org/) and [`sway`](https://swaywm.org/)
+---* [`Hyprland`](https://hyprland.org/)
 ---* [TODO (not supported) `glazewm`](https://github.com/glzr-io/glazewm)
 ---
+---Clicking a workspace focuses it, scrolling moves to the previous or next workspace that is shown.
+---
 ---See <!--@mkdocs-ignore-start-->[`ComponentProps`](lua://ComponentProps)<!--@mkdocs-ignore-end-->
 ---<!--@mkdocs-include <a href="/hitokage/api/ComponentProps" title="ComponentProps">`ComponentProps`</a> -->
 ---
@@ -39,7 +43,44 @@
 ---
 ---@field item_width integer?
 ---@field item_height integer?
+---
+---A handlebars template string used for each workspace label. Defaults to the workspace name or its number.
+---
+---See <!--@mkdocs-ignore-start-->[`WorkspaceInfo`](lua://WorkspaceInfo)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WorkspaceInfo" title="WorkspaceInfo">`WorkspaceInfo`</a> -->
+---for the available arguments.
+---
+---**Example:** `"{{index}} {{#each exes}}{{this}} {{/each}}"`
+---
 ---@field format string?
+---
+---The window manager to read workspaces from. Defaults to detecting it from the environment, ie. `SWAYSOCK`, and
+---otherwise `'Komorebi'`.
+---
+---@field provider WorkspaceProviderKind?
+
+---@alias WorkspaceProviderKind 'Komorebi' | 'I3' | 'Sway' | 'Hyprland'
+
+---The arguments available to the `format` of a <!--@mkdocs-ignore-start-->[`WorkspaceProps`](lua://WorkspaceProps)<!--@mkdocs-ignore-end-->
+---<!--@mkdocs-include <a href="/hitokage/api/WrapWorkspaceProps/WorkspaceProps" title="WorkspaceProps">`WorkspaceProps`</a> -->
+---@class WorkspaceInfo
+---
+---The name of the workspace, or an empty string if it has none.
+---@field name string
+---
+---The zero based index of the workspace.
+---@field index string
+---
+---The number of windows, including stacked and floating windows.
+---@field window_count integer
+---
+---@field is_empty boolean
+---
+---The layout of the workspace, ie. `BSP`, `Columns` or `Custom`.
+---@field layout string
+---
+---The exe of each container's focused window, followed by the maximized and floating windows.
+---@field exes string[]
 
 --------------------------------------------------------------------------------
 ---Links to hitokage-lua\src\components\workspace.rs
@@ -14,13 +14,14 @@
 ---It is globally available as part of the lua global environment: <a href="https://www.lua.org/pil/14.html">_G</a>
 ---@class hitokage
 _G.hitokage = {}
-_G._not_deadlocked = function() end
 _G._subscribers = {}
 _G._subscriptions = {}
 
 local bar = require("hitokage.api.bar")
 local monitor = require("hitokage.api.monitor")
 local reactive = require("hitokage.api.reactive")
+local sandbox = require("hitokage.api.sandbox")
+local timer = require("hitokage.api.timer")
 
 -------------------------------------------------------------------------------
 ---Utility functions
@@ -35,15 +36,16 @@ function hitokage.error(...) end
 ---@vararg any
 function hitokage.info(...) end
 
----Sleep function in milliseconds
+---Sleep function in milliseconds. Only the calling coroutine is suspended, everything else keeps running
 ---@param ms number Amount of time to sleep
 function hitokage.sleep_ms(ms) end
 
 -------------------------------------------------------------------------------
 ---Functions written in lua
 
----Add a coroutine to the hitokage event loop. A coroutine can run at most once
----per 100ms. This is an internal throttling mechanism that cannot be bypassed.
+---Add a coroutine to the hitokage event loop. Nothing is polled, a coroutine that yields is resumed once something
+---it may be waiting on happens: a komorebi event, a reactive change, a component action or the timer of a
+---`hitokage.sleep_ms`.
 ---
 ---**Example**:
 ---```lua
@@ -65,7 +67,8 @@ function hitokage.dispatch(thread_or_threads) end
 ---@return nil
 function hitokage.subscribe(name, callback) end
 
----Utility function to help create a coroutine that runs at regular intervals
+---Utility function to help create a coroutine that runs at regular intervals. Prefer
+---[`hitokage.timer.interval`](/hitokage/api/hitokage/timer#function-interval){: title="hitokage.timer#function-interval"}, which can be cancelled
 ---
 ---<!--@mkdocs-ignore-next-line-->
 ---**Example:**
@@ -89,6 +92,7 @@ function hitokage.subscribe(name, callback) end
 ---@param action function A callback function that is run once the `timeout` has passed
 ---@return thread
 ---@nodiscard
+---@deprecated
 function hitokage.timeout(timeout, action) end
 
 -------------------------------------------------------------------------------
@@ -105,4 +109,6 @@ unstable.reactive = reactive
 
 hitokage.bar = bar
 hitokage.monitor = monitor
+hitokage.sandbox = sandbox
+hitokage.timer = timer
 hitokage.unstable = unstable
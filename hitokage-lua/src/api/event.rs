@@ -1,5 +1,5 @@
 use hitokage_core::components::app::{LuaHook, LuaHookType};
-use hitokage_core::event::{CONFIG_UPDATE, EVENT, NEW_EVENT};
+use hitokage_core::event::{EVENT, NEW_EVENT};
 use mlua::{Lua, LuaSerdeExt, Value};
 use relm4::{Component, ComponentSender};
 
@@ -20,6 +20,8 @@ where
             t: LuaHookType::ReadEvent,
             // callback: Box::new(|_| Ok(())),
           }));
+          // mark it read right away, the app may not have handled the hook before the scheduler resumes us again
+          *NEW_EVENT.write() = false;
           let args = EVENT.read();
 
           let lua_args = lua_inner.to_value(std::ops::Deref::deref(&args));
@@ -72,27 +74,6 @@ where
         }
       })?,
     )?;
-
-    let configuration = lua.create_table()?;
-
-    configuration.set(
-      "changed",
-      lua.create_function({
-        let sender = sender.clone();
-        move |lua_inner, _value: Value| {
-          sender.input(crate::AppMsg::LuaHook(LuaHook {
-            t: LuaHookType::CheckConfigUpdate,
-            // callback: Box::new(|_| Ok(())),
-          }));
-          let args = CONFIG_UPDATE.read();
-          let lua_args = lua_inner.to_value(std::ops::Deref::deref(&args));
-
-          Ok(lua_args)
-        }
-      })?,
-    )?;
-
-    table.set("configuration", configuration)?;
   }
 
   Ok(table)
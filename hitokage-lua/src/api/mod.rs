@@ -2,3 +2,6 @@ pub mod actions;
 pub mod event;
 pub mod monitor;
 pub mod reactive;
+pub mod require;
+pub mod sandbox;
+pub mod timer;
@@ -0,0 +1,152 @@
+use hitokage_core::components::app::{AppMsg, LuaHook, LuaHookType};
+use mlua::{Lua, Table, Value};
+use relm4::{Component, ComponentSender};
+use std::path::{Path, PathBuf};
+
+/// `require("bars.left")` looks for `bars/left.lua`, then `bars/left/init.lua`
+fn module_paths(config_dir: &Path, name: &str) -> Option<[PathBuf; 2]> {
+  let segments: Vec<&str> = name.split('.').collect();
+  // keep modules inside the config dir
+  if segments
+    .iter()
+    .any(|segment| segment.is_empty() || *segment == ".." || segment.contains(['/', '\\']))
+  {
+    return None;
+  }
+
+  let base = segments
+    .iter()
+    .fold(config_dir.to_path_buf(), |path, segment| path.join(segment));
+  Some([base.with_extension("lua"), base.join("init.lua")])
+}
+
+/// Installs a `require` searcher rooted at `config_dir`. Every file it loads is sent to the app to be watched,
+/// so editing a module reloads the config just like editing `init.lua`
+pub fn make<C>(lua: &Lua, sender: &ComponentSender<C>, config_dir: PathBuf) -> anyhow::Result<()>
+where
+  C: Component<Input = AppMsg>,
+  <C as Component>::Output: std::marker::Send,
+{
+  let sender = sender.clone();
+  install_searcher(lua, config_dir, move |msg| sender.input(msg))?;
+  Ok(())
+}
+
+fn install_searcher(lua: &Lua, config_dir: PathBuf, send: impl Fn(AppMsg) + Send + 'static) -> mlua::Result<()> {
+  let package: Table = lua.globals().get("package")?;
+  let searchers: Table = package.get("searchers")?;
+
+  let searcher = lua.create_function(move |lua, name: String| {
+    let Some(paths) = module_paths(&config_dir, &name) else {
+      return Ok((
+        Value::String(lua.create_string(format!("'{}' is not a valid module name", name))?),
+        Value::Nil,
+      ));
+    };
+
+    let Some(path) = paths.iter().find(|path| path.is_file()) else {
+      let tried = paths
+        .iter()
+        .map(|path| format!("no file '{}'", path.display()))
+        .collect::<Vec<_>>()
+        .join("\n\t");
+      return Ok((Value::String(lua.create_string(tried)?), Value::Nil));
+    };
+
+    let source = std::fs::read_to_string(path).map_err(mlua::Error::external)?;
+    let loader = lua
+      .load(source)
+      .set_name(format!("@{}", path.display()))
+      .into_function()?;
+
+    send(AppMsg::LuaHook(LuaHook {
+      t: LuaHookType::WatchFile(path.clone()),
+    }));
+
+    Ok((
+      Value::Function(loader),
+      Value::String(lua.create_string(path.to_string_lossy().as_bytes())?),
+    ))
+  })?;
+
+  // after the preload searcher, but before the default searchers relative to the working directory
+  searchers.raw_insert(2, searcher)?;
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::mpsc;
+
+  fn config_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("hitokage-require-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("bars").join("right")).unwrap();
+    std::fs::write(dir.join("bars").join("left.lua"), "return { side = 'left' }").unwrap();
+    std::fs::write(
+      dir.join("bars").join("right").join("init.lua"),
+      "return { side = 'right' }",
+    )
+    .unwrap();
+    dir
+  }
+
+  fn installed(config_dir: PathBuf) -> (Lua, mpsc::Receiver<PathBuf>) {
+    let lua = Lua::new();
+    let (tx, rx) = mpsc::channel();
+    install_searcher(&lua, config_dir, move |msg| {
+      if let AppMsg::LuaHook(LuaHook {
+        t: LuaHookType::WatchFile(path),
+      }) = msg
+      {
+        tx.send(path).unwrap();
+      }
+    })
+    .unwrap();
+    (lua, rx)
+  }
+
+  #[test]
+  fn searcher_follows_preload() {
+    let dir = config_dir("order");
+    let (lua, _rx) = installed(dir.clone());
+
+    let (loader, path): (Value, String) = lua.load("return package.searchers[2]('bars.left')").eval().unwrap();
+    assert!(matches!(loader, Value::Function(_)));
+    assert_eq!(PathBuf::from(path), dir.join("bars").join("left.lua"));
+  }
+
+  #[test]
+  fn requires_module_and_watches_it() {
+    let dir = config_dir("watch");
+    let (lua, rx) = installed(dir.clone());
+
+    let side: String = lua.load("return require('bars.left').side").eval().unwrap();
+    assert_eq!(side, "left");
+    assert_eq!(rx.try_recv().unwrap(), dir.join("bars").join("left.lua"));
+
+    // modules are cached by require, so they are only watched once
+    let _: Table = lua.load("return require('bars.left')").eval().unwrap();
+    assert!(rx.try_recv().is_err());
+  }
+
+  #[test]
+  fn requires_init_lua_of_directory() {
+    let dir = config_dir("init");
+    let (lua, rx) = installed(dir.clone());
+
+    let side: String = lua.load("return require('bars.right').side").eval().unwrap();
+    assert_eq!(side, "right");
+    assert_eq!(rx.try_recv().unwrap(), dir.join("bars").join("right").join("init.lua"));
+  }
+
+  #[test]
+  fn rejects_modules_outside_config_dir() {
+    let (lua, rx) = installed(config_dir("escape"));
+    assert!(lua.load("require('..secrets')").exec().is_err());
+    assert!(lua.load("require('bars/left')").exec().is_err());
+    assert!(rx.try_recv().is_err());
+  }
+}
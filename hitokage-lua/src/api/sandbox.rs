@@ -0,0 +1,239 @@
+use mlua::{Function, Lua, MultiValue};
+use std::future::{poll_fn, Future};
+use std::pin::pin;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_MEMORY_LIMIT_MB: usize = 512;
+pub const DEFAULT_CALLBACK_BUDGET_MS: u64 = 1000;
+
+/// Raised inside a callback that ran longer than its budget
+#[derive(Debug)]
+pub struct BudgetExceeded(Duration);
+
+impl std::fmt::Display for BudgetExceeded {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "callback exceeded its budget of {}ms, is it stuck in a loop? The budget can be changed with \
+       hitokage.sandbox.set_callback_budget",
+      self.0.as_millis()
+    )
+  }
+}
+
+impl std::error::Error for BudgetExceeded {}
+
+#[derive(Debug)]
+struct CallbackBudget {
+  /// `None` disables the budget
+  budget: Option<Duration>,
+  /// When the outermost running callback runs out of budget, nested callbacks share it
+  deadline: Option<Instant>,
+}
+
+/// Starts the budget of a callback, the budget ends when this is dropped
+pub struct BudgetGuard {
+  lua: Lua,
+  is_outermost: bool,
+}
+
+impl BudgetGuard {
+  pub fn new(lua: &Lua) -> Self {
+    let mut is_outermost = false;
+    if let Some(mut budget) = lua.app_data_mut::<CallbackBudget>() {
+      if budget.deadline.is_none() {
+        budget.deadline = budget.budget.map(|budget| Instant::now() + budget);
+        is_outermost = true;
+      }
+    }
+
+    BudgetGuard {
+      lua: lua.clone(),
+      is_outermost,
+    }
+  }
+}
+
+impl Drop for BudgetGuard {
+  fn drop(&mut self) {
+    if !self.is_outermost {
+      return;
+    }
+    if let Some(mut budget) = self.lua.app_data_mut::<CallbackBudget>() {
+      budget.deadline = None;
+    }
+  }
+}
+
+/// Fails once the running callback is over its budget, meant to be called from a lua hook. The error is raised again
+/// on every check, so a callback can't escape its budget with `pcall`
+pub fn check_callback_budget(lua: &Lua) -> mlua::Result<()> {
+  let Some(budget) = lua.app_data_ref::<CallbackBudget>() else {
+    return Ok(());
+  };
+
+  match (budget.deadline, budget.budget) {
+    (Some(deadline), Some(duration)) if Instant::now() >= deadline => {
+      Err(mlua::Error::external(BudgetExceeded(duration)))
+    }
+    _ => Ok(()),
+  }
+}
+
+fn set_memory_limit(lua: &Lua, mb: usize) -> mlua::Result<()> {
+  // 0 means unlimited to mlua as well
+  lua.set_memory_limit(mb * 1024 * 1024)?;
+  Ok(())
+}
+
+pub fn make(lua: &Lua) -> anyhow::Result<mlua::Table> {
+  set_memory_limit(lua, DEFAULT_MEMORY_LIMIT_MB)?;
+  lua.set_app_data(CallbackBudget {
+    budget: Some(Duration::from_millis(DEFAULT_CALLBACK_BUDGET_MS)),
+    deadline: None,
+  });
+
+  let table = lua.create_table()?;
+
+  table.set(
+    "set_memory_limit",
+    lua.create_function(|lua, mb: usize| set_memory_limit(lua, mb))?,
+  )?;
+
+  table.set(
+    "set_callback_budget",
+    lua.create_function(|lua, ms: u64| {
+      if let Some(mut budget) = lua.app_data_mut::<CallbackBudget>() {
+        budget.budget = (ms > 0).then_some(Duration::from_millis(ms));
+      }
+      Ok(())
+    })?,
+  )?;
+
+  Ok(table)
+}
+
+/// Calls a function within a callback budget, used by prepend.lua to run subscription callbacks and component actions.
+/// The callback may still await, ie. `hitokage.sleep_ms`, time spent waiting is not counted
+pub fn make_call(lua: &Lua) -> mlua::Result<Function> {
+  lua.create_async_function(|lua, (callback, args): (Function, MultiValue)| async move {
+    let mut call = pin!(callback.call_async::<MultiValue>(args));
+    poll_fn(|cx| {
+      let _guard = BudgetGuard::new(&lua);
+      call.as_mut().poll(cx)
+    })
+    .await
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// A runtime with the sandbox installed, and the budget checked like hitokage's interrupt hook does
+  fn sandboxed(budget_ms: u64) -> Lua {
+    let lua = Lua::new();
+    let sandbox = make(&lua).unwrap();
+    sandbox
+      .get::<Function>("set_callback_budget")
+      .unwrap()
+      .call::<()>(budget_ms)
+      .unwrap();
+    lua.set_hook(mlua::HookTriggers::new().every_nth_instruction(1000), |lua, _| {
+      check_callback_budget(lua)?;
+      Ok(mlua::VmState::Continue)
+    });
+    lua
+  }
+
+  fn deadline(lua: &Lua) -> Option<Instant> {
+    lua.app_data_ref::<CallbackBudget>().unwrap().deadline
+  }
+
+  fn is_budget_exceeded(err: &mlua::Error) -> bool {
+    match err {
+      mlua::Error::CallbackError { cause, .. } => is_budget_exceeded(cause),
+      err => err.downcast_ref::<BudgetExceeded>().is_some(),
+    }
+  }
+
+  #[test]
+  fn interrupts_busy_loop_after_budget() {
+    let lua = sandboxed(50);
+    let busy: Function = lua.load("return function() while true do end end").eval().unwrap();
+
+    let start = Instant::now();
+    let err = {
+      let _guard = BudgetGuard::new(&lua);
+      busy.call::<()>(()).unwrap_err()
+    };
+
+    assert!(is_budget_exceeded(&err), "unexpected error: {}", err);
+    assert!(start.elapsed() >= Duration::from_millis(50));
+    // the next callback gets a budget of its own
+    let _guard = BudgetGuard::new(&lua);
+    assert_eq!(lua.load("return 1 + 1").eval::<i32>().unwrap(), 2);
+  }
+
+  #[test]
+  fn pcall_cannot_escape_budget() {
+    let lua = sandboxed(50);
+    let busy: Function = lua
+      .load("return function() pcall(function() while true do end end) while true do end end")
+      .eval()
+      .unwrap();
+
+    let _guard = BudgetGuard::new(&lua);
+    assert!(is_budget_exceeded(&busy.call::<()>(()).unwrap_err()));
+  }
+
+  #[test]
+  fn guard_resets_deadline_on_drop() {
+    let lua = sandboxed(50);
+    assert_eq!(deadline(&lua), None);
+
+    {
+      let _guard = BudgetGuard::new(&lua);
+      let outer = deadline(&lua);
+      assert!(outer.is_some());
+
+      {
+        // nested callbacks share the outermost deadline, and leave it alone when they finish
+        let _nested = BudgetGuard::new(&lua);
+        assert_eq!(deadline(&lua), outer);
+      }
+      assert_eq!(deadline(&lua), outer);
+    }
+
+    assert_eq!(deadline(&lua), None);
+  }
+
+  #[test]
+  fn no_deadline_without_budget() {
+    let lua = sandboxed(0);
+    let _guard = BudgetGuard::new(&lua);
+    assert_eq!(deadline(&lua), None);
+    assert!(check_callback_budget(&lua).is_ok());
+  }
+
+  #[test]
+  fn memory_limit_raises_lua_error() {
+    let lua = sandboxed(0);
+    set_memory_limit(&lua, 1).unwrap();
+
+    let grow = "local t = {} for i = 1, 1e7 do t[i] = tostring(i) end";
+    let err = lua.load(grow).exec().unwrap_err();
+    assert!(matches!(err, mlua::Error::MemoryError(_)), "unexpected error: {}", err);
+
+    // scripts can catch it like any other error
+    let caught: bool = lua
+      .load(format!("return not pcall(function() {} end)", grow))
+      .eval()
+      .unwrap();
+    assert!(caught);
+
+    // and the runtime keeps working once the memory is collected
+    lua.gc_collect().unwrap();
+    assert_eq!(lua.load("return 1 + 1").eval::<i32>().unwrap(), 2);
+  }
+}
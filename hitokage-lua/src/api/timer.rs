@@ -0,0 +1,438 @@
+use super::sandbox::BudgetGuard;
+use chrono::{DateTime, Local};
+use cron::Schedule;
+use mlua::{Function, Lua, Table, UserData, UserDataMethods};
+use std::future::{poll_fn, Future};
+use std::pin::{pin, Pin};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+enum TimerKind {
+  Interval(Duration),
+  After(Duration),
+  Cron(Box<Schedule>),
+}
+
+#[derive(Debug, Default)]
+struct TimerState {
+  /// Bumped on every reset and cancel, a thread running an older generation stops at its next wake
+  generation: u64,
+  active: bool,
+  waker: Option<Waker>,
+}
+
+/// A timer running on the lua scheduler, callbacks run on the lua thread like any dispatched coroutine
+#[derive(Debug, Clone)]
+pub struct TimerHandle {
+  kind: TimerKind,
+  callback: Function,
+  state: Arc<Mutex<TimerState>>,
+}
+
+impl TimerHandle {
+  fn new(lua: &Lua, kind: TimerKind, callback: Function) -> mlua::Result<Self> {
+    let timer = TimerHandle {
+      kind,
+      callback,
+      state: Arc::new(Mutex::new(TimerState::default())),
+    };
+    timer.start(lua)?;
+    Ok(timer)
+  }
+
+  /// (Re)starts the countdown from now, replacing the thread of a previous start
+  fn start(&self, lua: &Lua) -> mlua::Result<()> {
+    let generation = self.bump(true);
+
+    let timer = self.clone();
+    let func = lua.create_async_function(move |lua, ()| {
+      let timer = timer.clone();
+      async move { timer.run(&lua, generation).await }
+    })?;
+
+    // picked up by the scheduler like anything passed to `hitokage.dispatch`
+    let threads: Table = lua.globals().get("_threads")?;
+    threads.push(lua.create_thread(func)?)?;
+
+    Ok(())
+  }
+
+  fn cancel(&self) {
+    self.bump(false);
+  }
+
+  fn is_active(&self) -> bool {
+    self.state.lock().unwrap().active
+  }
+
+  fn bump(&self, active: bool) -> u64 {
+    let mut state = self.state.lock().unwrap();
+    state.generation += 1;
+    state.active = active;
+    // let a sleeping thread notice it is outdated instead of waiting out its deadline
+    if let Some(waker) = state.waker.take() {
+      waker.wake();
+    }
+    state.generation
+  }
+
+  async fn run(&self, lua: &Lua, generation: u64) -> mlua::Result<()> {
+    let start = Instant::now();
+
+    loop {
+      let awake = match &self.kind {
+        TimerKind::After(delay) => self.sleep_until(start + *delay, generation).await,
+        TimerKind::Interval(period) => {
+          self
+            .sleep_until(next_tick(start, Instant::now(), *period), generation)
+            .await
+        }
+        TimerKind::Cron(schedule) => match schedule.upcoming(Local).next() {
+          Some(next) => self.sleep_until_local(next, generation).await,
+          // the schedule has no more occurrences
+          None => {
+            self.finish(generation);
+            return Ok(());
+          }
+        },
+      };
+
+      if !awake {
+        return Ok(());
+      }
+
+      self.fire(lua).await;
+
+      if let TimerKind::After(_) = self.kind {
+        self.finish(generation);
+        return Ok(());
+      }
+    }
+  }
+
+  /// Sleeps until `deadline`, returns `false` if the timer was cancelled or reset in the meantime
+  async fn sleep_until(&self, deadline: Instant, generation: u64) -> bool {
+    let mut timer = smol::Timer::at(deadline);
+    poll_fn(|cx| {
+      let mut state = self.state.lock().unwrap();
+      if state.generation != generation {
+        return Poll::Ready(false);
+      }
+      state.waker = Some(cx.waker().clone());
+      drop(state);
+      Pin::new(&mut timer).poll(cx).map(|_| true)
+    })
+    .await
+  }
+
+  /// Like [`TimerHandle::sleep_until`], but against the system clock so changes to it are honored
+  async fn sleep_until_local(&self, deadline: DateTime<Local>, generation: u64) -> bool {
+    loop {
+      let remaining = (deadline - Local::now()).to_std().unwrap_or_default();
+      if remaining.is_zero() {
+        return true;
+      }
+      // wake up at least every minute to check the clock has not moved
+      if !self
+        .sleep_until(Instant::now() + remaining.min(Duration::from_secs(60)), generation)
+        .await
+      {
+        return false;
+      }
+    }
+  }
+
+  async fn fire(&self, lua: &Lua) {
+    let mut call = pin!(self.callback.call_async::<()>(()));
+    // the budget covers every stretch the callback runs, time spent awaiting ie. `hitokage.sleep_ms` is not counted
+    let result = poll_fn(|cx| {
+      let _guard = BudgetGuard::new(lua);
+      call.as_mut().poll(cx)
+    })
+    .await;
+    // a failing callback is logged, but does not stop the timer
+    if let Err(err) = result {
+      log::error!("Timer callback failed: {}", err);
+    }
+  }
+
+  fn finish(&self, generation: u64) {
+    let mut state = self.state.lock().unwrap();
+    if state.generation == generation {
+      state.active = false;
+    }
+  }
+}
+
+impl UserData for TimerHandle {
+  fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("cancel", |_, this, ()| {
+      this.cancel();
+      Ok(())
+    });
+    methods.add_method("reset", |lua, this, ()| this.start(lua));
+    methods.add_method("is_active", |_, this, ()| Ok(this.is_active()));
+  }
+}
+
+/// Ticks stay on the grid of the first start, ticks missed by a long callback are skipped rather than queued
+fn next_tick(start: Instant, now: Instant, period: Duration) -> Instant {
+  let ticks = now.duration_since(start).as_nanos() / period.as_nanos() + 1;
+  start + Duration::from_nanos((period.as_nanos() * ticks) as u64)
+}
+
+fn milliseconds(ms: u64) -> mlua::Result<Duration> {
+  if ms == 0 {
+    return Err(mlua::Error::RuntimeError(
+      "Timer duration must be greater than 0".to_string(),
+    ));
+  }
+  Ok(Duration::from_millis(ms))
+}
+
+fn parse_cron(expr: &str) -> mlua::Result<Schedule> {
+  // the cron crate requires a seconds field, also accept the usual five fields
+  let expr = match expr.split_whitespace().count() {
+    5 => format!("0 {}", expr),
+    _ => expr.to_string(),
+  };
+  Schedule::from_str(&expr)
+    .map_err(|err| mlua::Error::RuntimeError(format!("Invalid cron expression '{}': {}", expr, err)))
+}
+
+pub fn make(lua: &Lua) -> anyhow::Result<mlua::Table> {
+  let table = lua.create_table()?;
+
+  table.set(
+    "interval",
+    lua.create_function(|lua, (ms, callback): (u64, Function)| {
+      TimerHandle::new(lua, TimerKind::Interval(milliseconds(ms)?), callback)
+    })?,
+  )?;
+
+  table.set(
+    "after",
+    lua.create_function(|lua, (ms, callback): (u64, Function)| {
+      TimerHandle::new(lua, TimerKind::After(milliseconds(ms)?), callback)
+    })?,
+  )?;
+
+  table.set(
+    "cron",
+    lua.create_function(|lua, (expr, callback): (String, Function)| {
+      TimerHandle::new(lua, TimerKind::Cron(Box::new(parse_cron(&expr)?)), callback)
+    })?,
+  )?;
+
+  Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::scheduler::Scheduler;
+  use chrono::{TimeZone, Timelike, Utc};
+  use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+  fn runtime() -> Lua {
+    let lua = Lua::new();
+    let globals = lua.globals();
+    globals.set("_threads", lua.create_table().unwrap()).unwrap();
+    globals.set("timer", make(&lua).unwrap()).unwrap();
+    lua
+  }
+
+  /// Runs `script` and the timers it starts until every one of them has finished
+  fn run(lua: &Lua, script: &str) {
+    let main = lua.create_thread(lua.load(script).into_function().unwrap()).unwrap();
+    let is_parked = AtomicBool::new(false);
+    let result = Scheduler::new(main).run(lua, &is_parked, || false);
+    assert!(matches!(result, Ok(None)), "unexpected result: {:?}", result);
+  }
+
+  #[test]
+  fn interval_ticks_on_grid() {
+    let start = Instant::now();
+    let period = Duration::from_millis(100);
+
+    assert_eq!(next_tick(start, start, period), start + period);
+    // a tick that ran late is not carried over to the next one
+    assert_eq!(
+      next_tick(start, start + Duration::from_millis(130), period),
+      start + period * 2
+    );
+    assert_eq!(next_tick(start, start + period * 2, period), start + period * 3);
+    // ticks missed by a long callback are skipped
+    assert_eq!(
+      next_tick(start, start + Duration::from_millis(350), period),
+      start + period * 4
+    );
+  }
+
+  #[test]
+  fn interval_does_not_drift() {
+    let lua = runtime();
+    let start = Instant::now();
+    let globals = lua.globals();
+    globals
+      .set(
+        "elapsed_ms",
+        lua
+          .create_function(move |_, ()| Ok(start.elapsed().as_millis() as u64))
+          .unwrap(),
+      )
+      .unwrap();
+    globals
+      .set(
+        "block_ms",
+        lua
+          .create_function(|_, ms: u64| {
+            std::thread::sleep(Duration::from_millis(ms));
+            Ok(())
+          })
+          .unwrap(),
+      )
+      .unwrap();
+
+    run(
+      &lua,
+      r#"
+        ticks = {}
+        t = timer.interval(50, function()
+          table.insert(ticks, elapsed_ms())
+          block_ms(30)
+          if #ticks == 4 then t:cancel() end
+        end)
+      "#,
+    );
+
+    let ticks: Vec<u64> = globals.get("ticks").unwrap();
+    assert_eq!(ticks.len(), 4);
+    for (i, tick) in ticks.iter().enumerate() {
+      assert!(*tick >= (i as u64 + 1) * 50, "tick {} fired early at {}ms", i + 1, tick);
+    }
+    // a timer restarting its period after each 30ms callback would only reach its 4th tick after 290ms
+    assert!(ticks[3] < 250, "ticks drifted: {:?}", ticks);
+    assert!(!lua.load("return t:is_active()").eval::<bool>().unwrap());
+  }
+
+  #[test]
+  fn after_fires_once() {
+    let lua = runtime();
+    run(
+      &lua,
+      r#"
+        fired = 0
+        t = timer.after(20, function() fired = fired + 1 end)
+      "#,
+    );
+    assert_eq!(lua.globals().get::<u32>("fired").unwrap(), 1);
+    assert!(!lua.load("return t:is_active()").eval::<bool>().unwrap());
+  }
+
+  #[test]
+  fn cancel_stops_timer() {
+    let lua = runtime();
+    run(
+      &lua,
+      r#"
+        fired = 0
+        t = timer.after(20, function() fired = fired + 1 end)
+        t:cancel()
+        active = t:is_active()
+      "#,
+    );
+    assert_eq!(lua.globals().get::<u32>("fired").unwrap(), 0);
+    assert!(!lua.globals().get::<bool>("active").unwrap());
+  }
+
+  #[test]
+  fn reset_restarts_timer() {
+    let lua = runtime();
+    run(
+      &lua,
+      r#"
+        fired = 0
+        t = timer.after(20, function() fired = fired + 1 end)
+        t:reset()
+        c = timer.after(20, function() fired = fired + 10 end)
+        c:cancel()
+        c:reset()
+      "#,
+    );
+    // the threads of the first starts are stale, so each timer fires only once
+    assert_eq!(lua.globals().get::<u32>("fired").unwrap(), 11);
+  }
+
+  #[test]
+  fn stale_generation_is_a_no_op() {
+    let lua = Lua::new();
+    let fired = Arc::new(AtomicUsize::new(0));
+    let timer = TimerHandle {
+      kind: TimerKind::After(Duration::from_millis(20)),
+      callback: lua
+        .create_function({
+          let fired = Arc::clone(&fired);
+          move |_, ()| {
+            fired.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+          }
+        })
+        .unwrap(),
+      state: Arc::new(Mutex::new(TimerState::default())),
+    };
+
+    let stale = timer.bump(true);
+    let current = timer.bump(true);
+
+    // an outdated thread returns right away, without waiting out its deadline or firing
+    let started = Instant::now();
+    smol::block_on(timer.run(&lua, stale)).unwrap();
+    assert!(started.elapsed() < Duration::from_millis(20));
+    assert_eq!(fired.load(Ordering::SeqCst), 0);
+
+    // and finishing it leaves the current generation alone
+    timer.finish(stale);
+    assert!(timer.is_active());
+
+    smol::block_on(timer.run(&lua, current)).unwrap();
+    assert_eq!(fired.load(Ordering::SeqCst), 1);
+    assert!(!timer.is_active());
+  }
+
+  #[test]
+  fn rejects_zero_duration() {
+    let lua = runtime();
+    assert!(lua.load("timer.interval(0, function() end)").exec().is_err());
+    assert!(lua.load("timer.after(0, function() end)").exec().is_err());
+  }
+
+  #[test]
+  fn parses_cron_with_five_fields() {
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 10, 2, 30).unwrap();
+
+    // seconds are implied to be 0
+    let next = parse_cron("*/5 * * * *").unwrap().after(&from).next().unwrap();
+    assert_eq!(next, Utc.with_ymd_and_hms(2024, 1, 1, 10, 5, 0).unwrap());
+
+    let next = parse_cron("0 12 * * *").unwrap().after(&from).next().unwrap();
+    assert_eq!((next.hour(), next.minute(), next.second()), (12, 0, 0));
+  }
+
+  #[test]
+  fn parses_cron_with_seconds() {
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 10, 2, 30).unwrap();
+    let next = parse_cron("45 * * * * *").unwrap().after(&from).next().unwrap();
+    assert_eq!(next, Utc.with_ymd_and_hms(2024, 1, 1, 10, 2, 45).unwrap());
+  }
+
+  #[test]
+  fn rejects_invalid_cron() {
+    let err = parse_cron("every day").unwrap_err();
+    assert!(err.to_string().contains("Invalid cron expression"), "{}", err);
+    assert!(parse_cron("61 * * * *").is_err());
+  }
+}
@@ -67,6 +67,11 @@ impl BarUserData {
   #[rustfmt::skip]
   impl_setter_fn!(set_size_request, BarMsg::LuaHook, BoxHook, BaseHook, SetSizeRequest, (Option<i32>, Option<i32>));
 
+  #[rustfmt::skip]
+  impl_getter_fn!(get_tooltip, BarMsg::LuaHook, BoxHook, BaseHook, GetTooltip, Option<String>);
+  #[rustfmt::skip]
+  impl_setter_fn!(set_tooltip, BarMsg::LuaHook, BoxHook, BaseHook, SetTooltip, Option<String>);
+
   impl_getter_fn!(get_valign, BarMsg::LuaHook, BoxHook, BaseHook, GetValign, Align);
   impl_setter_fn!(set_valign, BarMsg::LuaHook, BoxHook, BaseHook, SetValign, Align);
 
@@ -100,11 +100,14 @@ where
     }
 
     if recursive.unwrap_or(false) {
-      if let ChildUserData::Box(box_userdata) = widget {
-        let children = box_userdata.get_children()?;
-        for child in children {
-          queue.push_back(child);
-        }
+      let children = match widget {
+        ChildUserData::Box(box_userdata) => box_userdata.get_children()?,
+        ChildUserData::Revealer(revealer_userdata) => revealer_userdata.get_children()?,
+        ChildUserData::Stack(stack_userdata) => stack_userdata.get_children()?,
+        _ => continue,
+      };
+      for child in children {
+        queue.push_back(child);
       }
     }
   }
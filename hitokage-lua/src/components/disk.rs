@@ -0,0 +1,61 @@
+use crate::{impl_getter_fn, impl_setter_fn};
+use hitokage_core::components::disk::DiskMsg;
+use hitokage_core::components::disk::DiskMsgHook::BaseHook;
+use hitokage_core::components::disk::DiskMsgHook::{GetFormat, GetFormatReactive, GetMounts, SetFormat, SetMounts};
+use hitokage_core::structs::reactive::Reactive;
+use hitokage_core::structs::Align;
+use hitokage_macros::impl_lua_base;
+use mlua::{LuaSerdeExt, UserData, UserDataMethods, Value};
+
+#[derive(Debug, Clone)]
+pub struct DiskUserData {
+  pub r#type: String,
+  pub sender: relm4::Sender<DiskMsg>,
+}
+
+#[impl_lua_base(DiskMsg::LuaHook)]
+impl DiskUserData {
+  fn sender(&self) -> Result<relm4::Sender<DiskMsg>, crate::HitokageError> {
+    Ok(self.sender.clone())
+  }
+
+  impl_getter_fn!(get_format, DiskMsg::LuaHook, GetFormat, String);
+  impl_getter_fn!(
+    get_format_reactive,
+    DiskMsg::LuaHook,
+    GetFormatReactive,
+    Reactive<String>
+  );
+  impl_setter_fn!(set_format, DiskMsg::LuaHook, SetFormat, String);
+
+  impl_getter_fn!(get_mounts, DiskMsg::LuaHook, GetMounts, Vec<String>);
+  impl_setter_fn!(set_mounts, DiskMsg::LuaHook, SetMounts, Vec<String>);
+}
+
+#[impl_lua_base]
+impl UserData for DiskUserData {
+  fn add_methods<'lua, M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("get_type", |_, this, _: ()| Ok(this.r#type.clone()));
+
+    methods.add_method("get_format", |_, this, _: ()| Ok(this.get_format()?));
+    methods.add_method("get_format_reactive", |_, this, _: ()| Ok(this.get_format_reactive()?));
+    methods.add_method("set_format", |lua, this, value: mlua::Value| {
+      this.set_format(lua, value)
+    });
+
+    methods.add_method("get_mounts", |_, this, _: ()| Ok(this.get_mounts()?));
+    methods.add_method("set_mounts", |lua, this, args: mlua::Variadic<Value>| {
+      this.set_mounts(lua, args)
+    });
+
+    methods.add_meta_method("__index", |lua, instance, value| -> Result<mlua::Value, mlua::Error> {
+      match value {
+        Value::String(s) => match s.to_str()?.as_ref() {
+          "type" => Ok(lua.to_value(&instance.r#type.clone())?),
+          _ => Ok(Value::Nil),
+        },
+        _ => Ok(Value::Nil),
+      }
+    })
+  }
+}
@@ -0,0 +1,53 @@
+use crate::{impl_getter_fn, impl_setter_fn};
+use hitokage_core::components::graph::GraphMsg;
+use hitokage_core::components::graph::GraphMsgHook::BaseHook;
+use hitokage_core::components::graph::GraphMsgHook::{GetMax, GetMin, GetSamples, SetMax, SetMin};
+use hitokage_core::structs::Align;
+use hitokage_macros::impl_lua_base;
+use mlua::{LuaSerdeExt, UserData, UserDataMethods, Value};
+
+#[derive(Debug, Clone)]
+pub struct GraphUserData {
+  pub r#type: String,
+  pub sender: relm4::Sender<GraphMsg>,
+}
+
+#[impl_lua_base(GraphMsg::LuaHook)]
+impl GraphUserData {
+  fn sender(&self) -> Result<relm4::Sender<GraphMsg>, crate::HitokageError> {
+    Ok(self.sender.clone())
+  }
+
+  impl_getter_fn!(get_samples, GraphMsg::LuaHook, GetSamples, Vec<f64>);
+
+  impl_getter_fn!(get_min, GraphMsg::LuaHook, GetMin, f64);
+  impl_setter_fn!(set_min, GraphMsg::LuaHook, SetMin, Option<f64>);
+
+  impl_getter_fn!(get_max, GraphMsg::LuaHook, GetMax, Option<f64>);
+  impl_setter_fn!(set_max, GraphMsg::LuaHook, SetMax, Option<f64>);
+}
+
+#[impl_lua_base]
+impl UserData for GraphUserData {
+  fn add_methods<'lua, M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("get_type", |_, this, _: ()| Ok(this.r#type.clone()));
+
+    methods.add_method("get_samples", |_, this, _: ()| Ok(this.get_samples()?));
+
+    methods.add_method("get_min", |_, this, _: ()| Ok(this.get_min()?));
+    methods.add_method("set_min", |lua, this, value: mlua::Value| this.set_min(lua, value));
+
+    methods.add_method("get_max", |_, this, _: ()| Ok(this.get_max()?));
+    methods.add_method("set_max", |lua, this, value: mlua::Value| this.set_max(lua, value));
+
+    methods.add_meta_method("__index", |lua, instance, value| -> Result<mlua::Value, mlua::Error> {
+      match value {
+        Value::String(s) => match s.to_str()?.as_ref() {
+          "type" => Ok(lua.to_value(&instance.r#type.clone())?),
+          _ => Ok(Value::Nil),
+        },
+        _ => Ok(Value::Nil),
+      }
+    })
+  }
+}
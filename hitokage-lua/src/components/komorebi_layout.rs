@@ -0,0 +1,64 @@
+use crate::{impl_getter_fn, impl_setter_fn};
+use hitokage_core::components::komorebi_layout::KomorebiLayoutMsg;
+use hitokage_core::components::komorebi_layout::KomorebiLayoutMsgHook::BaseHook;
+use hitokage_core::components::komorebi_layout::KomorebiLayoutMsgHook::{
+  GetFormat, GetLayout, Next, Previous, SetFormat,
+};
+use hitokage_core::structs::Align;
+use hitokage_macros::impl_lua_base;
+use mlua::{LuaSerdeExt, UserData, UserDataMethods, Value};
+
+#[derive(Debug, Clone)]
+pub struct KomorebiLayoutUserData {
+  pub r#type: String,
+  pub sender: relm4::Sender<KomorebiLayoutMsg>,
+}
+
+#[impl_lua_base(KomorebiLayoutMsg::LuaHook)]
+impl KomorebiLayoutUserData {
+  fn sender(&self) -> Result<relm4::Sender<KomorebiLayoutMsg>, crate::HitokageError> {
+    Ok(self.sender.clone())
+  }
+
+  impl_getter_fn!(get_format, KomorebiLayoutMsg::LuaHook, GetFormat, String);
+  impl_setter_fn!(set_format, KomorebiLayoutMsg::LuaHook, SetFormat, String);
+
+  impl_getter_fn!(get_layout, KomorebiLayoutMsg::LuaHook, GetLayout, Option<String>);
+
+  fn next(&self) -> Result<(), crate::HitokageError> {
+    self.sender()?.send(KomorebiLayoutMsg::LuaHook(Next)).unwrap();
+    Ok(())
+  }
+
+  fn previous(&self) -> Result<(), crate::HitokageError> {
+    self.sender()?.send(KomorebiLayoutMsg::LuaHook(Previous)).unwrap();
+    Ok(())
+  }
+}
+
+#[impl_lua_base]
+impl UserData for KomorebiLayoutUserData {
+  fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("get_type", |_, this, _: ()| Ok(this.r#type.clone()));
+
+    methods.add_method("get_format", |_, this, _: ()| Ok(this.get_format()?));
+    methods.add_method("set_format", |lua, this, value: mlua::Value| {
+      this.set_format(lua, value)
+    });
+
+    methods.add_method("get_layout", |_, this, _: ()| Ok(this.get_layout()?));
+
+    methods.add_method("next", |_, this, _: ()| Ok(this.next()?));
+    methods.add_method("previous", |_, this, _: ()| Ok(this.previous()?));
+
+    methods.add_meta_method::<_, mlua::String, _>(
+      "__index",
+      |lua, instance, key| -> Result<mlua::Value, mlua::Error> {
+        match key.to_str()?.as_ref() {
+          "type" => Ok(lua.to_value(&instance.r#type.clone())?),
+          _ => Ok(Value::Nil),
+        }
+      },
+    );
+  }
+}
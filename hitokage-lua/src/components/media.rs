@@ -0,0 +1,82 @@
+use crate::{impl_getter_fn, impl_setter_fn};
+use hitokage_core::components::media::MediaMsg;
+use hitokage_core::components::media::MediaMsgHook::BaseHook;
+use hitokage_core::components::media::MediaMsgHook::{
+  GetControls, GetFormat, GetFormatReactive, Next, PlayPause, Previous, SetControls, SetFormat,
+};
+use hitokage_core::structs::reactive::Reactive;
+use hitokage_core::structs::Align;
+use hitokage_macros::impl_lua_base;
+use mlua::{LuaSerdeExt, UserData, UserDataMethods, Value};
+
+#[derive(Debug, Clone)]
+pub struct MediaUserData {
+  pub r#type: String,
+  pub sender: relm4::Sender<MediaMsg>,
+}
+
+#[impl_lua_base(MediaMsg::LuaHook)]
+impl MediaUserData {
+  fn sender(&self) -> Result<relm4::Sender<MediaMsg>, crate::HitokageError> {
+    Ok(self.sender.clone())
+  }
+
+  impl_getter_fn!(get_format, MediaMsg::LuaHook, GetFormat, String);
+  impl_getter_fn!(
+    get_format_reactive,
+    MediaMsg::LuaHook,
+    GetFormatReactive,
+    Reactive<String>
+  );
+  impl_setter_fn!(set_format, MediaMsg::LuaHook, SetFormat, String);
+
+  impl_getter_fn!(get_controls, MediaMsg::LuaHook, GetControls, bool);
+  impl_setter_fn!(set_controls, MediaMsg::LuaHook, SetControls, bool);
+
+  fn play_pause(&self) -> Result<(), crate::HitokageError> {
+    self.sender()?.send(MediaMsg::LuaHook(PlayPause)).unwrap();
+    Ok(())
+  }
+
+  fn next(&self) -> Result<(), crate::HitokageError> {
+    self.sender()?.send(MediaMsg::LuaHook(Next)).unwrap();
+    Ok(())
+  }
+
+  fn previous(&self) -> Result<(), crate::HitokageError> {
+    self.sender()?.send(MediaMsg::LuaHook(Previous)).unwrap();
+    Ok(())
+  }
+}
+
+#[impl_lua_base]
+impl UserData for MediaUserData {
+  fn add_methods<'lua, M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("get_type", |_, this, _: ()| Ok(this.r#type.clone()));
+
+    methods.add_method("get_format", |_, this, _: ()| Ok(this.get_format()?));
+    methods.add_method("get_format_reactive", |_, this, _: ()| Ok(this.get_format_reactive()?));
+    methods.add_method("set_format", |lua, this, value: mlua::Value| {
+      this.set_format(lua, value)
+    });
+
+    methods.add_method("get_controls", |_, this, _: ()| Ok(this.get_controls()?));
+    methods.add_method("set_controls", |lua, this, value: mlua::Value| {
+      this.set_controls(lua, value)
+    });
+
+    methods.add_method("play_pause", |_, this, _: ()| Ok(this.play_pause()?));
+    methods.add_method("next", |_, this, _: ()| Ok(this.next()?));
+    methods.add_method("previous", |_, this, _: ()| Ok(this.previous()?));
+
+    methods.add_meta_method("__index", |lua, instance, value| -> Result<mlua::Value, mlua::Error> {
+      match value {
+        Value::String(s) => match s.to_str()?.as_ref() {
+          "type" => Ok(lua.to_value(&instance.r#type.clone())?),
+          _ => Ok(Value::Nil),
+        },
+        _ => Ok(Value::Nil),
+      }
+    })
+  }
+}
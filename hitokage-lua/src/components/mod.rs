@@ -1,36 +1,78 @@
 use battery::BatteryUserData;
+use button::ButtonUserData;
 use clock::ClockUserData;
 use cpu::CpuUserData;
+use disk::DiskUserData;
+use graph::GraphUserData;
 use hitokage_core::components::ChildUserData as CoreChildUserData;
 use icon::IconUserData;
+use komorebi_layout::KomorebiLayoutUserData;
 use label::LabelUserData;
+use level::LevelUserData;
+use media::MediaUserData;
 use memory::MemoryUserData;
 use mlua::{IntoLua, Lua};
+use network::NetworkUserData;
 use r#box::BoxUserData;
+use revealer::RevealerUserData;
+use scale::ScaleUserData;
+use stack::StackUserData;
 use std::sync::Arc;
+use system_info::SystemInfoUserData;
+use taskbar::TaskbarUserData;
+use temperature::TemperatureUserData;
 use weather::WeatherUserData;
+use window_title::WindowTitleUserData;
 use workspace::WorkspaceUserData;
 
 pub mod bar;
 pub mod battery;
 pub mod r#box;
+pub mod button;
 pub mod clock;
 pub mod cpu;
+pub mod disk;
+pub mod graph;
 pub mod icon;
+pub mod komorebi_layout;
 pub mod label;
+pub mod level;
+pub mod media;
 pub mod memory;
+pub mod network;
+pub mod revealer;
+pub mod scale;
+pub mod stack;
+pub mod system_info;
+pub mod taskbar;
+pub mod temperature;
 pub mod weather;
+pub mod window_title;
 pub mod workspace;
 
 pub(crate) enum ChildUserData {
   Battery(BatteryUserData),
   Box(BoxUserData),
+  Button(ButtonUserData),
   Clock(ClockUserData),
   Cpu(CpuUserData),
+  Disk(DiskUserData),
+  Graph(GraphUserData),
   Icon(IconUserData),
+  KomorebiLayout(KomorebiLayoutUserData),
   Label(LabelUserData),
+  Level(LevelUserData),
+  Media(MediaUserData),
   Memory(MemoryUserData),
+  Network(NetworkUserData),
+  Revealer(RevealerUserData),
+  Scale(ScaleUserData),
+  Stack(StackUserData),
+  SystemInfo(SystemInfoUserData),
+  Taskbar(TaskbarUserData),
+  Temperature(TemperatureUserData),
   Weather(WeatherUserData),
+  WindowTitle(WindowTitleUserData),
   Workspace(WorkspaceUserData),
 }
 
@@ -39,12 +81,26 @@ impl ChildUserData {
     match self {
       ChildUserData::Battery(userdata) => userdata.get_id().unwrap(),
       ChildUserData::Box(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Button(userdata) => userdata.get_id().unwrap(),
       ChildUserData::Clock(userdata) => userdata.get_id().unwrap(),
       ChildUserData::Cpu(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Disk(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Graph(userdata) => userdata.get_id().unwrap(),
       ChildUserData::Icon(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::KomorebiLayout(userdata) => userdata.get_id().unwrap(),
       ChildUserData::Label(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Level(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Media(userdata) => userdata.get_id().unwrap(),
       ChildUserData::Memory(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Network(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Revealer(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Scale(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Stack(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::SystemInfo(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Taskbar(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::Temperature(userdata) => userdata.get_id().unwrap(),
       ChildUserData::Weather(userdata) => userdata.get_id().unwrap(),
+      ChildUserData::WindowTitle(userdata) => userdata.get_id().unwrap(),
       ChildUserData::Workspace(userdata) => userdata.get_id().unwrap(),
     }
   }
@@ -55,12 +111,26 @@ impl IntoLua for ChildUserData {
     match self {
       ChildUserData::Battery(userdata) => lua.pack(userdata),
       ChildUserData::Box(userdata) => lua.pack(userdata),
+      ChildUserData::Button(userdata) => lua.pack(userdata),
       ChildUserData::Clock(userdata) => lua.pack(userdata),
       ChildUserData::Cpu(userdata) => lua.pack(userdata),
+      ChildUserData::Disk(userdata) => lua.pack(userdata),
+      ChildUserData::Graph(userdata) => lua.pack(userdata),
       ChildUserData::Icon(userdata) => lua.pack(userdata),
+      ChildUserData::KomorebiLayout(userdata) => lua.pack(userdata),
       ChildUserData::Label(userdata) => lua.pack(userdata),
+      ChildUserData::Level(userdata) => lua.pack(userdata),
+      ChildUserData::Media(userdata) => lua.pack(userdata),
       ChildUserData::Memory(userdata) => lua.pack(userdata),
+      ChildUserData::Network(userdata) => lua.pack(userdata),
+      ChildUserData::Revealer(userdata) => lua.pack(userdata),
+      ChildUserData::Scale(userdata) => lua.pack(userdata),
+      ChildUserData::Stack(userdata) => lua.pack(userdata),
+      ChildUserData::SystemInfo(userdata) => lua.pack(userdata),
+      ChildUserData::Taskbar(userdata) => lua.pack(userdata),
+      ChildUserData::Temperature(userdata) => lua.pack(userdata),
       ChildUserData::Weather(userdata) => lua.pack(userdata),
+      ChildUserData::WindowTitle(userdata) => lua.pack(userdata),
       ChildUserData::Workspace(userdata) => lua.pack(userdata),
     }
   }
@@ -77,6 +147,10 @@ impl From<CoreChildUserData> for ChildUserData {
         r#type: "Box".to_string(),
         sender,
       }),
+      CoreChildUserData::Button(sender) => ChildUserData::Button(ButtonUserData {
+        r#type: "Button".to_string(),
+        sender,
+      }),
       CoreChildUserData::Clock(sender) => ChildUserData::Clock(ClockUserData {
         r#type: "Clock".to_string(),
         sender,
@@ -85,22 +159,74 @@ impl From<CoreChildUserData> for ChildUserData {
         r#type: "Cpu".to_string(),
         sender,
       }),
+      CoreChildUserData::Disk(sender) => ChildUserData::Disk(DiskUserData {
+        r#type: "Disk".to_string(),
+        sender,
+      }),
+      CoreChildUserData::Graph(sender) => ChildUserData::Graph(GraphUserData {
+        r#type: "Graph".to_string(),
+        sender,
+      }),
       CoreChildUserData::Icon(sender) => ChildUserData::Icon(IconUserData {
         r#type: "Icon".to_string(),
         sender,
       }),
+      CoreChildUserData::KomorebiLayout(sender) => ChildUserData::KomorebiLayout(KomorebiLayoutUserData {
+        r#type: "KomorebiLayout".to_string(),
+        sender,
+      }),
       CoreChildUserData::Label(sender) => ChildUserData::Label(LabelUserData {
         r#type: "Label".to_string(),
         sender,
       }),
+      CoreChildUserData::Level(sender) => ChildUserData::Level(LevelUserData {
+        r#type: "Level".to_string(),
+        sender,
+      }),
+      CoreChildUserData::Media(sender) => ChildUserData::Media(MediaUserData {
+        r#type: "Media".to_string(),
+        sender,
+      }),
       CoreChildUserData::Memory(sender) => ChildUserData::Memory(MemoryUserData {
         r#type: "Memory".to_string(),
         sender,
       }),
+      CoreChildUserData::Network(sender) => ChildUserData::Network(NetworkUserData {
+        r#type: "Network".to_string(),
+        sender,
+      }),
+      CoreChildUserData::Revealer(sender) => ChildUserData::Revealer(RevealerUserData {
+        r#type: "Revealer".to_string(),
+        sender,
+      }),
+      CoreChildUserData::Scale(sender) => ChildUserData::Scale(ScaleUserData {
+        r#type: "Scale".to_string(),
+        sender,
+      }),
+      CoreChildUserData::Stack(sender) => ChildUserData::Stack(StackUserData {
+        r#type: "Stack".to_string(),
+        sender,
+      }),
+      CoreChildUserData::SystemInfo(sender) => ChildUserData::SystemInfo(SystemInfoUserData {
+        r#type: "SystemInfo".to_string(),
+        sender,
+      }),
+      CoreChildUserData::Taskbar(sender) => ChildUserData::Taskbar(TaskbarUserData {
+        r#type: "Taskbar".to_string(),
+        sender,
+      }),
+      CoreChildUserData::Temperature(sender) => ChildUserData::Temperature(TemperatureUserData {
+        r#type: "Temperature".to_string(),
+        sender,
+      }),
       CoreChildUserData::Weather(sender) => ChildUserData::Weather(WeatherUserData {
         r#type: "Weather".to_string(),
         sender,
       }),
+      CoreChildUserData::WindowTitle(sender) => ChildUserData::WindowTitle(WindowTitleUserData {
+        r#type: "WindowTitle".to_string(),
+        sender,
+      }),
       CoreChildUserData::Workspace(sender) => ChildUserData::Workspace(WorkspaceUserData {
         r#type: "Workspace".to_string(),
         sender,
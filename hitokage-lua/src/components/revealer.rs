@@ -0,0 +1,91 @@
+use super::{ChildUserDataVec, HoldsChildren};
+use crate::impl_lua_get_child_by_id;
+use crate::{impl_getter_fn, impl_setter_fn};
+use hitokage_core::components::revealer::RevealerMsgHook::BaseHook;
+use hitokage_core::components::revealer::RevealerMsgHook::{
+  GetChildren, GetHomogeneous, GetRevealed, GetTransitionDuration, SetHomogeneous, SetRevealed, SetTransitionDuration,
+};
+use hitokage_core::components::revealer::RevealerMsgPortable;
+use hitokage_core::structs::Align;
+use hitokage_macros::impl_lua_base;
+use mlua::{LuaSerdeExt, UserData, UserDataMethods, Value};
+
+#[derive(Debug, Clone)]
+pub struct RevealerUserData {
+  pub r#type: String,
+  pub sender: relm4::Sender<RevealerMsgPortable>,
+}
+
+#[impl_lua_base(RevealerMsgPortable::LuaHook)]
+impl RevealerUserData {
+  fn sender(&self) -> Result<relm4::Sender<RevealerMsgPortable>, crate::HitokageError> {
+    Ok(self.sender.clone())
+  }
+
+  // BOX PROPERTIES START
+  impl_getter_fn!(get_homogeneous, RevealerMsgPortable::LuaHook, GetHomogeneous, bool);
+  impl_setter_fn!(set_homogeneous, RevealerMsgPortable::LuaHook, SetHomogeneous, bool);
+  // BOX PROPERTIES END
+
+  impl_getter_fn!(get_revealed, RevealerMsgPortable::LuaHook, GetRevealed, bool);
+  impl_setter_fn!(set_revealed, RevealerMsgPortable::LuaHook, SetRevealed, bool);
+
+  #[rustfmt::skip]
+  impl_getter_fn!(get_transition_duration, RevealerMsgPortable::LuaHook, GetTransitionDuration, u32);
+  #[rustfmt::skip]
+  impl_setter_fn!(set_transition_duration, RevealerMsgPortable::LuaHook, SetTransitionDuration, u32);
+}
+
+impl HoldsChildren for RevealerUserData {
+  impl_getter_fn!(,get_children, RevealerMsgPortable::LuaHook, GetChildren, ChildUserDataVec);
+}
+
+#[impl_lua_base]
+impl UserData for RevealerUserData {
+  fn add_methods<'lua, M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("get_type", |_, this, _: ()| Ok(this.r#type.clone()));
+
+    // BOX PROPERTIES START
+    methods.add_method("get_homogeneous", |lua, instance, ()| {
+      lua.to_value(&instance.get_homogeneous()?)
+    });
+    methods.add_method("set_homogeneous", |lua, this, value: mlua::Value| {
+      this.set_homogeneous(lua, value)
+    });
+
+    methods.add_method("get_children", |lua, instance, ()| lua.pack(instance.get_children()?));
+    methods.add_method("get_widgets", |lua, instance, ()| lua.pack(instance.get_children()?));
+    // BOX PROPERTIES END
+
+    methods.add_method("get_revealed", |_, this, _: ()| Ok(this.get_revealed()?));
+    methods.add_method("set_revealed", |lua, this, value: mlua::Value| {
+      this.set_revealed(lua, value)
+    });
+    methods.add_method("toggle", |lua, this, _: ()| {
+      let revealed = this.get_revealed()?;
+      this.set_revealed(lua, Value::Boolean(!revealed))
+    });
+
+    methods.add_method("get_transition_duration", |_, this, _: ()| {
+      Ok(this.get_transition_duration()?)
+    });
+    methods.add_method("set_transition_duration", |lua, this, value: mlua::Value| {
+      this.set_transition_duration(lua, value)
+    });
+
+    impl_lua_get_child_by_id!(methods);
+
+    methods.add_meta_method("__index", |lua, instance, value| -> Result<mlua::Value, mlua::Error> {
+      match value {
+        Value::String(s) => match s.to_str()?.as_ref() {
+          "type" => Ok(lua.to_value(&instance.r#type.clone())?),
+          "children" => Ok(lua.pack(instance.get_children()?)?),
+          "widgets" => Ok(lua.pack(instance.get_children()?)?),
+          "revealed" => Ok(lua.to_value(&instance.get_revealed()?)?),
+          _ => Ok(Value::Nil),
+        },
+        _ => Ok(Value::Nil),
+      }
+    })
+  }
+}
@@ -0,0 +1,63 @@
+use crate::{impl_getter_fn, impl_setter_fn};
+use hitokage_core::components::scale::ScaleMsg;
+use hitokage_core::components::scale::ScaleMsgHook::BaseHook;
+use hitokage_core::components::scale::ScaleMsgHook::{
+  GetMax, GetMin, GetStep, GetValue, SetMax, SetMin, SetStep, SetValue,
+};
+use hitokage_core::structs::Align;
+use hitokage_macros::impl_lua_base;
+use mlua::{LuaSerdeExt, UserData, UserDataMethods, Value};
+
+#[derive(Debug, Clone)]
+pub struct ScaleUserData {
+  pub r#type: String,
+  pub sender: relm4::Sender<ScaleMsg>,
+}
+
+#[impl_lua_base(ScaleMsg::LuaHook)]
+impl ScaleUserData {
+  fn sender(&self) -> Result<relm4::Sender<ScaleMsg>, crate::HitokageError> {
+    Ok(self.sender.clone())
+  }
+
+  impl_getter_fn!(get_value, ScaleMsg::LuaHook, GetValue, f64);
+  impl_setter_fn!(set_value, ScaleMsg::LuaHook, SetValue, f64);
+
+  impl_getter_fn!(get_min, ScaleMsg::LuaHook, GetMin, f64);
+  impl_setter_fn!(set_min, ScaleMsg::LuaHook, SetMin, f64);
+
+  impl_getter_fn!(get_max, ScaleMsg::LuaHook, GetMax, f64);
+  impl_setter_fn!(set_max, ScaleMsg::LuaHook, SetMax, f64);
+
+  impl_getter_fn!(get_step, ScaleMsg::LuaHook, GetStep, f64);
+  impl_setter_fn!(set_step, ScaleMsg::LuaHook, SetStep, f64);
+}
+
+#[impl_lua_base]
+impl UserData for ScaleUserData {
+  fn add_methods<'lua, M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("get_type", |_, this, _: ()| Ok(this.r#type.clone()));
+
+    methods.add_method("get_value", |_, this, _: ()| Ok(this.get_value()?));
+    methods.add_method("set_value", |lua, this, value: mlua::Value| this.set_value(lua, value));
+
+    methods.add_method("get_min", |_, this, _: ()| Ok(this.get_min()?));
+    methods.add_method("set_min", |lua, this, value: mlua::Value| this.set_min(lua, value));
+
+    methods.add_method("get_max", |_, this, _: ()| Ok(this.get_max()?));
+    methods.add_method("set_max", |lua, this, value: mlua::Value| this.set_max(lua, value));
+
+    methods.add_method("get_step", |_, this, _: ()| Ok(this.get_step()?));
+    methods.add_method("set_step", |lua, this, value: mlua::Value| this.set_step(lua, value));
+
+    methods.add_meta_method("__index", |lua, instance, value| -> Result<mlua::Value, mlua::Error> {
+      match value {
+        Value::String(s) => match s.to_str()?.as_ref() {
+          "type" => Ok(lua.to_value(&instance.r#type.clone())?),
+          _ => Ok(Value::Nil),
+        },
+        _ => Ok(Value::Nil),
+      }
+    })
+  }
+}
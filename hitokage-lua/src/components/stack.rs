@@ -0,0 +1,76 @@
+use super::{ChildUserDataVec, HoldsChildren};
+use crate::impl_lua_get_child_by_id;
+use crate::{impl_getter_fn, impl_setter_fn};
+use hitokage_core::components::stack::StackMsgHook::BaseHook;
+use hitokage_core::components::stack::StackMsgHook::{GetChildren, GetVisibleChild, Next, Previous, SetVisibleChild};
+use hitokage_core::components::stack::StackMsgPortable;
+use hitokage_core::structs::Align;
+use hitokage_macros::impl_lua_base;
+use mlua::{LuaSerdeExt, UserData, UserDataMethods, Value};
+
+#[derive(Debug, Clone)]
+pub struct StackUserData {
+  pub r#type: String,
+  pub sender: relm4::Sender<StackMsgPortable>,
+}
+
+#[impl_lua_base(StackMsgPortable::LuaHook)]
+impl StackUserData {
+  fn sender(&self) -> Result<relm4::Sender<StackMsgPortable>, crate::HitokageError> {
+    Ok(self.sender.clone())
+  }
+
+  impl_getter_fn!(
+    get_visible_child,
+    StackMsgPortable::LuaHook,
+    GetVisibleChild,
+    Option<String>
+  );
+  impl_setter_fn!(set_visible_child, StackMsgPortable::LuaHook, SetVisibleChild, String);
+
+  fn next(&self) -> Result<(), crate::HitokageError> {
+    self.sender()?.send(StackMsgPortable::LuaHook(Next)).unwrap();
+    Ok(())
+  }
+
+  fn previous(&self) -> Result<(), crate::HitokageError> {
+    self.sender()?.send(StackMsgPortable::LuaHook(Previous)).unwrap();
+    Ok(())
+  }
+}
+
+impl HoldsChildren for StackUserData {
+  impl_getter_fn!(,get_children, StackMsgPortable::LuaHook, GetChildren, ChildUserDataVec);
+}
+
+#[impl_lua_base]
+impl UserData for StackUserData {
+  fn add_methods<'lua, M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("get_type", |_, this, _: ()| Ok(this.r#type.clone()));
+
+    methods.add_method("get_children", |lua, instance, ()| lua.pack(instance.get_children()?));
+    methods.add_method("get_widgets", |lua, instance, ()| lua.pack(instance.get_children()?));
+
+    methods.add_method("get_visible_child", |_, this, _: ()| Ok(this.get_visible_child()?));
+    methods.add_method("set_visible_child", |lua, this, value: mlua::Value| {
+      this.set_visible_child(lua, value)
+    });
+    methods.add_method("next", |_, this, _: ()| Ok(this.next()?));
+    methods.add_method("previous", |_, this, _: ()| Ok(this.previous()?));
+
+    impl_lua_get_child_by_id!(methods);
+
+    methods.add_meta_method("__index", |lua, instance, value| -> Result<mlua::Value, mlua::Error> {
+      match value {
+        Value::String(s) => match s.to_str()?.as_ref() {
+          "type" => Ok(lua.to_value(&instance.r#type.clone())?),
+          "children" => Ok(lua.pack(instance.get_children()?)?),
+          "widgets" => Ok(lua.pack(instance.get_children()?)?),
+          "visible_child" => Ok(lua.to_value(&instance.get_visible_child()?)?),
+          _ => Ok(Value::Nil),
+        },
+        _ => Ok(Value::Nil),
+      }
+    })
+  }
+}
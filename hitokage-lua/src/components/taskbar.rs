@@ -0,0 +1,63 @@
+use crate::{impl_getter_fn, impl_setter_fn};
+use hitokage_core::components::taskbar::TaskbarMsg;
+use hitokage_core::components::taskbar::TaskbarMsgHook::BaseHook;
+use hitokage_core::components::taskbar::TaskbarMsgHook::{
+  GetFormat, GetIconSize, GetMaxLength, SetFormat, SetIconSize, SetMaxLength,
+};
+use hitokage_core::structs::Align;
+use hitokage_macros::impl_lua_base;
+use mlua::{LuaSerdeExt, UserData, UserDataMethods, Value};
+
+#[derive(Debug, Clone)]
+pub struct TaskbarUserData {
+  pub r#type: String,
+  pub sender: relm4::Sender<TaskbarMsg>,
+}
+
+#[impl_lua_base(TaskbarMsg::LuaHook)]
+impl TaskbarUserData {
+  fn sender(&self) -> Result<relm4::Sender<TaskbarMsg>, crate::HitokageError> {
+    Ok(self.sender.clone())
+  }
+
+  impl_getter_fn!(get_format, TaskbarMsg::LuaHook, GetFormat, String);
+  impl_setter_fn!(set_format, TaskbarMsg::LuaHook, SetFormat, String);
+
+  impl_getter_fn!(get_max_length, TaskbarMsg::LuaHook, GetMaxLength, Option<u32>);
+  impl_setter_fn!(set_max_length, TaskbarMsg::LuaHook, SetMaxLength, Option<u32>);
+
+  impl_getter_fn!(get_icon_size, TaskbarMsg::LuaHook, GetIconSize, i32);
+  impl_setter_fn!(set_icon_size, TaskbarMsg::LuaHook, SetIconSize, i32);
+}
+
+#[impl_lua_base]
+impl UserData for TaskbarUserData {
+  fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("get_type", |_, this, _: ()| Ok(this.r#type.clone()));
+
+    methods.add_method("get_format", |_, this, _: ()| Ok(this.get_format()?));
+    methods.add_method("set_format", |lua, this, value: mlua::Value| {
+      this.set_format(lua, value)
+    });
+
+    methods.add_method("get_max_length", |_, this, _: ()| Ok(this.get_max_length()?));
+    methods.add_method("set_max_length", |lua, this, value: mlua::Value| {
+      this.set_max_length(lua, value)
+    });
+
+    methods.add_method("get_icon_size", |_, this, _: ()| Ok(this.get_icon_size()?));
+    methods.add_method("set_icon_size", |lua, this, value: mlua::Value| {
+      this.set_icon_size(lua, value)
+    });
+
+    methods.add_meta_method::<_, mlua::String, _>(
+      "__index",
+      |lua, instance, key| -> Result<mlua::Value, mlua::Error> {
+        match key.to_str()?.as_ref() {
+          "type" => Ok(lua.to_value(&instance.r#type.clone())?),
+          _ => Ok(Value::Nil),
+        }
+      },
+    );
+  }
+}
@@ -0,0 +1,75 @@
+use crate::{impl_getter_fn, impl_setter_fn};
+use hitokage_core::components::temperature::TemperatureMsg;
+use hitokage_core::components::temperature::TemperatureMsgHook::BaseHook;
+use hitokage_core::components::temperature::TemperatureMsgHook::{
+  GetCrit, GetFormat, GetFormatReactive, GetSensor, GetWarn, SetCrit, SetFormat, SetSensor, SetWarn,
+};
+use hitokage_core::structs::reactive::Reactive;
+use hitokage_core::structs::Align;
+use hitokage_macros::impl_lua_base;
+use mlua::{LuaSerdeExt, UserData, UserDataMethods, Value};
+
+#[derive(Debug, Clone)]
+pub struct TemperatureUserData {
+  pub r#type: String,
+  pub sender: relm4::Sender<TemperatureMsg>,
+}
+
+#[impl_lua_base(TemperatureMsg::LuaHook)]
+impl TemperatureUserData {
+  fn sender(&self) -> Result<relm4::Sender<TemperatureMsg>, crate::HitokageError> {
+    Ok(self.sender.clone())
+  }
+
+  impl_getter_fn!(get_format, TemperatureMsg::LuaHook, GetFormat, String);
+  impl_getter_fn!(
+    get_format_reactive,
+    TemperatureMsg::LuaHook,
+    GetFormatReactive,
+    Reactive<String>
+  );
+  impl_setter_fn!(set_format, TemperatureMsg::LuaHook, SetFormat, String);
+
+  impl_getter_fn!(get_sensor, TemperatureMsg::LuaHook, GetSensor, Option<String>);
+  impl_setter_fn!(set_sensor, TemperatureMsg::LuaHook, SetSensor, Option<String>);
+
+  impl_getter_fn!(get_warn, TemperatureMsg::LuaHook, GetWarn, Option<f32>);
+  impl_setter_fn!(set_warn, TemperatureMsg::LuaHook, SetWarn, Option<f32>);
+
+  impl_getter_fn!(get_crit, TemperatureMsg::LuaHook, GetCrit, Option<f32>);
+  impl_setter_fn!(set_crit, TemperatureMsg::LuaHook, SetCrit, Option<f32>);
+}
+
+#[impl_lua_base]
+impl UserData for TemperatureUserData {
+  fn add_methods<'lua, M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("get_type", |_, this, _: ()| Ok(this.r#type.clone()));
+
+    methods.add_method("get_format", |_, this, _: ()| Ok(this.get_format()?));
+    methods.add_method("get_format_reactive", |_, this, _: ()| Ok(this.get_format_reactive()?));
+    methods.add_method("set_format", |lua, this, value: mlua::Value| {
+      this.set_format(lua, value)
+    });
+
+    methods.add_method("get_sensor", |_, this, _: ()| Ok(this.get_sensor()?));
+    methods.add_method("set_sensor", |lua, this, value: mlua::Value| {
+      this.set_sensor(lua, value)
+    });
+
+    methods.add_method("get_warn", |_, this, _: ()| Ok(this.get_warn()?));
+    methods.add_method("set_warn", |lua, this, value: mlua::Value| this.set_warn(lua, value));
+
+    methods.add_method("get_crit", |_, this, _: ()| Ok(this.get_crit()?));
+    methods.add_method("set_crit", |lua, this, value: mlua::Value| this.set_crit(lua, value));
+
+    methods.add_meta_method("__index", |lua, instance, value| -> Result<mlua::Value, mlua::Error> {
+      match value {
+        Value::String(s) => match s.to_str()?.as_ref() {
+          "type" => Ok(lua.to_value(&instance.r#type.clone())?),
+          _ => Ok(Value::Nil),
+        },
+        _ => Ok(Value::Nil),
+      }
+    })
+  }
+}
@@ -0,0 +1,63 @@
+use crate::{impl_getter_fn, impl_setter_fn};
+use hitokage_core::components::window_title::WindowTitleMsg;
+use hitokage_core::components::window_title::WindowTitleMsgHook::BaseHook;
+use hitokage_core::components::window_title::WindowTitleMsgHook::{
+  GetEllipsize, GetFormat, GetMaxLength, SetEllipsize, SetFormat, SetMaxLength,
+};
+use hitokage_core::structs::{Align, EllipsizeMode};
+use hitokage_macros::impl_lua_base;
+use mlua::{LuaSerdeExt, UserData, UserDataMethods, Value};
+
+#[derive(Debug, Clone)]
+pub struct WindowTitleUserData {
+  pub r#type: String,
+  pub sender: relm4::Sender<WindowTitleMsg>,
+}
+
+#[impl_lua_base(WindowTitleMsg::LuaHook)]
+impl WindowTitleUserData {
+  fn sender(&self) -> Result<relm4::Sender<WindowTitleMsg>, crate::HitokageError> {
+    Ok(self.sender.clone())
+  }
+
+  impl_getter_fn!(get_format, WindowTitleMsg::LuaHook, GetFormat, String);
+  impl_setter_fn!(set_format, WindowTitleMsg::LuaHook, SetFormat, String);
+
+  impl_getter_fn!(get_max_length, WindowTitleMsg::LuaHook, GetMaxLength, Option<u32>);
+  impl_setter_fn!(set_max_length, WindowTitleMsg::LuaHook, SetMaxLength, Option<u32>);
+
+  impl_getter_fn!(get_ellipsize, WindowTitleMsg::LuaHook, GetEllipsize, EllipsizeMode);
+  impl_setter_fn!(set_ellipsize, WindowTitleMsg::LuaHook, SetEllipsize, EllipsizeMode);
+}
+
+#[impl_lua_base]
+impl UserData for WindowTitleUserData {
+  fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+    methods.add_method("get_type", |_, this, _: ()| Ok(this.r#type.clone()));
+
+    methods.add_method("get_format", |_, this, _: ()| Ok(this.get_format()?));
+    methods.add_method("set_format", |lua, this, value: mlua::Value| {
+      this.set_format(lua, value)
+    });
+
+    methods.add_method("get_max_length", |_, this, _: ()| Ok(this.get_max_length()?));
+    methods.add_method("set_max_length", |lua, this, value: mlua::Value| {
+      this.set_max_length(lua, value)
+    });
+
+    methods.add_method("get_ellipsize", |lua, this, _: ()| lua.to_value(&this.get_ellipsize()?));
+    methods.add_method("set_ellipsize", |lua, this, value: mlua::Value| {
+      this.set_ellipsize(lua, value)
+    });
+
+    methods.add_meta_method::<_, mlua::String, _>(
+      "__index",
+      |lua, instance, key| -> Result<mlua::Value, mlua::Error> {
+        match key.to_str()?.as_ref() {
+          "type" => Ok(lua.to_value(&instance.r#type.clone())?),
+          _ => Ok(Value::Nil),
+        }
+      },
+    );
+  }
+}
@@ -1,13 +1,15 @@
-use api::{actions, event, monitor, reactive};
+use api::{actions, event, monitor, reactive, require, sandbox, timer};
 use components::bar;
 use hitokage_core::components::app::AppMsg;
 use luahelper::ValuePrinter;
 use mlua::{AnyUserData, Lua, Table, Value, Variadic};
 use relm4::{Component, ComponentSender};
 use std::fmt;
+use std::path::PathBuf;
 
 pub mod api;
 pub mod components;
+pub mod scheduler;
 
 // Thanks @wez https://github.com/wez/wezterm/blob/b8f94c474ce48ac195b51c1aeacf41ae049b774e/config/src/lua.rs#L211
 
@@ -83,7 +85,8 @@ async fn sleep_ms(_: Lua, milliseconds: u64) -> mlua::Result<()> {
   Ok(())
 }
 
-pub fn make<C>(lua: mlua::Lua, sender: ComponentSender<C>) -> anyhow::Result<mlua::Lua>
+/// `config_dir` is where `require` looks for the user's own modules, ie. `~/.config/hitokage/`
+pub fn make<C>(lua: mlua::Lua, sender: ComponentSender<C>, config_dir: PathBuf) -> anyhow::Result<mlua::Lua>
 where
   C: Component<Input = AppMsg>,
   <C as Component>::Output: std::marker::Send,
@@ -97,6 +100,8 @@ where
     let event: Table = event::make(&lua, &sender)?;
     let reactive: Table = reactive::make(&lua)?;
     let actions: Table = actions::make(&lua)?;
+    let timer: Table = timer::make(&lua)?;
+    let sandbox: Table = sandbox::make(&lua)?;
 
     let unstable: Table = lua.create_table()?;
     unstable.set("reactive", reactive)?;
@@ -104,9 +109,12 @@ where
     let internals: Table = lua.create_table()?;
     internals.set("event", event)?;
     internals.set("actions", actions)?;
+    internals.set("call_with_budget", sandbox::make_call(&lua)?)?;
 
     hitokage_mod.set("monitor", monitor)?;
     hitokage_mod.set("bar", bar)?;
+    hitokage_mod.set("timer", timer)?;
+    hitokage_mod.set("sandbox", sandbox)?;
     hitokage_mod.set("unstable", unstable)?;
     hitokage_mod.set("_internals", internals)?;
 
@@ -141,7 +149,12 @@ where
 
     hitokage_mod.set("sleep_ms", lua.create_async_function(sleep_ms)?)?;
 
-    globals.set("hitokage", hitokage_mod)?;
+    globals.set("hitokage", hitokage_mod.clone())?;
+
+    // modules split out of init.lua can `require("hitokage")` rather than rely on the global
+    let package_loaded: Table = globals.get::<Table>("package")?.get("loaded")?;
+    package_loaded.set("hitokage", hitokage_mod)?;
+    require::make(&lua, &sender, config_dir)?;
 
     globals.set(
       "print",
@@ -0,0 +1,197 @@
+use hitokage_core::event::LUA_WAKER;
+use mlua::{AsyncThread, Lua, Table, Thread, ThreadStatus, Value};
+use smol::stream::Stream;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
+
+/// Raised inside a cancelled lua runtime, ie. to restart it after `init.lua` changed
+#[derive(Debug)]
+pub struct Interrupted;
+
+impl std::fmt::Display for Interrupted {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "the lua runtime was interrupted")
+  }
+}
+
+impl std::error::Error for Interrupted {}
+
+struct Task {
+  thread: Thread,
+  stream: Pin<Box<AsyncThread<Value>>>,
+  /// The thread yielded and should only be resumed once something it may be waiting on changes, otherwise it is
+  /// waiting on a future such as `hitokage.sleep_ms`
+  waits_for_event: bool,
+  /// The user script itself, errors in it stop the runtime rather than only the thread
+  is_main: bool,
+}
+
+impl Task {
+  fn new(thread: Thread, is_main: bool) -> Self {
+    Task {
+      stream: Box::pin(thread.clone().into_async(())),
+      thread,
+      waits_for_event: false,
+      is_main,
+    }
+  }
+}
+
+/// Drives the user script and every thread passed to `hitokage.dispatch`.
+///
+/// Nothing is polled on an interval. A thread that yields is resumed on the next [`LUA_WAKER`] wake (komorebi events,
+/// reactive changes and action requests), and a thread awaiting an async function such as `hitokage.sleep_ms` is
+/// resumed once that future is ready.
+pub struct Scheduler {
+  tasks: Vec<Task>,
+}
+
+impl Scheduler {
+  pub fn new(main: Thread) -> Self {
+    Scheduler {
+      tasks: vec![Task::new(main, true)],
+    }
+  }
+
+  /// Blocks until a thread yields a message for the runtime, ie. `"Suspend"`. Returns `None` once every thread has
+  /// finished. `is_parked` is set while the scheduler waits, so the deadlock watcher does not mistake idling for a loop.
+  ///
+  /// Fails with [`Interrupted`] once `is_cancelled` holds, which is checked whenever the scheduler wakes up
+  pub fn run(
+    &mut self,
+    lua: &Lua,
+    is_parked: &AtomicBool,
+    is_cancelled: impl Fn() -> bool,
+  ) -> mlua::Result<Option<Value>> {
+    let result = smol::block_on(std::future::poll_fn(|cx| {
+      is_parked.store(false, Ordering::SeqCst);
+      if is_cancelled() {
+        return Poll::Ready(Err(mlua::Error::external(Interrupted)));
+      }
+      let poll = self.poll(lua, cx);
+      if poll.is_pending() {
+        is_parked.store(true, Ordering::SeqCst);
+      }
+      poll
+    }));
+    is_parked.store(false, Ordering::SeqCst);
+    result
+  }
+
+  fn poll(&mut self, lua: &Lua, cx: &mut Context<'_>) -> Poll<mlua::Result<Option<Value>>> {
+    loop {
+      self.adopt_dispatched(lua)?;
+
+      if self.tasks.is_empty() {
+        return Poll::Ready(Ok(None));
+      }
+
+      let woken = LUA_WAKER.poll_woken(cx).is_ready();
+
+      let mut i = 0;
+      while i < self.tasks.len() {
+        let task = &mut self.tasks[i];
+        if task.waits_for_event && !woken {
+          i += 1;
+          continue;
+        }
+
+        match task.stream.as_mut().poll_next(cx) {
+          Poll::Ready(Some(Ok(value))) => {
+            if task.thread.status() == ThreadStatus::Finished {
+              self.tasks.remove(i);
+              continue;
+            }
+            task.waits_for_event = true;
+            match value {
+              Value::Nil | Value::Boolean(_) | Value::UserData(_) | Value::LightUserData(_) => (),
+              // anything else is a message for the runtime
+              _ => return Poll::Ready(Ok(Some(value))),
+            }
+          }
+          Poll::Ready(Some(Err(err))) => {
+            let task = self.tasks.remove(i);
+            if task.is_main {
+              return Poll::Ready(Err(err));
+            }
+            log::error!("Dispatched thread exited: {}", err);
+            continue;
+          }
+          Poll::Ready(None) => {
+            self.tasks.remove(i);
+            continue;
+          }
+          Poll::Pending => task.waits_for_event = false,
+        }
+        i += 1;
+      }
+
+      // go again for threads dispatched during this pass, a consumed wake also has to re-register us with the waker
+      if !woken && !has_dispatched(lua)? {
+        return Poll::Pending;
+      }
+    }
+  }
+
+  fn adopt_dispatched(&mut self, lua: &Lua) -> mlua::Result<()> {
+    let globals = lua.globals();
+    let Some(threads) = globals.get::<Option<Table>>("_threads")? else {
+      return Ok(());
+    };
+    globals.set("_threads", lua.create_table()?)?;
+
+    for thread in threads.sequence_values::<Thread>() {
+      self.tasks.push(Task::new(thread?, false));
+    }
+
+    Ok(())
+  }
+}
+
+fn has_dispatched(lua: &Lua) -> mlua::Result<bool> {
+  Ok(
+    lua
+      .globals()
+      .get::<Option<Table>>("_threads")?
+      .is_some_and(|threads| threads.raw_len() > 0),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  #[test]
+  fn run_returns_once_cancelled() {
+    let lua = Lua::new();
+    let main = lua
+      .create_thread(lua.load("while true do coroutine.yield() end").into_function().unwrap())
+      .unwrap();
+    let mut scheduler = Scheduler::new(main);
+
+    let is_parked = Arc::new(AtomicBool::new(false));
+    let is_cancelled = Arc::new(AtomicBool::new(false));
+
+    let canceller = std::thread::spawn({
+      let is_parked = Arc::clone(&is_parked);
+      let is_cancelled = Arc::clone(&is_cancelled);
+      move || {
+        // cancel once the scheduler sleeps, so only the wake can get it to return
+        while !is_parked.load(Ordering::SeqCst) {
+          std::thread::sleep(Duration::from_millis(1));
+        }
+        is_cancelled.store(true, Ordering::SeqCst);
+        LUA_WAKER.wake();
+      }
+    });
+
+    let result = scheduler.run(&lua, &is_parked, || is_cancelled.load(Ordering::SeqCst));
+    canceller.join().unwrap();
+
+    assert!(result.unwrap_err().downcast_ref::<Interrupted>().is_some());
+    assert!(!is_parked.load(Ordering::SeqCst));
+  }
+}
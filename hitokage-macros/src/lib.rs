@@ -33,9 +33,9 @@ pub fn impl_lua_base(args: TokenStream, input: TokenStream) -> TokenStream {
 
   let imports = quote! {
     use hitokage_core::components::base::BaseMsgHook::{
-      GetClass, GetHalign, GetHeight, GetHeightRequest, GetHexpand, GetSizeRequest, GetValign, GetVexpand, GetWidth,
-      GetWidthRequest, SetClass, SetHalign, SetHeightRequest, SetHexpand, GetId, SetSizeRequest, SetValign, SetVexpand,
-      SetWidthRequest,
+      GetClass, GetHalign, GetHeight, GetHeightRequest, GetHexpand, GetSizeRequest, GetTooltip, GetValign, GetVexpand,
+      GetWidth, GetWidthRequest, SetClass, SetHalign, SetHeightRequest, SetHexpand, GetId, SetSizeRequest, SetTooltip,
+      SetValign, SetVexpand, SetWidthRequest,
     };
   };
 
@@ -62,6 +62,9 @@ pub fn impl_lua_base(args: TokenStream, input: TokenStream) -> TokenStream {
         impl_getter_fn!(get_size_request, #path, BaseHook, GetSizeRequest, (i32, i32));
         impl_setter_fn!(set_size_request, #path, BaseHook, SetSizeRequest, (Option<i32>, Option<i32>));
 
+        impl_getter_fn!(get_tooltip, #path, BaseHook, GetTooltip, Option<String>);
+        impl_setter_fn!(set_tooltip, #path, BaseHook, SetTooltip, Option<String>);
+
         impl_getter_fn!(get_valign, #path, BaseHook, GetValign, Align);
         impl_setter_fn!(set_valign, #path, BaseHook, SetValign, Align);
 
@@ -103,6 +106,9 @@ pub fn impl_lua_base(args: TokenStream, input: TokenStream) -> TokenStream {
       methods.add_method("get_size_request", |lua, instance, ()| lua.to_value(&instance.get_size_request()?));
       methods.add_method("set_size_request", |lua, this, value: mlua::Value| { this.set_size_request(lua, value) });
 
+      methods.add_method("get_tooltip", |lua, instance, ()| lua.to_value(&instance.get_tooltip()?));
+      methods.add_method("set_tooltip", |lua, this, value: mlua::Value| { this.set_tooltip(lua, value) });
+
       methods.add_method("get_valign", |lua, instance, ()| lua.to_value(&instance.get_valign()?));
       methods.add_method("set_valign", |lua, this, value: mlua::Value| { this.set_valign(lua, value) });
 
@@ -1,7 +1,9 @@
 use crate::{App, LuaCoroutineMessage};
 use bon::bon;
 use gtk4::{style_context_add_provider_for_display, style_context_remove_provider_for_display, ApplicationWindow};
-use hitokage_core::{event::CONFIG_UPDATE, win_utils};
+use hitokage_core::event::{LUA_ACTION_REQUESTS, LUA_WAKER};
+use hitokage_lua::api::sandbox::check_callback_budget;
+use hitokage_lua::scheduler::{Interrupted, Scheduler};
 use mlua::LuaSerdeExt;
 use relm4::ComponentSender;
 use std::{
@@ -9,16 +11,15 @@ use std::{
   io::Read,
   path::PathBuf,
   sync::{
-    atomic::{AtomicBool, AtomicU32, Ordering},
-    mpsc::Sender,
+    atomic::{AtomicBool, AtomicU64, Ordering},
     Arc, Mutex,
   },
-  thread,
+  thread::{self, JoinHandle},
   time::{Duration, Instant},
 };
-use windows::Win32::Foundation::CloseHandle;
-use windows::Win32::Foundation::HANDLE;
-use windows::Win32::System::Threading::{OpenThread, TerminateThread, THREAD_TERMINATE};
+
+// how long a new generation waits for the previous one to unwind
+const STOP_TIMEOUT: Duration = Duration::from_secs(5);
 
 pub fn load_content(path: Option<PathBuf>) -> String {
   let mut contents = String::new();
@@ -29,186 +30,183 @@ pub fn load_content(path: Option<PathBuf>) -> String {
   }
 
   let prepend = include_str!("./lua/prepend.lua");
-  let append = include_str!("./lua/append.lua");
 
-  prepend.to_owned() + "\n" + &contents + "\n" + append
+  prepend.to_owned() + "\n" + &contents
+}
+
+/// Runs one generation of the lua runtime at a time, each on its own thread
+#[derive(Clone, Default)]
+struct Generations {
+  /// Bumped by every start, a runtime whose generation is outdated interrupts itself
+  current: Arc<AtomicU64>,
+  handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl Generations {
+  /// Stops the running generation first, then runs `run` with the new generation on its own thread
+  fn start(&self, run: impl FnOnce(u64) + Send + 'static) {
+    let mut handle = self.handle.lock().unwrap();
+    let generation = self.stop_locked(&mut handle);
+    *handle = Some(thread::spawn(move || run(generation)));
+  }
+
+  /// Interrupts the running generation and waits for it to unwind
+  fn stop(&self) {
+    self.stop_locked(&mut self.handle.lock().unwrap());
+  }
+
+  fn stop_locked(&self, handle: &mut Option<JoinHandle<()>>) -> u64 {
+    let generation = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+    // a parked scheduler only notices it was cancelled once it wakes up
+    LUA_WAKER.wake();
+
+    if let Some(previous) = handle.take() {
+      let started_at = Instant::now();
+      while !previous.is_finished() && started_at.elapsed() < STOP_TIMEOUT {
+        thread::sleep(Duration::from_millis(10));
+      }
+
+      if previous.is_finished() {
+        if previous.join().is_err() {
+          log::error!("Previous lua runtime panicked");
+        }
+      } else {
+        // ie. blocked inside a rust function, it interrupts itself as soon as it runs lua again
+        log::error!(
+          "Previous lua runtime did not stop within {:?}, leaving it to stop on its own",
+          STOP_TIMEOUT
+        );
+      }
+    }
+
+    generation
+  }
+
+  /// Holds once a newer generation than `generation` was started, or the runtime was stopped
+  fn is_cancelled(&self, generation: u64) -> impl Fn() -> bool + Clone + Send + Sync + 'static {
+    let current = Arc::clone(&self.current);
+    move || current.load(Ordering::SeqCst) != generation
+  }
+}
+
+/// Interrupts lua code wherever it is once `is_cancelled` holds, or a callback runs over its budget.
+///
+/// Threads created afterwards inherit the hook. A script can still catch the error with pcall, but is interrupted
+/// again on its next instructions
+fn set_interrupt_hook(lua: &mlua::Lua, is_cancelled: impl Fn() -> bool + Send + 'static) {
+  lua.set_hook(mlua::HookTriggers::new().every_nth_instruction(1000), move |lua, _| {
+    if is_cancelled() {
+      return Err(mlua::Error::external(Interrupted));
+    }
+    check_callback_budget(lua)?;
+    Ok(mlua::VmState::Continue)
+  });
 }
 
 #[derive(Clone)]
 pub(crate) struct LuaRuntime {
-  lua: mlua::Lua,
   sender: ComponentSender<App>,
   file_path: PathBuf,
-  lua_thread_id: Arc<AtomicU32>,
   preventer_called: Arc<AtomicBool>,
   is_stopped: Arc<AtomicBool>,
-  file_last_checked_at: Arc<Mutex<Instant>>,
-  tx: Sender<bool>,
+  generations: Generations,
 }
 
 #[bon]
 impl LuaRuntime {
-  #[allow(clippy::too_many_arguments)]
   #[builder]
   pub(crate) fn new(
-    lua: mlua::Lua,
     sender: ComponentSender<App>,
     file_path: PathBuf,
-    lua_thread_id: Arc<AtomicU32>,
     preventer_called: Arc<AtomicBool>,
     is_stopped: Arc<AtomicBool>,
-    file_last_checked_at: Arc<Mutex<Instant>>,
-    tx: Sender<bool>,
   ) -> Self {
     Self {
-      lua,
       sender,
       file_path,
-      lua_thread_id,
       preventer_called,
       is_stopped,
-      file_last_checked_at,
-      tx,
+      generations: Generations::default(),
     }
   }
 
-  pub(crate) fn start_runtime(self) {
-    thread::spawn(move || -> anyhow::Result<(), mlua::Error> {
-      terminate_thread(&self.lua_thread_id);
-
-      let lua = hitokage_lua::make(self.lua, self.sender.clone()).unwrap();
-
-      self
-        .lua_thread_id
-        .store(win_utils::get_current_thread_id(), Ordering::SeqCst);
-
-      let globals = lua.globals();
-
-      let preventer_fn = lua.create_function(move |_, ()| {
-        self.preventer_called.store(true, Ordering::SeqCst);
-        Ok(())
-      })?;
-
-      globals.set("_not_deadlocked", preventer_fn)?;
-
-      let func_or_err = lua.load(load_content(Some(self.file_path.clone()))).into_function();
-
-      // if we failed to create a coroutine default to an empty script
-      let coroutine = lua
-        .create_thread({
-          match func_or_err {
-            Ok(func) => func,
-            Err(err) => {
-              log::error!("There was an error loading your user script: {:?}", err);
-              log::info!("Falling back to an empty script. Waiting for user script fixes");
-              lua
-                .load(load_content(None))
-                .into_function()
-                .expect("Internal script error when falling back to empty user script")
-            }
-          }
-        })
-        .expect("Failed to create a lua thread");
-
-      let tx_clone = self.tx.clone();
-
-      // file-watcher is injected through a hook to ensure we are polling it through other means as well
-      coroutine.set_hook(mlua::HookTriggers::new().every_nth_instruction(500), {
-        let file_last_checked_at = Arc::clone(&self.file_last_checked_at);
-        move |lua, _| {
-          let valid_status = match lua.current_thread().status() {
-            mlua::ThreadStatus::Resumable => true,
-            mlua::ThreadStatus::Finished => false,
-            mlua::ThreadStatus::Running => false,
-            mlua::ThreadStatus::Error => false,
-          };
-          let mut guard = file_last_checked_at.lock().unwrap();
-          // skip if we havent passed enough time, or we aren't invalid status
-          // @codyduong this is super fragile, we can double run this on accident, instead use a different method to check
-          if ((*guard).elapsed() <= Duration::from_millis(250)) || valid_status {
-            drop(guard);
-            return Ok(mlua::VmState::Continue);
-          }
-
-          let mut update_guard = CONFIG_UPDATE.write();
-          if *update_guard {
-            *update_guard = false;
-            drop(update_guard);
-
-            lua.remove_hook();
-
-            // safe reload not possible!
-            tx_clone.send(false).unwrap();
-          }
-
-          *guard = Instant::now();
-          drop(guard);
-
-          Ok(mlua::VmState::Continue)
-        }
-      });
+  /// Starts a new generation of the lua runtime with a fresh lua state, stopping the previous generation first
+  pub(crate) fn start_runtime(&self) {
+    let runtime = self.clone();
+    self.generations.start(move |generation| runtime.run(generation));
+  }
+
+  /// Interrupts the running generation and waits for it to unwind
+  pub(crate) fn stop_runtime(&self) {
+    self.generations.stop();
+  }
 
-      loop {
-        let time = Instant::now();
-        match coroutine.resume::<mlua::Value>(()) {
-          Ok(value) => match value {
-            mlua::Value::Nil => (),
-            mlua::Value::Boolean(_) => (),
-            mlua::Value::UserData(_) => (),
-            mlua::Value::LightUserData(_) => (),
-            _ => {
-              let props: mlua::Result<LuaCoroutineMessage> = lua.from_value(value.clone());
-
-              match props {
-                Ok(msg) => match msg {
-                  LuaCoroutineMessage::Suspend => {
-                    log::info!("Received suspend from lua coroutine");
-                    self.is_stopped.store(true, Ordering::SeqCst);
-                    break Ok(());
-                  }
-                  LuaCoroutineMessage::Reload => {
-                    log::info!("Received reload from lua coroutine");
-                    let mut sswg = CONFIG_UPDATE.write();
-                    *sswg = false;
-                    let user_script = load_content(Some(self.file_path.clone()));
-                    let result = lua.load(user_script).into_function();
-                    drop(sswg);
-                    match result {
-                      Ok(func) => {
-                        let _ = coroutine.reset(func);
-                        self.tx.send(true).unwrap(); // safe reload success
-                      }
-                      Err(_) => {
-                        // no need to handle the error since we will attempt to start again
-                        // which will indicate the error
-                        self.tx.send(false).unwrap();
-                      }
-                    }
-                  }
-                },
-                Err(err) => {
-                  log::error!("Received unknown coroutine return, {:?}, {:?}", err, value);
-                  self.is_stopped.store(true, Ordering::SeqCst);
-                  break Err(err);
-                }
-              }
-            }
-          },
-          Err(mlua::Error::CoroutineUnresumable) => {
-            self.is_stopped.store(true, Ordering::SeqCst);
-            break Ok(());
-          }
-          Err(err) => {
-            log::error!("Lua error: {:?}", err);
-            self.is_stopped.store(true, Ordering::SeqCst);
-            break Err(err);
-          }
+  fn run(&self, generation: u64) {
+    let is_cancelled = self.generations.is_cancelled(generation);
+
+    // requests queued for the previous generation belong to a lua state that no longer exists
+    LUA_ACTION_REQUESTS.write().clear();
+
+    let config_dir = self.file_path.parent().map(PathBuf::from).unwrap_or_default();
+    let lua = match hitokage_lua::make(mlua::Lua::new(), self.sender.clone(), config_dir) {
+      Ok(lua) => lua,
+      Err(err) => {
+        log::error!("Failed to create the lua runtime: {:?}", err);
+        self.is_stopped.store(true, Ordering::SeqCst);
+        return;
+      }
+    };
+
+    set_interrupt_hook(&lua, is_cancelled.clone());
+
+    let func_or_err = lua.load(load_content(Some(self.file_path.clone()))).into_function();
+
+    // if we failed to load the user script default to an empty script
+    let main = match func_or_err {
+      Ok(func) => func,
+      Err(err) => {
+        log::error!("There was an error loading your user script: {:?}", err);
+        log::info!("Falling back to an empty script. Waiting for user script fixes");
+        lua
+          .load(load_content(None))
+          .into_function()
+          .expect("Internal script error when falling back to empty user script")
+      }
+    };
+
+    let mut scheduler = match lua.create_thread(main) {
+      Ok(thread) => Scheduler::new(thread),
+      Err(err) => {
+        log::error!("Failed to create the lua thread: {:?}", err);
+        self.is_stopped.store(true, Ordering::SeqCst);
+        return;
+      }
+    };
+
+    match scheduler.run(&lua, &self.preventer_called, &is_cancelled) {
+      Ok(Some(value)) => match lua.from_value::<LuaCoroutineMessage>(value.clone()) {
+        Ok(LuaCoroutineMessage::Suspend) => {
+          log::info!("Received suspend from lua coroutine");
+          self.is_stopped.store(true, Ordering::SeqCst);
         }
-        if time.elapsed() <= Duration::from_millis(100) {
-          std::thread::sleep(Duration::from_millis(100) - time.elapsed())
+        Err(err) => {
+          log::error!("Received unknown coroutine return, {:?}, {:?}", err, value);
+          self.is_stopped.store(true, Ordering::SeqCst);
         }
+      },
+      Ok(None) => {
+        self.is_stopped.store(true, Ordering::SeqCst);
       }
-    });
+      // errors raised while unwinding are expected, ie. components destroyed under a running callback
+      Err(_) if is_cancelled() => {
+        log::info!("Stopped lua runtime generation {}", generation);
+      }
+      Err(err) => {
+        log::error!("Lua error: {:?}", err);
+        self.is_stopped.store(true, Ordering::SeqCst);
+      }
+    }
+    // the lua state of this generation is dropped along with the thread
   }
 }
 
@@ -224,7 +222,7 @@ pub(crate) fn create_watcher_handle(
       let is_stopped = is_stopped.load(Ordering::SeqCst);
 
       if is_stopped {
-        // this should never happen given that in prepend.lua we have a dispatch loop
+        // this should never happen given that the scheduler keeps running the threads from prepend.lua
         // unless we have crashed the lua runtime or CTRL+C the progrma. in any case
         // shutdown all bars
         log::debug!("Lua execution finished, stopping lua watcher");
@@ -243,10 +241,10 @@ pub(crate) fn create_watcher_handle(
         start_time = Instant::now();
       }
 
+      // the lua scheduler is parked waiting for events, so it can't be stuck
       let called = preventer_called.load(Ordering::SeqCst);
 
       if called {
-        preventer_called.store(true, Ordering::SeqCst);
         start_time = Instant::now();
       };
 
@@ -255,33 +253,6 @@ pub(crate) fn create_watcher_handle(
   })
 }
 
-fn terminate_thread(id_arc: &Arc<AtomicU32>) {
-  let id_guard = id_arc.load(Ordering::SeqCst);
-  if id_guard != 0 {
-    log::debug!("Attempting to terminate lua thread with id: {:?}", id_guard);
-    unsafe {
-      let handle = OpenThread(THREAD_TERMINATE, false, id_guard).unwrap();
-
-      if handle != HANDLE(0) {
-        let result = TerminateThread(handle, 1);
-
-        if let Err(result) = result {
-          // let error_code = windows::Win32::Foundation::GetLastError();
-          log::error!("Failed to terminate thread: {:?}", result);
-        } else {
-          log::debug!("Successfully terminated thread");
-        }
-
-        let _ = CloseHandle(handle);
-      } else {
-        let error_code = windows::Win32::Foundation::GetLastError();
-        log::error!("Failed to open thread handle: {:?}", error_code);
-      }
-    }
-  }
-  id_arc.store(0, Ordering::SeqCst);
-}
-
 pub fn reload_css_provider(
   root: &ApplicationWindow,
   css_file_path: &PathBuf,
@@ -298,3 +269,67 @@ pub fn reload_css_provider(
   style_context_add_provider_for_display(&display, &provider, 500);
   provider
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::mpsc;
+
+  /// Hook errors reach the caller wrapped in the error of the function that was running
+  fn is_interrupted(err: &mlua::Error) -> bool {
+    match err {
+      mlua::Error::CallbackError { cause, .. } => is_interrupted(cause),
+      err => err.downcast_ref::<Interrupted>().is_some(),
+    }
+  }
+
+  #[test]
+  fn hook_interrupts_infinite_loop() {
+    let lua = mlua::Lua::new();
+    let is_cancelled = Arc::new(AtomicBool::new(false));
+    set_interrupt_hook(&lua, {
+      let is_cancelled = Arc::clone(&is_cancelled);
+      move || is_cancelled.load(Ordering::SeqCst)
+    });
+
+    let canceller = thread::spawn({
+      let is_cancelled = Arc::clone(&is_cancelled);
+      move || {
+        thread::sleep(Duration::from_millis(50));
+        is_cancelled.store(true, Ordering::SeqCst);
+      }
+    });
+
+    let err = lua.load("while true do end").exec().unwrap_err();
+    canceller.join().unwrap();
+    assert!(is_interrupted(&err), "{:?}", err);
+  }
+
+  #[test]
+  fn start_replaces_the_running_generation() {
+    let generations = Generations::default();
+    let (tx, rx) = mpsc::channel();
+
+    let start = || {
+      let runner = generations.clone();
+      let tx = tx.clone();
+      generations.start(move |generation| {
+        let lua = mlua::Lua::new();
+        set_interrupt_hook(&lua, runner.is_cancelled(generation));
+        let result = lua.load("while true do end").exec();
+        tx.send((generation, result.is_err_and(|err| is_interrupted(&err))))
+          .unwrap();
+      });
+    };
+
+    start();
+    start();
+    // the first generation unwound before the second one started
+    assert_eq!(rx.try_recv(), Ok((1, true)));
+    assert!(rx.try_recv().is_err());
+
+    generations.stop();
+    assert_eq!(rx.try_recv(), Ok((2, true)));
+    assert!(generations.handle.lock().unwrap().is_none());
+  }
+}
@@ -46,7 +46,7 @@ local komorebic_coroutine = coroutine.create(function()
 			local unread_states = hitokage._internals.event.get_unread()
 			for id, callback in pairs(subscriptions) do
 				for _, state in pairs(unread_states) do
-					local status, res = pcall(callback, state)
+					local status, res = pcall(hitokage._internals.call_with_budget, callback, state)
 					if status == false then
 						hitokage.error("Error running subscription callback {" .. id .. "}:", res)
 					end
@@ -57,25 +57,17 @@ local komorebic_coroutine = coroutine.create(function()
 	end
 end)
 
-local file_watcher = coroutine.create(function()
-	while true do
-		local new = hitokage._internals.event.configuration.changed()
-		if new then
-			coroutine.yield("Reload")
-			-- stop running lua, then it will be passed to rust to reload this lua
-			_G["threads"] = {}
-		end
-		coroutine.yield()
-	end
-end)
-
 local callback_watcher = coroutine.create(function()
 	while true do
 		local actions = hitokage._internals.actions.get_unread()
 		if actions ~= nil then
 			for _, action in pairs(actions) do
-				hitokage.error("my fridge ran")
-				action:call()
+				local status, res = pcall(hitokage._internals.call_with_budget, function()
+					action:call()
+				end)
+				if status == false then
+					hitokage.error("Error running component action:", res)
+				end
 			end
 		end
 		coroutine.yield()
@@ -84,7 +76,6 @@ end)
 
 _G["_threads"] = {
 	komorebic_coroutine,
-	file_watcher,
 	callback_watcher,
 }
 
@@ -93,17 +84,10 @@ _G["_threads"] = {
 ---@return thread
 _G.hitokage.timeout = function(timeout, action)
 	return coroutine.create(function()
-		local start_time = os.clock()
-
 		while true do
-			local elapsed_time = (os.clock() - start_time) * 1000
-			local remaining_time = timeout - elapsed_time
-			if remaining_time <= 0 then
-				start_time = os.clock()
-				action()
-			end
-
-			coroutine.yield()
+			-- only this coroutine is suspended, the scheduler resumes it once the timer fires
+			hitokage.sleep_ms(timeout)
+			action()
 		end
 	end)
 end
@@ -4,7 +4,7 @@ use hitokage_core::components;
 use hitokage_core::components::app::{AppMsg, LuaHookType};
 use hitokage_core::components::bar;
 use hitokage_core::components::weather::WeatherStation;
-use hitokage_core::event::{CONFIG_UPDATE, EVENT, LUA_ACTION_REQUESTS, NEW_EVENT, STATE};
+use hitokage_core::event::{EVENT, LUA_ACTION_REQUESTS, LUA_WAKER, MAX_LUA_ACTION_REQUESTS, NEW_EVENT, STATE};
 use hitokage_core::get_hitokage_asset;
 use hitokage_core::structs::lua_action::LuaActionRequest;
 use hitokage_core::structs::system::SystemWrapper;
@@ -15,15 +15,15 @@ use relm4::component::Controller;
 use relm4::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
+use std::path::PathBuf;
 use std::rc::Rc;
-use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
-use std::sync::mpsc::{channel, Sender};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
 use std::sync::Mutex;
 use std::sync::{Arc, Condvar};
 use std::thread;
 use std::time::Duration;
-use std::time::Instant;
 use windows::Win32::System::Console::{GenerateConsoleCtrlEvent, CTRL_C_EVENT};
 use windows::Win32::UI::HiDpi::{SetProcessDpiAwareness, PROCESS_PER_MONITOR_DPI_AWARE};
 use windows::Win32::UI::WindowsAndMessaging::{GetWindowLongPtrW, SetWindowLongPtrW, GWL_EXSTYLE, WS_EX_TOOLWINDOW};
@@ -33,13 +33,10 @@ mod socket;
 #[derive(Debug, Deserialize, Serialize, Clone)]
 enum LuaCoroutineMessage {
   Suspend,
-  Reload,
 }
 
 struct App {
-  // lua: mlua::Lua, // TODO @codyduong, reevaluate after mlua0.10.0 is stable
   bars: Vec<Controller<components::bar::Bar>>,
-  file_last_checked_at: Arc<Mutex<Instant>>,
   // so we only keep one weather station to request forecasts (todo @codyduong support multiple weather stations)
   weather_station: Arc<Mutex<Option<WeatherStation>>>,
   weather_station_count: Arc<AtomicUsize>,
@@ -47,15 +44,15 @@ struct App {
   is_destroyed_condvar: Arc<(Mutex<bool>, Condvar)>,
   bars_destroyed_condvar: Arc<(Mutex<usize>, Condvar)>, // the number of bars that have destroyed
 
+  debouncer: notify_debouncer_full::Debouncer<notify::ReadDirectoryChangesWatcher, notify_debouncer_full::FileIdMap>,
+  watched_files: HashSet<PathBuf>, // init.lua and every module it required
+
   // keep alive for lifetime of app
-  _debouncer: notify_debouncer_full::Debouncer<notify::ReadDirectoryChangesWatcher, notify_debouncer_full::FileIdMap>,
   _css_debouncer:
     notify_debouncer_full::Debouncer<notify::ReadDirectoryChangesWatcher, notify_debouncer_full::FileIdMap>,
-  _tx_lua: Sender<bool>,
 }
 
 struct AppInit {
-  lua: mlua::Lua,
   is_stopped: Arc<AtomicBool>,
 }
 
@@ -94,7 +91,6 @@ impl Component for App {
 
   fn init(init: Self::Init, root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
     // start the lua hook
-    let lua = init.lua;
     let is_stopped = init.is_stopped;
 
     let lua_file_path = get_hitokage_asset("init.lua");
@@ -104,25 +100,14 @@ impl Component for App {
     log::info!("attempting to load lua styles.css at: {}", css_file_path.display());
 
     let preventer_called = Arc::new(AtomicBool::new(false));
-    let lua_thread_id = Arc::new(AtomicU32::new(0));
-    let file_last_checked_at = Arc::new(Mutex::new(Instant::now())); // when did we last check for a config update through any means?
-
-    // these names suck, these are for sending to create a new luahandle for forceful termination
-    let (tx_lua, rx_lua) = channel::<bool>();
 
-    // if we need this for some reason, uhh good luck managing the arc mutex,
-    // plus untangle all the other ones then...
     let runtime = LuaRuntime::builder()
-      .lua(lua.clone())
       .sender(sender.clone())
       .file_path(lua_file_path.clone())
-      .lua_thread_id(lua_thread_id.clone())
       .preventer_called(preventer_called.clone())
       .is_stopped(is_stopped.clone())
-      .file_last_checked_at(file_last_checked_at.clone())
-      .tx(tx_lua.clone())
       .build();
-    runtime.clone().start_runtime();
+    runtime.start_runtime();
 
     let _monitor_handle = config::create_watcher_handle(
       preventer_called.clone(),
@@ -151,6 +136,8 @@ impl Component for App {
               Ok(e) => {
                 log::info!("File update: {:?}", e);
 
+                // interrupt the running config wherever it is before tearing down the bars it created
+                runtime.stop_runtime();
                 sender.input(AppMsg::DestroyActual);
 
                 let (lock, cvar) = &*is_destroyed_condvar_2;
@@ -164,39 +151,9 @@ impl Component for App {
                 std::thread::sleep(Duration::from_millis(2000));
                 *destroyed = false;
 
-                if !is_stopped.load(Ordering::SeqCst) {
-                  // if we are stuck in lua execution loop we need to dispatch a response to it for it to implode itself
-                  match CONFIG_UPDATE.try_write() {
-                    Ok(mut wg) => {
-                      *wg = true;
-                      drop(wg);
-                    }
-                    Err(e) => {
-                      log::warn!("{}", e);
-                      runtime.clone().start_runtime();
-                    }
-                  };
-
-                  match rx_lua.recv() {
-                    Ok(true) => {
-                      log::info!("Lua thread reset itself within lua environment");
-                    }
-                    Ok(false) => {
-                      log::info!("Lua thread coroutine deadlocked, starting new lua thread");
-                      runtime.clone().start_runtime();
-                    }
-                    Err(_) => {
-                      // @codyduong LOL, todo
-                      log::error!("Lua channel closed before receiving confirmation of launch of lua thread");
-                      is_stopped.store(true, Ordering::SeqCst);
-                    }
-                  }
-                } else {
-                  log::info!("Lua thread finished executing, starting new lua thread");
-                  // otherwise we have finished execution, so dispatch a new thread
-                  runtime.clone().start_runtime();
-                  is_stopped.store(false, Ordering::SeqCst);
-                }
+                log::info!("Starting new lua runtime");
+                runtime.start_runtime();
+                is_stopped.store(false, Ordering::SeqCst);
               }
               Err(e) => {
                 log::error!("Watch error: {:?}", e);
@@ -267,17 +224,15 @@ impl Component for App {
     }
 
     let model = App {
-      // lua,
       bars: Vec::new(),
-      file_last_checked_at,
       weather_station: Arc::new(Mutex::new(None)),
       weather_station_count: Arc::new(AtomicUsize::new(0)),
       system: SystemWrapper::default(),
       is_destroyed_condvar,
       bars_destroyed_condvar: Arc::new((Mutex::new(0), Condvar::new())),
-      _debouncer: debouncer,
+      debouncer,
+      watched_files: HashSet::from([lua_file_path]),
       _css_debouncer: css_debouncer,
-      _tx_lua: tx_lua,
     };
 
     let widgets = view_output!();
@@ -306,6 +261,7 @@ impl Component for App {
         deque.push_back(notif.clone());
         *STATE.write() = notif.state;
         *NEW_EVENT.write() = true;
+        LUA_WAKER.wake();
       }
       AppMsg::KomorebiErr(line) => {
         println!("{:?}", &line);
@@ -330,8 +286,18 @@ impl Component for App {
         LuaHookType::ReadEvent => {
           *NEW_EVENT.write() = false;
         }
-        LuaHookType::CheckConfigUpdate => {
-          *self.file_last_checked_at.lock().unwrap() = Instant::now();
+        LuaHookType::WatchFile(path) => {
+          // modules that are no longer required stay watched, at worst editing one reloads an unchanged config
+          if self.watched_files.insert(path.clone()) {
+            log::info!("Watching lua module at: {}", path.display());
+            if let Err(err) = self
+              .debouncer
+              .watcher()
+              .watch(&path, notify::RecursiveMode::NonRecursive)
+            {
+              log::error!("Failed to watch {}: {:?}", path.display(), err);
+            }
+          }
         }
         LuaHookType::NoAction => (),
         _ => {
@@ -376,10 +342,13 @@ impl Component for App {
       AppMsg::RequestLuaAction(id, args, f) => {
         log::debug!("Requested lua in component");
         let mut deque = LUA_ACTION_REQUESTS.write();
-        if deque.len() == deque.capacity() {
-          panic!("Our requests filled!")
+        if deque.len() >= MAX_LUA_ACTION_REQUESTS {
+          log::error!("Lua action requests are full, dropping request");
+          return;
         }
         deque.push_back(LuaActionRequest { id, args, f: Some(f) });
+        drop(deque);
+        LUA_WAKER.wake();
       }
       AppMsg::DestroyActual => {
         self
@@ -442,7 +411,6 @@ fn main() {
 
   let app = RelmApp::new("com.example.hitokage");
   let is_stopped = Arc::new(AtomicBool::new(false));
-  let lua = mlua::Lua::new();
 
   {
     let is_stopped = is_stopped.clone();
@@ -463,7 +431,7 @@ fn main() {
   }
 
   // let _ = app.set_global_css_from_file(style_file_path);
-  app.run::<App>(AppInit { lua, is_stopped });
+  app.run::<App>(AppInit { is_stopped });
 }
 
 fn cleanup() {